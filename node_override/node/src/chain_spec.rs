@@ -1,7 +1,8 @@
 use sp_core::{Pair, Public, sr25519, crypto::Ss58Codec};
 use node_template_runtime::{
 	AccountId, AuraConfig, BalancesConfig, GenesisConfig, GrandpaConfig,
-	SudoConfig, SystemConfig, WASM_BINARY, Signature, EvercityAccountsConfig
+	SudoConfig, SystemConfig, WASM_BINARY, Signature, EvercityAccountsConfig,
+	EvercityCarbonCreditsConfig,
 };
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_finality_grandpa::AuthorityId as GrandpaId;
@@ -199,5 +200,6 @@ fn testnet_genesis(
                 })
                 .collect(),
         }),
+		pallet_evercity_carbon_credits: Some(EvercityCarbonCreditsConfig::default()),
 	}
 }
@@ -0,0 +1,187 @@
+//! RPC methods for read only queries of the carbon credits pallet.
+//! Pallet structs are returned as JSON, serialized with serde derives of the pallet types.
+
+use std::sync::Arc;
+
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use node_template_runtime::{opaque::Block, AccountId, AssetId, Balance, BlockNumber, Moment};
+use node_template_runtime::pallet_evercity_carbon_credits::project::{ProjectId, ProjectStateMask, ProjectStruct};
+use node_template_runtime::pallet_evercity_carbon_credits::standard::Standard;
+use node_template_runtime::pallet_evercity_carbon_credits::runtime_api::CarbonCreditsAssetInfo;
+use node_template_runtime::pallet_evercity_carbon_credits::carbon_credits_totals::CarbonCreditsTotals;
+use node_template_runtime::pallet_evercity_carbon_credits::burn_certificate::{CarbonCreditsBurnCertificate, RetirementCertificate};
+use node_template_runtime::pallet_evercity_carbon_credits::serial_range::CarbonCreditsSerialRanges;
+pub use node_template_runtime::pallet_evercity_carbon_credits::runtime_api::CarbonCreditsApi as CarbonCreditsRuntimeApi;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+/// Carbon credits RPC methods.
+#[rpc]
+pub trait CarbonCreditsApi<BlockHash> {
+	/// Returns Option<CarbonCreditsAssetInfo> - passport of the asset with its project and annual report.
+	#[rpc(name = "carbonCredits_assetInfo")]
+	fn asset_info(&self, asset_id: AssetId, at: Option<BlockHash>) -> Result<Option<CarbonCreditsAssetInfo<AccountId, AssetId, Moment, Balance>>>;
+
+	/// Returns Vec<ProjectStruct> - projects of the owner.
+	#[rpc(name = "carbonCredits_projectsByOwner")]
+	fn projects_by_owner(&self, owner: AccountId, at: Option<BlockHash>) -> Result<Vec<ProjectStruct<AccountId>>>;
+
+	/// Returns annual report indexes and carbon credits assets of the project.
+	#[rpc(name = "carbonCredits_projectAssets")]
+	fn project_assets(&self, project_id: ProjectId, at: Option<BlockHash>) -> Result<Vec<(u64, AssetId)>>;
+
+	/// Returns CarbonCreditsTotals - issued, retired, buffered and cancelled carbon credits of the project.
+	#[rpc(name = "carbonCredits_projectTotals")]
+	fn project_totals(&self, project_id: ProjectId, at: Option<BlockHash>) -> Result<CarbonCreditsTotals<Balance>>;
+
+	/// Returns CarbonCreditsTotals - issued, retired, buffered and cancelled carbon credits of the whole ledger.
+	#[rpc(name = "carbonCredits_totals")]
+	fn totals(&self, at: Option<BlockHash>) -> Result<CarbonCreditsTotals<Balance>>;
+
+	/// Returns CarbonCreditsTotals of projects of the standard, e.g. "GOLD_STANDARD" or {"CUSTOM": 1}.
+	#[rpc(name = "carbonCredits_standardTotals")]
+	fn standard_totals(&self, standard: Standard, at: Option<BlockHash>) -> Result<CarbonCreditsTotals<Balance>>;
+
+	/// Returns CarbonCreditsTotals of carbon credits of the vintage.
+	#[rpc(name = "carbonCredits_vintageTotals")]
+	fn vintage_totals(&self, vintage: u16, at: Option<BlockHash>) -> Result<CarbonCreditsTotals<Balance>>;
+
+	/// Returns ids of projects in the state.
+	#[rpc(name = "carbonCredits_projectsByState")]
+	fn projects_by_state(&self, state: ProjectStateMask, at: Option<BlockHash>) -> Result<Vec<ProjectId>>;
+
+	/// Returns ids of projects, waiting for the signature of the signer.
+	#[rpc(name = "carbonCredits_projectsPendingSignature")]
+	fn projects_pending_signature(&self, signer: AccountId, at: Option<BlockHash>) -> Result<Vec<ProjectId>>;
+
+	/// Returns project ids and indexes of annual reports, waiting for the signature of the signer.
+	#[rpc(name = "carbonCredits_annualReportsPendingSignature")]
+	fn annual_reports_pending_signature(&self, signer: AccountId, at: Option<BlockHash>) -> Result<Vec<(ProjectId, u64)>>;
+
+	/// Returns Vec<CarbonCreditsBurnCertificate> - burned carbon credits of the account by asset.
+	#[rpc(name = "carbonCredits_certificatesByAccount")]
+	fn certificates_by_account(&self, account: AccountId, at: Option<BlockHash>) -> Result<Vec<CarbonCreditsBurnCertificate<AssetId, Balance>>>;
+
+	/// Returns Vec<RetirementCertificate> - retirement certificates of the beneficiary.
+	#[rpc(name = "carbonCredits_retirementCertificatesByAccount")]
+	fn retirement_certificates_by_account(&self, account: AccountId, at: Option<BlockHash>) -> Result<Vec<RetirementCertificate<AccountId, AssetId, Balance, Moment, BlockNumber>>>;
+
+	/// Returns Option<CarbonCreditsSerialRanges> - serial numbers of the asset, held by the account, with project id, annual report index and vintage.
+	#[rpc(name = "carbonCredits_serialRanges")]
+	fn serial_ranges(&self, asset_id: AssetId, account: AccountId, at: Option<BlockHash>) -> Result<Option<CarbonCreditsSerialRanges<Balance>>>;
+}
+
+/// Implementation of carbon credits RPC methods, calling the runtime api.
+pub struct CarbonCredits<C> {
+	client: Arc<C>,
+}
+
+impl<C> CarbonCredits<C> {
+	/// Creates new instance with the given client.
+	pub fn new(client: Arc<C>) -> Self {
+		CarbonCredits { client }
+	}
+}
+
+/// Error code of failed runtime api call.
+const RUNTIME_ERROR: i64 = 1;
+
+fn runtime_error(err: impl std::fmt::Debug) -> RpcError {
+	RpcError {
+		code: ErrorCode::ServerError(RUNTIME_ERROR),
+		message: "Unable to query carbon credits.".into(),
+		data: Some(format!("{:?}", err).into()),
+	}
+}
+
+impl<C> CarbonCredits<C> where C: HeaderBackend<Block> {
+	/// Requested block or the best block.
+	fn block_id(&self, at: Option<<Block as BlockT>::Hash>) -> BlockId<Block> {
+		BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash))
+	}
+}
+
+impl<C> CarbonCreditsApi<<Block as BlockT>::Hash> for CarbonCredits<C> where
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: CarbonCreditsRuntimeApi<Block, AccountId, AssetId, Balance, Moment, BlockNumber>,
+{
+	fn asset_info(&self, asset_id: AssetId, at: Option<<Block as BlockT>::Hash>) -> Result<Option<CarbonCreditsAssetInfo<AccountId, AssetId, Moment, Balance>>> {
+		self.client.runtime_api()
+			.carbon_credits_asset_info(&self.block_id(at), asset_id)
+			.map_err(runtime_error)
+	}
+
+	fn projects_by_owner(&self, owner: AccountId, at: Option<<Block as BlockT>::Hash>) -> Result<Vec<ProjectStruct<AccountId>>> {
+		self.client.runtime_api()
+			.projects_by_owner(&self.block_id(at), owner)
+			.map_err(runtime_error)
+	}
+
+	fn project_assets(&self, project_id: ProjectId, at: Option<<Block as BlockT>::Hash>) -> Result<Vec<(u64, AssetId)>> {
+		self.client.runtime_api()
+			.project_assets(&self.block_id(at), project_id)
+			.map_err(runtime_error)
+	}
+
+	fn project_totals(&self, project_id: ProjectId, at: Option<<Block as BlockT>::Hash>) -> Result<CarbonCreditsTotals<Balance>> {
+		self.client.runtime_api()
+			.project_carbon_credits_totals(&self.block_id(at), project_id)
+			.map_err(runtime_error)
+	}
+
+	fn totals(&self, at: Option<<Block as BlockT>::Hash>) -> Result<CarbonCreditsTotals<Balance>> {
+		self.client.runtime_api()
+			.carbon_credits_total(&self.block_id(at))
+			.map_err(runtime_error)
+	}
+
+	fn standard_totals(&self, standard: Standard, at: Option<<Block as BlockT>::Hash>) -> Result<CarbonCreditsTotals<Balance>> {
+		self.client.runtime_api()
+			.standard_carbon_credits_totals(&self.block_id(at), standard)
+			.map_err(runtime_error)
+	}
+
+	fn vintage_totals(&self, vintage: u16, at: Option<<Block as BlockT>::Hash>) -> Result<CarbonCreditsTotals<Balance>> {
+		self.client.runtime_api()
+			.vintage_carbon_credits_totals(&self.block_id(at), vintage)
+			.map_err(runtime_error)
+	}
+
+	fn projects_by_state(&self, state: ProjectStateMask, at: Option<<Block as BlockT>::Hash>) -> Result<Vec<ProjectId>> {
+		self.client.runtime_api()
+			.projects_by_state(&self.block_id(at), state)
+			.map_err(runtime_error)
+	}
+
+	fn projects_pending_signature(&self, signer: AccountId, at: Option<<Block as BlockT>::Hash>) -> Result<Vec<ProjectId>> {
+		self.client.runtime_api()
+			.projects_pending_signature(&self.block_id(at), signer)
+			.map_err(runtime_error)
+	}
+
+	fn annual_reports_pending_signature(&self, signer: AccountId, at: Option<<Block as BlockT>::Hash>) -> Result<Vec<(ProjectId, u64)>> {
+		self.client.runtime_api()
+			.annual_reports_pending_signature(&self.block_id(at), signer)
+			.map_err(runtime_error)
+	}
+
+	fn certificates_by_account(&self, account: AccountId, at: Option<<Block as BlockT>::Hash>) -> Result<Vec<CarbonCreditsBurnCertificate<AssetId, Balance>>> {
+		self.client.runtime_api()
+			.certificates_by_account(&self.block_id(at), account)
+			.map_err(runtime_error)
+	}
+
+	fn retirement_certificates_by_account(&self, account: AccountId, at: Option<<Block as BlockT>::Hash>) -> Result<Vec<RetirementCertificate<AccountId, AssetId, Balance, Moment, BlockNumber>>> {
+		self.client.runtime_api()
+			.retirement_certificates_by_account(&self.block_id(at), account)
+			.map_err(runtime_error)
+	}
+
+	fn serial_ranges(&self, asset_id: AssetId, account: AccountId, at: Option<<Block as BlockT>::Hash>) -> Result<Option<CarbonCreditsSerialRanges<Balance>>> {
+		self.client.runtime_api()
+			.carbon_credits_serial_ranges(&self.block_id(at), asset_id, account)
+			.map_err(runtime_error)
+	}
+}
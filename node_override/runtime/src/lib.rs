@@ -9,7 +9,7 @@ include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 use sp_std::prelude::*;
 use sp_core::{crypto::KeyTypeId, OpaqueMetadata};
 use sp_runtime::{
-	ApplyExtrinsicResult, generic, create_runtime_str, impl_opaque_keys, MultiSignature,
+	ApplyExtrinsicResult, generic, create_runtime_str, impl_opaque_keys, MultiSignature, ModuleId,
 	transaction_validity::{TransactionValidity, TransactionSource},
 };
 use sp_runtime::traits::{
@@ -68,6 +68,12 @@ pub type Hash = sp_core::H256;
 /// Digest item type.
 pub type DigestItem = generic::DigestItem<Hash>;
 
+/// A timestamp: milliseconds since the unix epoch.
+pub type Moment = u64;
+
+/// Id of an asset, including carbon credits assets.
+pub type AssetId = u64;
+
 /// Opaque types. These are used by the CLI to instantiate machinery that don't need to know
 /// the specifics of the runtime. They can then be made to be agnostic over specific formats
 /// of data like extrinsics, allowing for them to continue syncing the network through upgrades
@@ -220,7 +226,7 @@ parameter_types! {
 
 impl pallet_timestamp::Config for Runtime {
 	/// A timestamp: milliseconds since the unix epoch.
-	type Moment = u64;
+	type Moment = Moment;
 	type OnTimestampSet = Aura;
 	type MinimumPeriod = MinimumPeriod;
 	type WeightInfo = ();
@@ -285,7 +291,7 @@ parameter_types! {
 impl pallet_evercity_assets::Config for Runtime {
     type Event = Event;
     type Balance = Balance;
-    type AssetId = u64;
+    type AssetId = AssetId;
     type Currency = Balances;
     type ForceOrigin = frame_system::EnsureRoot<AccountId>;
     type AssetDepositBase = AssetDepositBase;
@@ -297,8 +303,14 @@ impl pallet_evercity_assets::Config for Runtime {
 }
 
 pub use pallet_evercity_carbon_credits;
+parameter_types! {
+    pub const CarbonCreditsModuleId: ModuleId = ModuleId(*b"ev/carbn");
+}
+
 impl pallet_evercity_carbon_credits::Config for Runtime {
     type Event = Event;
+    type ModuleId = CarbonCreditsModuleId;
+    type WeightInfo = pallet_evercity_carbon_credits::weights::SubstrateWeight<Runtime>;
 }
 
 pub use pallet_evercity_accounts;
@@ -324,7 +336,7 @@ construct_runtime!(
 		// Include the custom logic from the template pallet in the runtime.
 		TemplateModule: pallet_template::{Module, Call, Storage, Event<T>},
 
-		EvercityCarbonCredits: pallet_evercity_carbon_credits::{ Module, Call, Storage, Event<T>},
+		EvercityCarbonCredits: pallet_evercity_carbon_credits::{ Module, Call, Storage, Config, Event<T>},
         EvercityAccounts: pallet_evercity_accounts::{ Module, Call, Storage, Config<T>, Event<T>},
         EvercityFilesign: pallet_evercity_filesign::{ Module, Call, Storage, Event<T> },
         EvercityAssets: pallet_evercity_assets::{ Module, Storage, Event<T> },
@@ -496,6 +508,74 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_evercity_carbon_credits::runtime_api::CarbonCreditsApi<Block, AccountId, AssetId, Balance, Moment, BlockNumber>
+		for Runtime {
+		fn carbon_credits_asset_info(
+			asset_id: AssetId,
+		) -> Option<pallet_evercity_carbon_credits::runtime_api::CarbonCreditsAssetInfo<AccountId, AssetId, Moment, Balance>> {
+			EvercityCarbonCredits::get_carbon_credits_asset_info(asset_id)
+		}
+		fn projects_by_owner(
+			owner: AccountId,
+		) -> Vec<pallet_evercity_carbon_credits::project::ProjectStruct<AccountId>> {
+			EvercityCarbonCredits::get_projects_by_owner(owner)
+		}
+		fn project_assets(
+			project_id: pallet_evercity_carbon_credits::project::ProjectId,
+		) -> Vec<(u64, AssetId)> {
+			EvercityCarbonCredits::get_project_assets(project_id)
+		}
+		fn project_carbon_credits_totals(
+			project_id: pallet_evercity_carbon_credits::project::ProjectId,
+		) -> pallet_evercity_carbon_credits::carbon_credits_totals::CarbonCreditsTotals<Balance> {
+			EvercityCarbonCredits::get_project_carbon_credits_totals(project_id)
+		}
+		fn carbon_credits_total() -> pallet_evercity_carbon_credits::carbon_credits_totals::CarbonCreditsTotals<Balance> {
+			EvercityCarbonCredits::get_carbon_credits_total()
+		}
+		fn standard_carbon_credits_totals(
+			standard: pallet_evercity_carbon_credits::standard::Standard,
+		) -> pallet_evercity_carbon_credits::carbon_credits_totals::CarbonCreditsTotals<Balance> {
+			EvercityCarbonCredits::get_standard_carbon_credits_totals(standard)
+		}
+		fn vintage_carbon_credits_totals(
+			vintage: u16,
+		) -> pallet_evercity_carbon_credits::carbon_credits_totals::CarbonCreditsTotals<Balance> {
+			EvercityCarbonCredits::get_vintage_carbon_credits_totals(vintage)
+		}
+		fn projects_by_state(
+			state: pallet_evercity_carbon_credits::project::ProjectStateMask,
+		) -> Vec<pallet_evercity_carbon_credits::project::ProjectId> {
+			EvercityCarbonCredits::get_projects_by_state(state)
+		}
+		fn projects_pending_signature(
+			signer: AccountId,
+		) -> Vec<pallet_evercity_carbon_credits::project::ProjectId> {
+			EvercityCarbonCredits::get_projects_pending_signature(signer)
+		}
+		fn annual_reports_pending_signature(
+			signer: AccountId,
+		) -> Vec<(pallet_evercity_carbon_credits::project::ProjectId, u64)> {
+			EvercityCarbonCredits::get_annual_reports_pending_signature(signer)
+		}
+		fn certificates_by_account(
+			account: AccountId,
+		) -> Vec<pallet_evercity_carbon_credits::burn_certificate::CarbonCreditsBurnCertificate<AssetId, Balance>> {
+			EvercityCarbonCredits::get_certificates_by_account(account)
+		}
+		fn retirement_certificates_by_account(
+			account: AccountId,
+		) -> Vec<pallet_evercity_carbon_credits::burn_certificate::RetirementCertificate<AccountId, AssetId, Balance, Moment, BlockNumber>> {
+			EvercityCarbonCredits::get_retirement_certificates_by_account(account)
+		}
+		fn carbon_credits_serial_ranges(
+			asset_id: AssetId,
+			account: AccountId,
+		) -> Option<pallet_evercity_carbon_credits::serial_range::CarbonCreditsSerialRanges<Balance>> {
+			EvercityCarbonCredits::get_carbon_credits_serial_ranges(asset_id, account)
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn dispatch_benchmark(
@@ -530,6 +610,7 @@ impl_runtime_apis! {
 			add_benchmark!(params, batches, frame_system, SystemBench::<Runtime>);
 			add_benchmark!(params, batches, pallet_balances, Balances);
 			add_benchmark!(params, batches, pallet_timestamp, Timestamp);
+			add_benchmark!(params, batches, pallet_evercity_carbon_credits, EvercityCarbonCredits);
 
 			if batches.is_empty() { return Err("Benchmark not found for this pallet.".into()) }
 			Ok(batches)
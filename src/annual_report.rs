@@ -1,12 +1,26 @@
 use frame_support::{
     codec::{Decode, Encode},
-    sp_runtime::RuntimeDebug,
+    sp_runtime::{RuntimeDebug, traits::AtLeast32BitUnsigned},
     dispatch::Vec,
 };
+#[cfg(feature = "std")]
+use serde::{Serialize, Deserialize};
 use crate::required_signers::RequiredSigner;
+use crate::migration::AnnualReportStructV1;
+use pallet_evercity_accounts::accounts::{
+    RoleMask,
+    CC_PROJECT_OWNER_ROLE_MASK,
+    CC_AUDITOR_ROLE_MASK,
+    CC_STANDARD_ROLE_MASK,
+    CC_REGISTRY_ROLE_MASK,
+    CC_INVESTOR_ROLE_MASK,
+};
 use pallet_evercity_filesign::file::FileId;
 
 pub type AnnualReportStateMask = u16;
+/// Vintage of monitoring periods, that were not recorded, e.g. of annual reports migrated from storage version 1.
+/// Version 1 has no data to derive the vintage year from, so their carbon credits are totalled under this vintage
+pub const UNKNOWN_VINTAGE: u16 = 0;
 pub const REPORT_PROJECT_OWNER_SIGN_PENDING: AnnualReportStateMask = 1;
 pub const REPORT_AUDITOR_SIGN_PENDING: AnnualReportStateMask = 2;
 pub const REPORT_STANDARD_SIGN_PENDING: AnnualReportStateMask = 4;
@@ -14,10 +28,22 @@ pub const REPORT_INVESTOR_SIGN_PENDING: AnnualReportStateMask = 8;
 pub const REPORT_REGISTRY_SIGN_PENDING: AnnualReportStateMask = 16;
 pub const REPORT_ISSUED: AnnualReportStateMask = 32;
 
+/// State of the annual report, when signature of the role is pending
+pub fn report_state_by_role(role: RoleMask) -> Option<AnnualReportStateMask> {
+    match role {
+        CC_PROJECT_OWNER_ROLE_MASK => Some(REPORT_PROJECT_OWNER_SIGN_PENDING),
+        CC_AUDITOR_ROLE_MASK => Some(REPORT_AUDITOR_SIGN_PENDING),
+        CC_STANDARD_ROLE_MASK => Some(REPORT_STANDARD_SIGN_PENDING),
+        CC_INVESTOR_ROLE_MASK => Some(REPORT_INVESTOR_SIGN_PENDING),
+        CC_REGISTRY_ROLE_MASK => Some(REPORT_REGISTRY_SIGN_PENDING),
+        _ => None,
+    }
+}
+
 /// Generic annual report implementation
 pub type AnnualReportStruct<AccountId, T, Balance> = AnnualReportStructT<AccountId, <T as pallet_timestamp::Config>::Moment, Balance>;
 
-/// Main annual report implementation
+/// Main annual report implementation, stored separately from the project
 #[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq)]
 pub struct AnnualReportStructT<AccountId, Moment, Balance> where Balance: Clone, AccountId: PartialEq {
     pub file_id: FileId,
@@ -25,21 +51,35 @@ pub struct AnnualReportStructT<AccountId, Moment, Balance> where Balance: Clone,
     pub carbon_credits_meta: CarbonCreditsMeta,
     #[codec(compact)]
     create_time: Moment,
+    pub monitoring_period: MonitoringPeriod<Moment>,
     carbon_credits_count: Balance,
+    /// Carbon credits, released in all tranches
+    carbon_credits_issued: Balance,
     carbon_credits_released: bool,
     required_signers: Vec<RequiredSigner<AccountId>>,
+    /// Rejections are stored separately, indexed from 0 to rejections_count
+    pub rejections_count: u32,
 }
 
-impl<AccountId, Moment, Balance> AnnualReportStructT<AccountId, Moment, Balance> where Balance: Clone, AccountId: PartialEq {
-    pub fn new(file_id: FileId, carbon_credits_count: Balance, create_time: Moment, carbon_credits_meta: CarbonCreditsMeta) -> Self {
+impl<AccountId, Moment, Balance> AnnualReportStructT<AccountId, Moment, Balance> where Balance: Clone + Default, AccountId: PartialEq {
+    pub fn new(
+        file_id: FileId, 
+        carbon_credits_count: Balance, 
+        create_time: Moment, 
+        carbon_credits_meta: CarbonCreditsMeta,
+        monitoring_period: MonitoringPeriod<Moment>,
+    ) -> Self {
         AnnualReportStructT{
             file_id,
             state: REPORT_PROJECT_OWNER_SIGN_PENDING,
             carbon_credits_meta,
             required_signers: Vec::new(),
             create_time,
+            monitoring_period,
             carbon_credits_count,
+            carbon_credits_issued: Balance::default(),
             carbon_credits_released: false,
+            rejections_count: 0,
         }
     }
 
@@ -47,14 +87,14 @@ impl<AccountId, Moment, Balance> AnnualReportStructT<AccountId, Moment, Balance>
         self.carbon_credits_released
     }
 
-    pub fn set_carbon_credits_released(&mut self) {
-        self.carbon_credits_released = true;
-    }
-
     pub fn carbon_credits_count(&self) -> Balance {
         self.carbon_credits_count.clone()
     }
 
+    pub fn carbon_credits_issued(&self) -> Balance {
+        self.carbon_credits_issued.clone()
+    }
+
     pub fn change_carbon_credits_count(&mut self, new_count: Balance) {
         if self.state == REPORT_PROJECT_OWNER_SIGN_PENDING {
             self.carbon_credits_count = new_count;
@@ -69,6 +109,13 @@ impl<AccountId, Moment, Balance> AnnualReportStructT<AccountId, Moment, Balance>
         self.state == REPORT_ISSUED
     }
 
+    /// Returns report to the owner for corrections, returning index of the new rejection
+    pub fn reject(&mut self) -> u32 {
+        self.state = REPORT_PROJECT_OWNER_SIGN_PENDING;
+        self.rejections_count += 1;
+        self.rejections_count - 1
+    }
+
     pub fn assign_required_signer(&mut self, signer: RequiredSigner<AccountId>) {
         if !self.required_signers.iter().any(|(acc, role)| *acc == signer.0 && *role == signer.1) {
             self.required_signers.push(signer);
@@ -89,6 +136,90 @@ impl<AccountId, Moment, Balance> AnnualReportStructT<AccountId, Moment, Balance>
     pub fn is_required_signer(&self, signer: RequiredSigner<AccountId>) -> bool {
         self.required_signers.iter().any(|(acc, role)| *acc == signer.0 && *role == signer.1)
     }
+
+    /// Account is required signer with any role
+    pub fn has_required_signer(&self, account: &AccountId) -> bool {
+        self.required_signers.iter().any(|(acc, _)| acc == account)
+    }
+
+    pub fn get_required_signers(&self) -> &[RequiredSigner<AccountId>] {
+        &self.required_signers
+    }
+}
+
+impl<AccountId, Moment, Balance> AnnualReportStructT<AccountId, Moment, Balance> where Balance: AtLeast32BitUnsigned + Copy, AccountId: PartialEq {
+    /// Verified carbon credits, that are not released yet
+    pub fn remaining_carbon_credits(&self) -> Balance {
+        self.carbon_credits_count.saturating_sub(self.carbon_credits_issued)
+    }
+
+    /// At least one tranche of carbon credits is released
+    pub fn has_carbon_credits_issued(&self) -> bool {
+        !self.carbon_credits_issued.is_zero()
+    }
+
+    /// Registers released tranche, report is released when all verified carbon credits are issued
+    pub fn issue_carbon_credits(&mut self, amount: Balance) {
+        self.carbon_credits_issued = self.carbon_credits_issued.saturating_add(amount);
+        if self.carbon_credits_issued >= self.carbon_credits_count {
+            self.carbon_credits_released = true;
+        }
+    }
+}
+
+impl<AccountId, Moment, Balance> From<AnnualReportStructV1<AccountId, Moment, Balance>> for AnnualReportStructT<AccountId, Moment, Balance> 
+    where Balance: Clone + Default, AccountId: PartialEq, Moment: Default 
+{
+    fn from(old: AnnualReportStructV1<AccountId, Moment, Balance>) -> Self {
+        AnnualReportStructT{
+            file_id: old.file_id,
+            state: old.state,
+            carbon_credits_meta: old.carbon_credits_meta.into(),
+            create_time: old.create_time,
+            // Reports created before monitoring periods were introduced have empty period
+            monitoring_period: MonitoringPeriod::default(),
+            // Carbon credits were released at once before tranches were introduced
+            carbon_credits_issued: if old.carbon_credits_released { old.carbon_credits_count.clone() } else { Balance::default() },
+            carbon_credits_count: old.carbon_credits_count,
+            carbon_credits_released: old.carbon_credits_released,
+            required_signers: old.required_signers,
+            rejections_count: 0,
+        }
+    }
+}
+
+/// Period, when the emission reduction of the annual report happened
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct MonitoringPeriod<Moment> {
+    #[codec(compact)]
+    pub start: Moment,
+    #[codec(compact)]
+    pub end: Moment,
+    pub vintage: u16,
+}
+
+impl<Moment: PartialOrd> MonitoringPeriod<Moment> {
+    pub fn new(start: Moment, end: Moment, vintage: u16) -> Self {
+        MonitoringPeriod {
+            start,
+            end,
+            vintage,
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.start < self.end && self.vintage != UNKNOWN_VINTAGE
+    }
+
+    pub fn overlaps(&self, other: &MonitoringPeriod<Moment>) -> bool {
+        self.start < other.end && other.start < self.end
+    }
+
+    /// Period has finished by the given moment
+    pub fn is_ended(&self, now: &Moment) -> bool {
+        self.end <= *now
+    }
 }
 
 #[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq)]
@@ -0,0 +1,90 @@
+//! Benchmarking setup for pallet-evercity-carbon-credits
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::{benchmarks, account, whitelisted_caller};
+use frame_support::traits::Currency;
+use frame_system::RawOrigin;
+use sp_runtime::traits::Bounded;
+use annual_report::MonitoringPeriod;
+
+const SEED: u32 = 0;
+const CARBON_CREDITS_COUNT: u32 = 1_000;
+
+type CurrencyBalanceOf<T> = <<T as pallet_assets::Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+/// Account must exist to hold carbon credits
+fn fund_account<T: Config>(account: &T::AccountId) {
+    let balance = CurrencyBalanceOf::<T>::max_value() / 1_000u32.into();
+    <T as pallet_assets::Config>::Currency::make_free_balance_be(account, balance);
+}
+
+/// Creates carbon credits asset with passport, minting all carbon credits to the holder
+fn create_carbon_credits<T: Config>(holder: &T::AccountId, asset_id: AssetId<T>) {
+    let holder_source = <T::Lookup as StaticLookup>::unlookup(holder.clone());
+    let origin: T::Origin = RawOrigin::Signed(holder.clone()).into();
+    let amount: T::Balance = CARBON_CREDITS_COUNT.into();
+    pallet_assets::Call::<T>::create(asset_id, holder_source.clone(), 0, 1u32.into())
+        .dispatch_bypass_filter(origin.clone())
+        .expect("asset is created");
+    pallet_assets::Call::<T>::mint(asset_id, holder_source, amount)
+        .dispatch_bypass_filter(origin)
+        .expect("carbon credits are minted");
+    CarbonCreditPassportRegistry::<T>::insert(asset_id,
+        CarbonCreditsPassport::new(asset_id, 1, 1, MonitoringPeriod::default(), amount)
+    );
+    SerialRanges::<T>::insert(asset_id, holder, sp_std::vec![SerialRange::following(Zero::zero(), amount)]);
+}
+
+/// Transfers of carbon credits of different assets to different accounts
+fn setup_transfers<T: Config>(holder: &T::AccountId, count: u32) -> Vec<(AssetId<T>, T::AccountId, T::Balance)>
+    where AssetId<T>: From<u32>
+{
+    fund_account::<T>(holder);
+    (0..count).map(|i| {
+        let asset_id: AssetId<T> = (i + 1).into();
+        let recipient: T::AccountId = account("recipient", i, SEED);
+        fund_account::<T>(&recipient);
+        create_carbon_credits::<T>(holder, asset_id);
+        (asset_id, recipient, 1u32.into())
+    })
+    .collect()
+}
+
+benchmarks! {
+    where_clause { where AssetId<T>: From<u32> }
+
+    transfer_carbon_credits {
+        let caller: T::AccountId = whitelisted_caller();
+        let (asset_id, recipient, amount) = setup_transfers::<T>(&caller, 1).remove(0);
+    }: _(RawOrigin::Signed(caller.clone()), asset_id, recipient.clone(), amount)
+    verify {
+        assert_eq!(amount, pallet_assets::Pallet::<T>::balance(asset_id, recipient));
+    }
+
+    batch_transfer_carbon_credits {
+        let b in 1 .. MAX_BATCH_TRANSFERS as u32;
+        let caller: T::AccountId = whitelisted_caller();
+        let transfers = setup_transfers::<T>(&caller, b);
+        let (last_asset_id, last_recipient, amount) = transfers[transfers.len() - 1].clone();
+    }: _(RawOrigin::Signed(caller.clone()), transfers)
+    verify {
+        assert_eq!(amount, pallet_assets::Pallet::<T>::balance(last_asset_id, last_recipient));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::mock::{new_test_ext, TestRuntime};
+    use frame_support::assert_ok;
+
+    #[test]
+    fn test_benchmarks() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(test_benchmark_transfer_carbon_credits::<TestRuntime>());
+            assert_ok!(test_benchmark_batch_transfer_carbon_credits::<TestRuntime>());
+        });
+    }
+}
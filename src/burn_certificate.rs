@@ -1,9 +1,19 @@
 use frame_support::{
     codec::{Decode, Encode},
     sp_runtime::RuntimeDebug,
+    dispatch::Vec,
 };
+#[cfg(feature = "std")]
+use serde::{Serialize, Deserialize};
+use crate::project::ProjectId;
+use crate::serial_range::SerialRange;
+
+pub type CertificateId = u64;
+pub const MAX_BENEFICIARY_NAME_LEN: usize = 128;
+pub const MAX_RETIREMENT_REASON_LEN: usize = 256;
 
 #[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct CarbonCreditsBurnCertificate<AssetId, Balance> {
     pub asset_id: AssetId,
     pub burn_amount: Balance,
@@ -16,4 +26,32 @@ impl<AssetId, Balance> CarbonCreditsBurnCertificate<AssetId, Balance>{
             burn_amount
         }
     }
-}
\ No newline at end of file
+}
+
+/// Certificate of a single retirement of carbon credits
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct RetirementCertificate<AccountId, AssetId, Balance, Moment, BlockNumber> {
+    pub id: CertificateId,
+    /// Account, which burned carbon credits
+    pub retired_by: AccountId,
+    /// Account, on behalf of which the emissions are offset
+    pub beneficiary: AccountId,
+    pub beneficiary_name: Vec<u8>,
+    pub reason: Vec<u8>,
+    pub asset_id: AssetId,
+    pub amount: Balance,
+    /// Serial numbers of retired carbon credits
+    pub serial_ranges: Vec<SerialRange<Balance>>,
+    pub project_id: ProjectId,
+    /// Index of the annual report in the project, starting from 0
+    pub annual_report_index: u64,
+    #[codec(compact)]
+    pub timestamp: Moment,
+    pub block_number: BlockNumber,
+}
+
+/// Beneficiary name and retirement reason are optional, but bounded
+pub fn is_valid_retirement_description(beneficiary_name: &[u8], reason: &[u8]) -> bool {
+    beneficiary_name.len() <= MAX_BENEFICIARY_NAME_LEN && reason.len() <= MAX_RETIREMENT_REASON_LEN
+}
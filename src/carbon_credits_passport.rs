@@ -1,25 +1,69 @@
 use crate::project::ProjectId;
+use crate::annual_report::MonitoringPeriod;
+use crate::migration::CarbonCreditsPassportV1;
 use frame_support::{
     codec::{Decode, Encode},
-    sp_runtime::RuntimeDebug,
+    sp_runtime::{RuntimeDebug, traits::AtLeast32BitUnsigned},
 };
+#[cfg(feature = "std")]
+use serde::{Serialize, Deserialize};
 
 /// Passport, that prooves, that an asset is a carbon credit asset
 #[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq)]
-pub struct CarbonCreditsPassport<AssetId>{
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct CarbonCreditsPassport<AssetId, Moment, Balance>{
     asset_id: AssetId,
     project_id: ProjectId,
     annual_report_index: u64,
+    monitoring_period: MonitoringPeriod<Moment>,
+    /// Carbon credits, verified in the annual report
+    carbon_credits_count: Balance,
+    /// Carbon credits, released in all tranches
+    carbon_credits_issued: Balance,
+    /// Carbon credits, minted to the buffer pool and not cancelled yet
+    buffer_carbon_credits: Balance,
+    /// Carbon credits, cancelled or burned to cover reversals of the project
+    reversed_carbon_credits: Balance,
+    /// Transfers and retirements are stopped by registry
+    is_frozen: bool,
 }
 
-impl<AssetId> CarbonCreditsPassport<AssetId> {
-    pub fn new(asset_id: AssetId, project_id: ProjectId, annual_report_index: usize) -> Self {
+impl<AssetId, Moment, Balance: AtLeast32BitUnsigned + Copy> CarbonCreditsPassport<AssetId, Moment, Balance> {
+    pub fn new(
+        asset_id: AssetId, 
+        project_id: ProjectId, 
+        annual_report_index: usize, 
+        monitoring_period: MonitoringPeriod<Moment>,
+        carbon_credits_count: Balance,
+    ) -> Self {
         let annual_report_index_inner = annual_report_index as u64;
 
         CarbonCreditsPassport{
             asset_id,
             project_id,
             annual_report_index: annual_report_index_inner,
+            monitoring_period,
+            carbon_credits_count,
+            carbon_credits_issued: Balance::zero(),
+            buffer_carbon_credits: Balance::zero(),
+            reversed_carbon_credits: Balance::zero(),
+            is_frozen: false,
+        }
+    }
+
+    /// Converts passport from storage version 1, when carbon credits of the report were released at once
+    pub(crate) fn from_v1(old: CarbonCreditsPassportV1<AssetId>, carbon_credits_count: Balance) -> Self where Moment: Default {
+        CarbonCreditsPassport{
+            asset_id: old.asset_id,
+            project_id: old.project_id,
+            annual_report_index: old.annual_report_index,
+            // Version 1 has no monitoring period, vintage is UNKNOWN_VINTAGE
+            monitoring_period: MonitoringPeriod::default(),
+            carbon_credits_count,
+            carbon_credits_issued: carbon_credits_count,
+            buffer_carbon_credits: Balance::zero(),
+            reversed_carbon_credits: Balance::zero(),
+            is_frozen: false,
         }
     }
 
@@ -38,4 +82,54 @@ impl<AssetId> CarbonCreditsPassport<AssetId> {
     pub fn get_last_report_index(&self) -> usize { 
         self.annual_report_index as usize
     }
-}
\ No newline at end of file
+
+    pub fn get_monitoring_period(&self) -> &MonitoringPeriod<Moment> {
+        &self.monitoring_period
+    }
+
+    pub fn get_vintage(&self) -> u16 {
+        self.monitoring_period.vintage
+    }
+
+    pub fn get_carbon_credits_count(&self) -> Balance {
+        self.carbon_credits_count
+    }
+
+    pub fn get_carbon_credits_issued(&self) -> Balance {
+        self.carbon_credits_issued
+    }
+
+    pub fn get_remaining_carbon_credits(&self) -> Balance {
+        self.carbon_credits_count.saturating_sub(self.carbon_credits_issued)
+    }
+
+    pub fn get_buffer_carbon_credits(&self) -> Balance {
+        self.buffer_carbon_credits
+    }
+
+    /// Registers issued carbon credits, buffer share is included into amount
+    pub fn issue_carbon_credits(&mut self, amount: Balance, buffer_amount: Balance) {
+        self.carbon_credits_issued = self.carbon_credits_issued.saturating_add(amount);
+        self.buffer_carbon_credits = self.buffer_carbon_credits.saturating_add(buffer_amount);
+    }
+
+    pub fn cancel_buffer_carbon_credits(&mut self, amount: Balance) {
+        self.buffer_carbon_credits = self.buffer_carbon_credits.saturating_sub(amount);
+    }
+
+    pub fn get_reversed_carbon_credits(&self) -> Balance {
+        self.reversed_carbon_credits
+    }
+
+    pub fn reverse_carbon_credits(&mut self, amount: Balance) {
+        self.reversed_carbon_credits = self.reversed_carbon_credits.saturating_add(amount);
+    }
+
+    pub fn is_frozen(&self) -> bool {
+        self.is_frozen
+    }
+
+    pub fn set_frozen(&mut self, is_frozen: bool) {
+        self.is_frozen = is_frozen;
+    }
+}
@@ -0,0 +1,40 @@
+use frame_support::{
+    codec::{Decode, Encode},
+    sp_runtime::{RuntimeDebug, traits::AtLeast32BitUnsigned},
+};
+#[cfg(feature = "std")]
+use serde::{Serialize, Deserialize};
+
+/// Aggregate amounts of carbon credits
+#[derive(Encode, Decode, Clone, Copy, Default, RuntimeDebug, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct CarbonCreditsTotals<Balance> {
+    /// Released in all tranches, buffer share included
+    pub issued: Balance,
+    /// Burned by holders with retirement certificates
+    pub retired: Balance,
+    /// Minted to the buffer pool
+    pub buffered: Balance,
+    /// Cancelled from the buffer pool or burned to cover reversals
+    pub cancelled: Balance,
+}
+
+impl<Balance: AtLeast32BitUnsigned + Copy> CarbonCreditsTotals<Balance> {
+    /// Issued carbon credits, that are neither retired nor cancelled
+    pub fn outstanding(&self) -> Balance {
+        self.issued.saturating_sub(self.retired).saturating_sub(self.cancelled)
+    }
+
+    pub fn issue(&mut self, amount: Balance, buffer_amount: Balance) {
+        self.issued = self.issued.saturating_add(amount);
+        self.buffered = self.buffered.saturating_add(buffer_amount);
+    }
+
+    pub fn retire(&mut self, amount: Balance) {
+        self.retired = self.retired.saturating_add(amount);
+    }
+
+    pub fn cancel(&mut self, amount: Balance) {
+        self.cancelled = self.cancelled.saturating_add(amount);
+    }
+}
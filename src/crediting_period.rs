@@ -0,0 +1,65 @@
+use frame_support::{
+    codec::{Decode, Encode},
+    sp_runtime::RuntimeDebug,
+    dispatch::Vec,
+};
+use crate::annual_report::MonitoringPeriod;
+use pallet_evercity_accounts::accounts::RoleMask;
+
+/// Period, in which the project can generate carbon credits, granted by the standard on registration
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq)]
+pub struct CreditingPeriod<Moment> {
+    #[codec(compact)]
+    pub start: Moment,
+    #[codec(compact)]
+    pub end: Moment,
+    pub renewals_count: u8,
+    /// New end of the period, waiting for signatures of the renewal
+    pub pending_renewal_end: Option<Moment>,
+    /// Signing order of the pending renewal, fixed when the owner submits it
+    pub renewal_signing_order: Vec<RoleMask>,
+}
+
+impl<Moment: PartialOrd> CreditingPeriod<Moment> {
+    pub fn new(start: Moment, end: Moment) -> Self {
+        CreditingPeriod {
+            start,
+            end,
+            renewals_count: 0,
+            pending_renewal_end: None,
+            renewal_signing_order: Vec::new(),
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.start < self.end
+    }
+
+    pub fn contains(&self, monitoring_period: &MonitoringPeriod<Moment>) -> bool {
+        self.start <= monitoring_period.start && monitoring_period.end <= self.end
+    }
+
+    pub fn is_renewal_pending(&self) -> bool {
+        self.pending_renewal_end.is_some()
+    }
+
+    pub fn submit_renewal(&mut self, end: Moment, signing_order: Vec<RoleMask>) {
+        self.pending_renewal_end = Some(end);
+        self.renewal_signing_order = signing_order;
+    }
+
+    /// Extends the period till the end, approved by all signers
+    pub fn apply_renewal(&mut self) {
+        if let Some(end) = self.pending_renewal_end.take() {
+            self.end = end;
+            self.renewals_count += 1;
+        }
+        self.renewal_signing_order.clear();
+    }
+
+    /// Drops rejected renewal, keeping the current end of the period
+    pub fn cancel_renewal(&mut self) {
+        self.pending_renewal_end = None;
+        self.renewal_signing_order.clear();
+    }
+}
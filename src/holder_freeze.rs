@@ -0,0 +1,21 @@
+use frame_support::{
+    codec::{Decode, Encode},
+    sp_runtime::RuntimeDebug,
+};
+use pallet_evercity_filesign::file::H256;
+
+/// Record about freeze of carbon credits of a single holder, e.g. by sanctions or court order
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq)]
+pub struct HolderFreezeStruct<AccountId> {
+    pub frozen_by: AccountId,
+    pub reason_hash: H256,
+}
+
+impl<AccountId> HolderFreezeStruct<AccountId> {
+    pub fn new(frozen_by: AccountId, reason_hash: H256) -> Self {
+        HolderFreezeStruct {
+            frozen_by,
+            reason_hash,
+        }
+    }
+}
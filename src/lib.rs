@@ -8,10 +8,22 @@
 
 pub mod standard;
 pub mod project;
+pub mod project_metadata;
+pub mod crediting_period;
 pub mod annual_report;
 pub mod required_signers;
 pub mod carbon_credits_passport;
 pub mod burn_certificate;
+pub mod rejection;
+pub mod reversal;
+pub mod holder_freeze;
+pub mod serial_range;
+pub mod carbon_credits_totals;
+pub mod runtime_api;
+pub mod migration;
+pub mod weights;
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
 #[cfg(test)]    
 pub mod tests;
 
@@ -24,25 +36,46 @@ use frame_support::{
     decl_event,
     dispatch::{
         DispatchResult,
+        DispatchResultWithPostInfo,
+        DispatchError,
         Vec,
     },
+    storage::{IterableStorageMap, IterableStorageDoubleMap},
     traits::UnfilteredDispatchable,
+    weights::Weight,
+    transactional,
 };
 use frame_system::{
     ensure_signed,
+    ensure_root,
+};
+use sp_runtime::{
+    ModuleId,
+    Percent,
+    traits::{StaticLookup, Zero, AccountIdConversion},
 };
-use sp_runtime::traits::StaticLookup;
 use frame_support::sp_std::{
     cmp::{
         Eq, 
         PartialEq}, 
 };
-use project::{ProjectStruct, ProjectId};
-use standard::Standard;
-use pallet_evercity_filesign::file::{FileId};
+use project::{ProjectStruct, ProjectId, ProjectStateMask};
+use project_metadata::ProjectMetadata;
+use crediting_period::CreditingPeriod;
+use required_signers::RequiredSigner;
+use standard::{Standard, StandardStruct};
+use pallet_evercity_filesign::file::{FileId, H256};
 use pallet_evercity_accounts::accounts::RoleMask;
 use carbon_credits_passport::CarbonCreditsPassport;
-use burn_certificate::CarbonCreditsBurnCertificate;
+use burn_certificate::{CarbonCreditsBurnCertificate, RetirementCertificate, CertificateId};
+use rejection::RejectionStruct;
+use reversal::ReversalStruct;
+use holder_freeze::HolderFreezeStruct;
+use serial_range::{SerialRange, CarbonCreditsSerialRanges};
+use carbon_credits_totals::CarbonCreditsTotals;
+use runtime_api::CarbonCreditsAssetInfo;
+use migration::Releases;
+pub use weights::WeightInfo;
 
 use pallet_evercity_assets as pallet_assets;
 use pallet_evercity_accounts as accounts;
@@ -56,9 +89,34 @@ pub trait Config:
     pallet_assets::Config + 
     pallet_evercity_filesign::Config {
         type Event: From<Event<Self>> + Into<<Self as frame_system::Config>::Event>;
+        /// Id of the pallet, used to derive buffer pool account
+        type ModuleId: Get<ModuleId>;
+        /// Weight information for extrinsics of the pallet
+        type WeightInfo: WeightInfo;
 }
 
+/// Maximum number of transfers in batch_transfer_carbon_credits
+pub const MAX_BATCH_TRANSFERS: usize = 100;
+/// Maximum number of holders, whose carbon credits are burned by a single reversal
+pub const MAX_REVERSAL_HOLDERS: usize = 100;
+/// Maximum number of annual reports in a project, deleted reports are not counted
+pub const MAX_ANNUAL_REPORTS: u64 = 100;
+
 type AssetId<T> = <T as pallet_assets::Config>::AssetId;
+type Moment<T> = <T as pallet_timestamp::Config>::Moment;
+type RetirementCertificateOf<T> = RetirementCertificate<
+    <T as frame_system::Config>::AccountId, 
+    AssetId<T>, 
+    <T as pallet_assets::Config>::Balance, 
+    Moment<T>, 
+    <T as frame_system::Config>::BlockNumber,
+>;
+type CarbonCreditsAssetInfoOf<T> = CarbonCreditsAssetInfo<
+    <T as frame_system::Config>::AccountId, 
+    AssetId<T>, 
+    Moment<T>, 
+    <T as pallet_assets::Config>::Balance, 
+>;
 
 // Pallet Storage
 decl_storage! {
@@ -66,20 +124,144 @@ decl_storage! {
         /// Main storage for projects
         ProjectById
             get(fn project_by_id):
-            map hasher(blake2_128_concat) u32 => Option<ProjectStruct<T::AccountId, T, T::Balance>>;
+            map hasher(blake2_128_concat) u32 => Option<ProjectStruct<T::AccountId>>;
+
+        /// Projects of the owner
+        ProjectsByOwner
+            get(fn projects_by_owner):
+            double_map hasher(blake2_128_concat) T::AccountId, hasher(blake2_128_concat) ProjectId => ();
+
+        /// Projects in the state of the project state machine
+        ProjectsByState
+            get(fn projects_by_state):
+            double_map hasher(blake2_128_concat) ProjectStateMask, hasher(blake2_128_concat) ProjectId => ();
+
+        /// Projects, where account is required signer of the project or its annual reports,
+        /// with number of signer assignments of the account in the project and its reports
+        ProjectsBySigner
+            get(fn projects_by_signer):
+            double_map hasher(blake2_128_concat) T::AccountId, hasher(blake2_128_concat) ProjectId => u32;
 
         /// Incremented it of projects
         LastID: ProjectId;
 
+        /// Rejections of the project documentation, indexed from 0 in each project
+        ProjectRejections
+            get(fn project_rejection_by_index):
+            double_map hasher(blake2_128_concat) ProjectId, hasher(blake2_128_concat) u32 => Option<RejectionStruct<T::AccountId>>;
+
+        /// Storage for project metadata, set on project creation
+        ProjectMetadataById
+            get(fn project_metadata_by_id):
+            map hasher(blake2_128_concat) ProjectId => Option<ProjectMetadata>;
+
+        /// Storage for crediting periods of projects
+        CreditingPeriods
+            get(fn crediting_period_by_project_id):
+            map hasher(blake2_128_concat) ProjectId => Option<CreditingPeriod<Moment<T>>>;
+
+        /// Signatures of the pending crediting period renewal of the project, in signing order
+        CreditingPeriodRenewalSignatures
+            get(fn crediting_period_renewal_signatures):
+            map hasher(blake2_128_concat) ProjectId => Vec<RequiredSigner<T::AccountId>>;
+
+        /// Storage for annual reports of projects, indexed from 0 in each project
+        AnnualReports
+            get(fn annual_report_by_index):
+            double_map hasher(blake2_128_concat) ProjectId, hasher(blake2_128_concat) u64 => Option<annual_report::AnnualReportStruct<T::AccountId, T, T::Balance>>;
+
+        /// Rejections of annual reports, indexed from 0 in each report
+        AnnualReportRejections
+            get(fn annual_report_rejection_by_index):
+            double_map hasher(blake2_128_concat) (ProjectId, u64), hasher(blake2_128_concat) u32 => Option<RejectionStruct<T::AccountId>>;
+
         /// Storage for carbon credits passports
         CarbonCreditPassportRegistry
             get(fn registry_by_asseid):
-            map hasher(blake2_128_concat) AssetId<T> => Option<CarbonCreditsPassport<AssetId<T>>>;
+            map hasher(blake2_128_concat) AssetId<T> => Option<CarbonCreditsPassport<AssetId<T>, Moment<T>, <T as pallet_assets::Config>::Balance>>;
+
+        /// Carbon credits asset of the annual report, indexed by project and report index
+        AnnualReportAssets
+            get(fn annual_report_asset):
+            double_map hasher(blake2_128_concat) ProjectId, hasher(blake2_128_concat) u64 => Option<AssetId<T>>;
+
+        /// Issued, retired and cancelled carbon credits of all annual reports of the project
+        ProjectCarbonCreditsTotals
+            get(fn project_carbon_credits_totals):
+            map hasher(blake2_128_concat) ProjectId => CarbonCreditsTotals<T::Balance>;
+
+        /// Issued, retired and cancelled carbon credits of all projects by the current project standard
+        StandardCarbonCreditsTotals
+            get(fn standard_carbon_credits_totals):
+            map hasher(blake2_128_concat) Standard => CarbonCreditsTotals<T::Balance>;
+
+        /// Issued, retired and cancelled carbon credits of all projects by vintage,
+        /// carbon credits migrated from storage version 1 are under UNKNOWN_VINTAGE
+        VintageCarbonCreditsTotals
+            get(fn vintage_carbon_credits_totals):
+            map hasher(blake2_128_concat) u16 => CarbonCreditsTotals<T::Balance>;
+
+        /// Issued, retired and cancelled carbon credits of the whole ledger
+        CarbonCreditsTotal
+            get(fn carbon_credits_total):
+            CarbonCreditsTotals<T::Balance>;
+
+        /// Storage for standard definitions, set by governance
+        StandardDefinitions
+            get(fn standard_definition):
+            map hasher(blake2_128_concat) Standard => Option<StandardStruct>;
+
+        /// Number of projects of each standard
+        ProjectsCountByStandard
+            get(fn projects_count_by_standard):
+            map hasher(blake2_128_concat) Standard => u32;
 
         /// Storage for user burn sertificates
         BurnCertificates
             get(fn cert_by_account_id):
             map hasher(blake2_128_concat) T::AccountId => Vec<CarbonCreditsBurnCertificate<AssetId<T>, T::Balance>>;
+
+        /// Incremented id of retirement certificates
+        LastCertificateId: CertificateId;
+
+        /// Storage for certificates of individual retirements of carbon credits
+        RetirementCertificates
+            get(fn retirement_certificate_by_id):
+            map hasher(blake2_128_concat) CertificateId => Option<RetirementCertificateOf<T>>;
+
+        /// Ids of retirement certificates by beneficiary account
+        RetirementCertificatesByAccount
+            get(fn retirement_certificates_by_account):
+            map hasher(blake2_128_concat) T::AccountId => Vec<CertificateId>;
+
+        /// Carbon credits of the asset, burned by all holders
+        BurnedCarbonCredits
+            get(fn burned_carbon_credits):
+            map hasher(blake2_128_concat) AssetId<T> => T::Balance;
+
+        /// Reversals of projects, reported by registry, indexed from 0 in each project
+        ReversalsByProject
+            get(fn project_reversal_by_index):
+            double_map hasher(blake2_128_concat) ProjectId, hasher(blake2_128_concat) u32 => Option<ReversalStruct<T::AccountId, T::Balance>>;
+
+        /// Admins of carbon credits assets, that issued them. Assets, created in storage version 1, have no recorded admin
+        AssetAdmins
+            get(fn asset_admin):
+            map hasher(blake2_128_concat) AssetId<T> => Option<T::AccountId>;
+
+        /// Active freezes of carbon credits of single holders by asset
+        HolderFreezes
+            get(fn holder_freeze):
+            double_map hasher(blake2_128_concat) AssetId<T>, hasher(blake2_128_concat) T::AccountId => Option<HolderFreezeStruct<T::AccountId>>;
+
+        /// Serial numbers of carbon credits of the asset, held by account
+        SerialRanges
+            get(fn serial_ranges):
+            double_map hasher(blake2_128_concat) AssetId<T>, hasher(blake2_128_concat) T::AccountId => Vec<SerialRange<T::Balance>>;
+
+        /// Storage layout version, used by runtime upgrade migrations.
+        /// New chains start with the latest version, existing ones default to V1
+        StorageVersion get(fn storage_version) build(|_| Releases::V2): Releases;
     }
 }
 
@@ -90,7 +272,16 @@ decl_event!(
         AccountId = <T as frame_system::Config>::AccountId,
         AssetId = <T as pallet_assets::Config>::AssetId,
         Balance = <T as pallet_assets::Config>::Balance,
+        MonitoringPeriod = annual_report::MonitoringPeriod<<T as pallet_timestamp::Config>::Moment>,
+        Moment = <T as pallet_timestamp::Config>::Moment,
     {
+        // Standard Events:
+
+        /// \[Standard\]
+        StandardDefinitionSet(Standard),
+        /// \[Standard\]
+        StandardDefinitionRemoved(Standard),
+
         // Project Events:
 
         /// \[ProjectOwner, ProjectId\]
@@ -105,17 +296,35 @@ decl_event!(
         ProjectSignedByAduitor(AccountId, ProjectId),
         /// \[StandardRoleAccount, ProjectId\]
         ProjectSignedByStandard(AccountId, ProjectId),
+        /// \[Investor, ProjectId\]
+        ProjectSignedByInvestor(AccountId, ProjectId),
         /// \[Registry, ProjectId\]
         ProjectSignedByRegistry(AccountId, ProjectId),
+        /// \[Signer, ProjectId, Role, ReasonHash\]
+        ProjectRejected(AccountId, ProjectId, RoleMask, H256),
         /// \[ProjectOwner, Signer, Role, ProjectId\]
         ProjectSignerAdded(AccountId, AccountId, RoleMask, ProjectId),
         /// \[ProjectOwner, Signer, Role, ProjectId\]
         ProjectSignerRemoved(AccountId, AccountId, RoleMask, ProjectId),
+        /// \[ProjectOwner, ProjectId, InvestorSignRequired\]
+        ProjectInvestorSignRequiredChanged(AccountId, ProjectId, bool),
+        /// \[ProjectOwner, ProjectId\]
+        ProjectMetadataChanged(AccountId, ProjectId),
+        /// \[ProjectOwner, ProjectId, Start, End\]
+        ProjectCreditingPeriodChanged(AccountId, ProjectId, Moment, Moment),
+        /// \[ProjectOwner, ProjectId, NewEnd\]
+        ProjectCreditingPeriodRenewalSubmited(AccountId, ProjectId, Moment),
+        /// \[Signer, ProjectId, Role\]
+        ProjectCreditingPeriodRenewalSigned(AccountId, ProjectId, RoleMask),
+        /// \[Signer, ProjectId, Role, ReasonHash\]
+        ProjectCreditingPeriodRenewalRejected(AccountId, ProjectId, RoleMask, H256),
+        /// \[LastSigner, ProjectId, NewEnd\]
+        ProjectCreditingPeriodRenewed(AccountId, ProjectId, Moment),
 
         // Annual Report Events:
 
-        /// \[ProjectOwner, ProjectId\]
-        AnnualReportCreated(AccountId, ProjectId),
+        /// \[ProjectOwner, ProjectId, MonitoringPeriod\]
+        AnnualReportCreated(AccountId, ProjectId, MonitoringPeriod),
         /// \[ProjectOwner, ProjectId\]
         AnnualReportDeleted(AccountId, ProjectId),
         // \[ProjectOwner, ProjectId, NewCount\]
@@ -126,8 +335,12 @@ decl_event!(
         AnnualReportSignedByAuditor(AccountId, ProjectId),
         /// \[StandardRoleAccount, ProjectId\]
         AnnualReportSignedByStandard(AccountId, ProjectId),
+        /// \[Investor, ProjectId\]
+        AnnualReportSignedByInvestor(AccountId, ProjectId),
         /// \[Registry, ProjectId\]
         AnnualReportSignedByRegistry(AccountId, ProjectId),
+        /// \[Signer, ProjectId, Role, ReasonHash\]
+        AnnualReportRejected(AccountId, ProjectId, RoleMask, H256),
         /// \[ProjectOwner, Signer, Role, ProjectId\]
         AnnualReportSignerAdded(AccountId, AccountId, RoleMask, ProjectId),
         /// \[ProjectOwner, Signer, Role, ProjectId\]
@@ -139,12 +352,30 @@ decl_event!(
         CarbonCreditsAssetCreated(AccountId, ProjectId, AssetId),
         /// \[ProjectOwner, AssetId\]
         CarbonCreditsMetadataChanged(AccountId, AssetId),
-        /// \[ProjectOwner, ProjectId, AssetId\]
-        CarbonCreditsMinted(AccountId, ProjectId, AssetId),
+        /// \[ProjectOwner, ProjectId, AssetId, Vintage\]
+        CarbonCreditsMinted(AccountId, ProjectId, AssetId, u16),
         /// \[CarbonCreditsHolder, AccountToTransfer, AssetId\]
         CarbonCreditsTransfered(AccountId, AccountId, AssetId),
         /// \[ProjectOwner, AssetId\]
         CarbonCreditsAssetBurned(AccountId, AssetId),
+        /// \[CarbonCreditsHolder, Beneficiary, CertificateId\]
+        RetirementCertificateIssued(AccountId, AccountId, CertificateId),
+        /// \[AssetId, Amount\]
+        BufferCarbonCreditsCancelled(AssetId, Balance),
+        /// \[Registry, ProjectId, Amount\]
+        ReversalReported(AccountId, ProjectId, Balance),
+        /// \[AssetId, Amount\]
+        CarbonCreditsReversed(AssetId, Balance),
+        /// \[AssetId, Holder, Amount\]
+        HolderCarbonCreditsReversed(AssetId, AccountId, Balance),
+        /// \[Registry, AssetId\]
+        CarbonCreditsFrozen(AccountId, AssetId),
+        /// \[Registry, AssetId\]
+        CarbonCreditsThawed(AccountId, AssetId),
+        /// \[Registry, AssetId, Holder\]
+        CarbonCreditsHolderFrozen(AccountId, AssetId, AccountId),
+        /// \[Registry, AssetId, Holder\]
+        CarbonCreditsHolderThawed(AccountId, AssetId, AccountId),
     }
 );
 
@@ -156,6 +387,27 @@ decl_error! {
 
         /// Separate Error for project validation
         InvalidProjectState,
+        /// Project metadata fields are empty, too long or out of range
+        InvalidProjectMetadata,
+        /// Crediting period starts after its end or renewal doesnt extend it
+        InvalidCreditingPeriod,
+        /// Project doesnt have crediting period
+        CreditingPeriodNotSet,
+        /// Crediting period was renewed maximum times, allowed by the standard
+        CreditingPeriodRenewalsExceeded,
+        /// Renewal of the crediting period is already waiting for signatures
+        CreditingPeriodRenewalPending,
+        /// Crediting period doesnt have renewal, waiting for signatures
+        CreditingPeriodRenewalNotPending,
+
+        // Standard errors:
+
+        /// Standard is not defined neither in storage nor in built in standards
+        StandardNotExist,
+        /// Signing order of the standard is incorrect
+        InvalidStandardDefinition,
+        /// Custom standard can not be removed, while it has projects
+        StandardHasProjects,
 
         // Account errors:
 
@@ -190,6 +442,18 @@ decl_error! {
         NoAnnualReports,
         /// State of an annual report doesnt equal to Issued
         NotIssuedAnnualReportsExist,
+        /// Annual report with given index doesnt exist in the project
+        AnnualReportNotExist,
+        /// Project has reached MAX_ANNUAL_REPORTS
+        AnnualReportsLimitExceeded,
+        /// Monitoring period starts after its end or has no vintage
+        InvalidMonitoringPeriod,
+        /// Monitoring period overlaps with monitoring period of other annual report of the project
+        MonitoringPeriodOverlaps,
+        /// Monitoring period of the annual report has not ended yet
+        MonitoringPeriodNotEnded,
+        /// Monitoring period is not inside crediting period of the project
+        MonitoringPeriodOutsideCreditingPeriod,
 
         // Asset error
 
@@ -199,10 +463,26 @@ decl_error! {
         ErrorMintingAsset,
         /// Carbon credits are already created error
         CCAlreadyCreated,
+        /// Tranche amount is zero or exceeds carbon credits, remaining in the annual report
+        InvalidCarbonCreditsAmount,
+        /// Buffer pool doesnt have enough carbon credits of the asset
+        InsufficientBufferCarbonCredits,
+        /// Carbon credits asset is frozen by registry
+        CarbonCreditsAssetFrozen,
+        /// Carbon credits asset is already frozen or thawed
+        CarbonCreditsAssetFreezeUnchanged,
+        /// Carbon credits of the holder are frozen by registry
+        CarbonCreditsHolderFrozen,
+        /// Carbon credits of the holder are already frozen or thawed
+        CarbonCreditsHolderFreezeUnchanged,
         /// Carbon credits transfer failed
         TransferFailed,
+        /// Batch of transfers is empty or longer than MAX_BATCH_TRANSFERS
+        InvalidTransferBatch,
         /// Carbon Credits asset burn failed
         BurnFailed,
+        /// Beneficiary name or retirement reason is too long
+        BadRetirementParameters,
         /// Bad parameters of metadata
         BadMetadataParameters,
         /// Set metadata parameters failed
@@ -239,25 +519,102 @@ decl_module! {
         type Error = Error<T>;
         fn deposit_event() = default;
 
+        const ModuleId: ModuleId = T::ModuleId::get();
+
+        fn on_runtime_upgrade() -> Weight {
+            if StorageVersion::get() == Releases::V1 {
+                StorageVersion::put(Releases::V2);
+                migration::migrate_to_v2::<T>() + T::DbWeight::get().reads_writes(1, 1)
+            } else {
+                T::DbWeight::get().reads(1)
+            }
+        }
+
+        /// <pre>
+        /// Method: set_standard_definition(standard: Standard, definition: StandardStruct)
+        /// Arguments: origin: AccountId - Transaction caller
+        ///            standard: Standard - Carbon Credits Standard
+        ///            definition: StandardStruct - signing orders of project documentation and annual reports
+        /// Access: Root
+        ///
+        /// Creates or overrides standard definition, signing orders must start with project owner
+        /// </pre>
+        #[weight = 10_000 + T::DbWeight::get().writes(1)]
+        pub fn set_standard_definition(origin, standard: Standard, definition: StandardStruct) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(definition.is_valid(), Error::<T>::InvalidStandardDefinition);
+            StandardDefinitions::insert(&standard, definition);
+
+            Self::deposit_event(RawEvent::StandardDefinitionSet(standard));
+            Ok(())
+        }
+
+        /// <pre>
+        /// Method: remove_standard_definition(standard: Standard)
+        /// Arguments: origin: AccountId - Transaction caller
+        ///            standard: Standard - Carbon Credits Standard
+        /// Access: Root
+        ///
+        /// Removes standard definition from storage, built in standards return to their default signing orders.
+        /// Custom standard can be removed only if it has no projects
+        /// </pre>
+        #[weight = 10_000 + T::DbWeight::get().reads_writes(2, 1)]
+        pub fn remove_standard_definition(origin, standard: Standard) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(StandardDefinitions::contains_key(&standard), Error::<T>::StandardNotExist);
+            if let Standard::CUSTOM(_) = standard {
+                ensure!(ProjectsCountByStandard::get(&standard) == 0, Error::<T>::StandardHasProjects);
+            }
+            StandardDefinitions::remove(&standard);
+
+            Self::deposit_event(RawEvent::StandardDefinitionRemoved(standard));
+            Ok(())
+        }
+
         /// <pre>
-        /// Method: create_project(standard: Standard, file_id: FileId)
+        /// Method: create_project(
+        ///             standard: Standard, 
+        ///             file_id: FileId, 
+        ///             metadata: ProjectMetadata,
+        ///             crediting_period_start: T::Moment,
+        ///             crediting_period_end: T::Moment,
+        ///             )
         /// Arguments: origin: AccountId - Transaction caller
         ///            standard: Standard - Carbon Credits Standard
         ///            file_id: FileId - id of file in filesign pallet
+        ///            metadata: ProjectMetadata - location, methodology, sector and estimations of the project
+        ///            crediting_period_start, crediting_period_end - crediting period, that project applies for
         /// Access: Project Owner Role
         ///
         /// Creates new project with relation to PDD file in filesign
         /// </pre>
-        #[weight = 10_000 + T::DbWeight::get().reads_writes(1, 2)]
-        pub fn create_project(origin, standard: Standard, file_id: Option<FileId>) -> DispatchResult {
+        #[weight = 10_000 + T::DbWeight::get().reads_writes(4, 7)]
+        pub fn create_project(
+            origin, 
+            standard: Standard, 
+            file_id: Option<FileId>, 
+            metadata: ProjectMetadata,
+            crediting_period_start: Moment<T>,
+            crediting_period_end: Moment<T>,
+        ) -> DispatchResult {
             let caller = ensure_signed(origin)?;
             ensure!(accounts::Module::<T>::account_is_cc_project_owner(&caller), Error::<T>::AccountNotOwner);
             if let Some(id) = file_id {
                 ensure!(pallet_evercity_filesign::Module::<T>::address_is_owner_for_file(id, &caller), Error::<T>::AccountNotFileOwner);
             }
+            ensure!(Self::get_standard_definition(&standard).is_some(), Error::<T>::StandardNotExist);
+            ensure!(metadata.is_valid(), Error::<T>::InvalidProjectMetadata);
+            let crediting_period = CreditingPeriod::new(crediting_period_start, crediting_period_end);
+            ensure!(crediting_period.is_valid(), Error::<T>::InvalidCreditingPeriod);
             let new_id = LastID::get() + 1;
-            let new_project = ProjectStruct::<<T as frame_system::Config>::AccountId, T, T::Balance>::new(caller.clone(), new_id, standard, file_id);
+            ProjectsCountByStandard::mutate(&standard, |count| *count += 1);
+            let new_project = ProjectStruct::<<T as frame_system::Config>::AccountId>::new(caller.clone(), new_id, standard, file_id);
+            let new_state = new_project.state;
             <ProjectById<T>>::insert(new_id, new_project);
+            ProjectMetadataById::insert(new_id, metadata);
+            CreditingPeriods::<T>::insert(new_id, crediting_period);
+            ProjectsByOwner::<T>::insert(&caller, new_id, ());
+            ProjectsByState::insert(new_state, new_id, ());
             LastID::mutate(|x| *x = x.checked_add(1).unwrap());
 
             // SendEvent
@@ -295,6 +652,189 @@ decl_module! {
             Ok(())
         }
 
+        /// <pre>
+        /// Method: set_project_metadata(project_id: ProjectId, metadata: ProjectMetadata)
+        /// Arguments: origin: AccountId - Transaction caller
+        ///            project_id: ProjectId - id of the project
+        ///            metadata: ProjectMetadata - new metadata of the project
+        /// Access: Project Owner Role
+        ///
+        /// Changes project metadata, availible before signing starts
+        /// </pre>
+        #[weight = 10_000 + T::DbWeight::get().reads_writes(1, 1)]
+        pub fn set_project_metadata(origin, project_id: ProjectId, metadata: ProjectMetadata) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            ensure!(accounts::Module::<T>::account_is_cc_project_owner(&caller), Error::<T>::AccountNotOwner);
+            ensure!(metadata.is_valid(), Error::<T>::InvalidProjectMetadata);
+            let project = ProjectById::<T>::get(project_id).ok_or(Error::<T>::ProjectNotExist)?;
+            ensure!(project.owner == caller, Error::<T>::AccountNotOwner);
+            ensure!(project.state == project::PROJECT_OWNER_SIGN_PENDING, Error::<T>::InvalidState);
+            ProjectMetadataById::insert(project_id, metadata);
+            Self::deposit_event(RawEvent::ProjectMetadataChanged(caller, project_id));
+            Ok(())
+        }
+
+        /// <pre>
+        /// Method: set_project_crediting_period(project_id: ProjectId, start: T::Moment, end: T::Moment)
+        /// Arguments: origin: AccountId - Transaction caller
+        ///            project_id: ProjectId - id of the project
+        ///            start, end - crediting period, that project applies for
+        /// Access: Project Owner Role
+        ///
+        /// Changes crediting period of the project, availible before signing starts
+        /// </pre>
+        #[weight = 10_000 + T::DbWeight::get().reads_writes(2, 1)]
+        pub fn set_project_crediting_period(origin, project_id: ProjectId, start: Moment<T>, end: Moment<T>) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            ensure!(accounts::Module::<T>::account_is_cc_project_owner(&caller), Error::<T>::AccountNotOwner);
+            let new_crediting_period = CreditingPeriod::new(start, end);
+            ensure!(new_crediting_period.is_valid(), Error::<T>::InvalidCreditingPeriod);
+            let project = ProjectById::<T>::get(project_id).ok_or(Error::<T>::ProjectNotExist)?;
+            ensure!(project.owner == caller, Error::<T>::AccountNotOwner);
+            ensure!(project.state == project::PROJECT_OWNER_SIGN_PENDING, Error::<T>::InvalidState);
+            CreditingPeriods::<T>::mutate(project_id, |crediting_period_option| {
+                match crediting_period_option {
+                    None => *crediting_period_option = Some(new_crediting_period),
+                    Some(crediting_period) => {
+                        crediting_period.start = start;
+                        crediting_period.end = end;
+                    }
+                }
+            });
+            Self::deposit_event(RawEvent::ProjectCreditingPeriodChanged(caller, project_id, start, end));
+            Ok(())
+        }
+
+        /// <pre>
+        /// Method: renew_crediting_period(project_id: ProjectId, new_end: T::Moment)
+        /// Arguments: origin: AccountId - Transaction caller
+        ///            project_id: ProjectId - id of the project
+        ///            new_end - end of the renewed crediting period
+        /// Access: Project Owner Role
+        ///
+        /// Submits renewal of crediting period of the registered project. Renewal is signed
+        /// by the roles, following project owner in the standard signing order, the period is extended
+        /// after the last signature. Project stays registered, while the renewal is pending.
+        /// Number of renewals is limited by the standard
+        /// </pre>
+        #[weight = 10_000 + T::DbWeight::get().reads_writes(3, 2)]
+        pub fn renew_crediting_period(origin, project_id: ProjectId, new_end: Moment<T>) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            ensure!(accounts::Module::<T>::account_is_cc_project_owner(&caller), Error::<T>::AccountNotOwner);
+            let project = ProjectById::<T>::get(project_id).ok_or(Error::<T>::ProjectNotExist)?;
+            ensure!(project.owner == caller, Error::<T>::AccountNotOwner);
+            ensure!(project.state == project::REGISTERED, Error::<T>::ProjectNotRegistered);
+            let standard = Self::get_project_standard_definition(&project)?;
+            CreditingPeriods::<T>::try_mutate(project_id, |crediting_period_option| -> DispatchResult {
+                let crediting_period = crediting_period_option.as_mut().ok_or(Error::<T>::CreditingPeriodNotSet)?;
+                ensure!(!crediting_period.is_renewal_pending(), Error::<T>::CreditingPeriodRenewalPending);
+                ensure!(crediting_period.end < new_end, Error::<T>::InvalidCreditingPeriod);
+                ensure!(crediting_period.renewals_count < standard.max_crediting_period_renewals, 
+                    Error::<T>::CreditingPeriodRenewalsExceeded);
+                crediting_period.submit_renewal(new_end, standard.project_signing_order);
+                Ok(())
+            })?;
+            // Owner submits renewal, next roles sign it
+            CreditingPeriodRenewalSignatures::<T>::insert(project_id, sp_std::vec![(caller.clone(), accounts::accounts::CC_PROJECT_OWNER_ROLE_MASK)]);
+            Self::deposit_event(RawEvent::ProjectCreditingPeriodRenewalSubmited(caller, project_id, new_end));
+            Ok(())
+        }
+
+        /// <pre>
+        /// Method: sign_crediting_period_renewal(project_id: ProjectId)
+        /// Arguments: origin: AccountId - Transaction caller
+        ///            project_id - id of the project
+        ///
+        /// Access: Required Signer of the project with role of pending renewal signature
+        ///
+        /// Signs pending renewal of the crediting period, the period is extended after the last signature
+        /// </pre>
+        #[weight = 10_000 + T::DbWeight::get().reads_writes(4, 2)]
+        pub fn sign_crediting_period_renewal(origin, project_id: ProjectId) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            let project = ProjectById::<T>::get(project_id).ok_or(Error::<T>::ProjectNotExist)?;
+            let (role, renewed_end) = CreditingPeriods::<T>::try_mutate(
+                project_id, |crediting_period_option| -> Result<(RoleMask, Option<Moment<T>>), DispatchError> {
+                    let crediting_period = crediting_period_option.as_mut().ok_or(Error::<T>::CreditingPeriodNotSet)?;
+                    let mut signatures = CreditingPeriodRenewalSignatures::<T>::get(project_id);
+                    let role = Self::get_renewal_pending_role(crediting_period, &signatures)?;
+                    Self::ensure_account_role(&caller, role)?;
+                    ensure!(Self::is_correct_project_signer(&project, caller.clone(), role), Error::<T>::IncorrectProjectSigner);
+                    signatures.push((caller.clone(), role));
+                    if signatures.len() < crediting_period.renewal_signing_order.len() {
+                        CreditingPeriodRenewalSignatures::<T>::insert(project_id, signatures);
+                        return Ok((role, None));
+                    }
+                    // Last signer approves the renewal
+                    crediting_period.apply_renewal();
+                    CreditingPeriodRenewalSignatures::<T>::remove(project_id);
+                    Ok((role, Some(crediting_period.end)))
+                })?;
+            Self::deposit_event(RawEvent::ProjectCreditingPeriodRenewalSigned(caller.clone(), project_id, role));
+            if let Some(end) = renewed_end {
+                Self::deposit_event(RawEvent::ProjectCreditingPeriodRenewed(caller, project_id, end));
+            }
+            Ok(())
+        }
+
+        /// <pre>
+        /// Method: reject_crediting_period_renewal(project_id: ProjectId, reason_hash: H256)
+        /// Arguments: origin: AccountId - Transaction caller
+        ///            project_id - id of the project
+        ///            reason_hash - hash of the document with rejection reason
+        ///
+        /// Access: Required Signer of the project with role of pending renewal signature
+        ///
+        /// Rejects pending renewal of the crediting period, the period and the project dont change
+        /// </pre>
+        #[weight = 10_000 + T::DbWeight::get().reads_writes(4, 2)]
+        pub fn reject_crediting_period_renewal(origin, project_id: ProjectId, reason_hash: H256) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            let project = ProjectById::<T>::get(project_id).ok_or(Error::<T>::ProjectNotExist)?;
+            let role = CreditingPeriods::<T>::try_mutate(
+                project_id, |crediting_period_option| -> Result<RoleMask, DispatchError> {
+                    let crediting_period = crediting_period_option.as_mut().ok_or(Error::<T>::CreditingPeriodNotSet)?;
+                    let role = Self::get_renewal_pending_role(crediting_period, &CreditingPeriodRenewalSignatures::<T>::get(project_id))?;
+                    Self::ensure_account_role(&caller, role)?;
+                    ensure!(Self::is_correct_project_signer(&project, caller.clone(), role), Error::<T>::IncorrectProjectSigner);
+                    crediting_period.cancel_renewal();
+                    CreditingPeriodRenewalSignatures::<T>::remove(project_id);
+                    Ok(role)
+                })?;
+            Self::deposit_event(RawEvent::ProjectCreditingPeriodRenewalRejected(caller, project_id, role, reason_hash));
+            Ok(())
+        }
+
+        /// <pre>
+        /// Method: set_project_investor_sign_required(project_id: ProjectId, investor_sign_required: bool)
+        /// Arguments: origin: AccountId - Transaction caller
+        ///            project_id: ProjectId - id of the project
+        ///            investor_sign_required: bool - if investor must sign project documentation and annual reports
+        /// Access: Project Owner Role
+        ///
+        /// Adds investor stage before registry to signing of the project and its annual reports,
+        /// availible before signing starts
+        /// </pre>
+        #[weight = 10_000 + T::DbWeight::get().reads_writes(1, 1)]
+        pub fn set_project_investor_sign_required(origin, project_id: ProjectId, investor_sign_required: bool) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            ensure!(accounts::Module::<T>::account_is_cc_project_owner(&caller), Error::<T>::AccountNotOwner);
+            ProjectById::<T>::try_mutate(
+                project_id, |project_to_mutate| -> DispatchResult {
+                    match project_to_mutate  {
+                        None => return Err(Error::<T>::ProjectNotExist.into()),
+                        Some(project) => {
+                            ensure!(project.owner == caller, Error::<T>::AccountNotOwner);
+                            ensure!(project.state == project::PROJECT_OWNER_SIGN_PENDING, Error::<T>::InvalidState);
+                            project.set_investor_sign_required(investor_sign_required);
+                        }
+                    }
+                    Ok(())
+                })?;
+            Self::deposit_event(RawEvent::ProjectInvestorSignRequiredChanged(caller, project_id, investor_sign_required));
+            Ok(())
+        }
+
         /// <pre>
         /// Method: assign_project_signer(signer: T::AccountId, role: RoleMask, project_id: ProjectId)
         /// Arguments: origin: AccountId - Transaction caller
@@ -308,26 +848,30 @@ decl_module! {
         /// also adds signer to filesign PDD 
         /// 
         /// </pre>
-        #[weight = 10_000 + T::DbWeight::get().reads_writes(1, 1)]
+        #[weight = 10_000 + T::DbWeight::get().reads_writes(2, 2)]
         pub fn assign_project_signer(origin, signer: T::AccountId, role: RoleMask, project_id: ProjectId) -> DispatchResult {
             let caller = ensure_signed(origin.clone())?;
             ensure!(pallet_evercity_accounts::Module::<T>::account_is_selected_role(&signer, role), Error::<T>::AccountIncorrectRole);
-            ProjectById::<T>::try_mutate(
-                project_id, |project_to_mutate| -> DispatchResult {
+            let is_new_signer = ProjectById::<T>::try_mutate(
+                project_id, |project_to_mutate| -> Result<bool, DispatchError> {
                     match project_to_mutate  {
-                        None => return Err(Error::<T>::ProjectNotExist.into()),
+                        None => Err(Error::<T>::ProjectNotExist.into()),
                         Some(project) => {
                             ensure!(project.owner == caller, Error::<T>::AccountNotOwner);
+                            let is_new_signer = !project.is_required_signer((signer.clone(), role));
                             project.assign_required_signer((signer.clone(), role));
                             let file_id = match project.file_id {
                                 None => return Err(Error::<T>::IncorrectFileId.into()),
                                 Some(id) => id
                             };
                             pallet_evercity_filesign::Module::<T>::assign_signer(origin, file_id, signer.clone())?;
+                            Ok(is_new_signer)
                         }
                     }
-                    Ok(())
                 })?;
+            if is_new_signer {
+                Self::add_project_signer_index(&signer, project_id);
+            }
             Self::deposit_event(RawEvent::ProjectSignerAdded(caller, signer, role, project_id));
             Ok(())
         }
@@ -345,7 +889,7 @@ decl_module! {
         /// also deletes signer from filesign PDD 
         /// 
         /// </pre>
-        #[weight = 10_000 + T::DbWeight::get().reads_writes(3, 2)]
+        #[weight = 10_000 + T::DbWeight::get().reads_writes(5, 3)]
         pub fn remove_project_signer(origin, signer: T::AccountId, role: RoleMask, project_id: ProjectId) -> DispatchResult {
             let caller = ensure_signed(origin.clone())?;
             ensure!(pallet_evercity_accounts::Module::<T>::account_is_selected_role(&signer, role), Error::<T>::AccountIncorrectRole);
@@ -371,6 +915,7 @@ decl_module! {
 
                     Ok(())
             })?;
+            Self::remove_project_signer_index(&signer, project_id);
             Self::deposit_event(RawEvent::ProjectSignerRemoved(caller, signer, role, project_id));
             Ok(())
         }
@@ -385,10 +930,11 @@ decl_module! {
         /// Signs project documentation, changing state of the project state machine
         /// 
         /// </pre>
-        #[weight = 10_000 + T::DbWeight::get().reads_writes(2, 2)]
+        #[weight = 10_000 + T::DbWeight::get().reads_writes(5, 5)]
         pub fn sign_project(origin, project_id: ProjectId) -> DispatchResult {
             let caller = ensure_signed(origin.clone())?;
             let mut event_opt: Option<Event<T>> = None;
+            let mut state_change: Option<(ProjectStateMask, ProjectStateMask)> = None;
             ProjectById::<T>::try_mutate(
                 project_id, |project_option| -> DispatchResult {
                     match project_option {
@@ -400,12 +946,17 @@ decl_module! {
                             };
                             ensure!(pallet_evercity_filesign::Module::<T>::address_is_signer_for_file(project_documentation_file_id, &caller), 
                                 Error::<T>::IncorrectProjectSigner);
+                            let old_state = project.state;
                             Self::change_project_state(project, caller, &mut event_opt)?;
+                            state_change = Some((old_state, project.state));
                             pallet_evercity_filesign::Module::<T>::sign_latest_version(origin, project_documentation_file_id)?;
                         }
                     }
                     Ok(())
                 })?;
+            if let Some((old_state, new_state)) = state_change {
+                Self::update_project_state_index(project_id, old_state, new_state);
+            }
             if let Some(event) = event_opt {
                 Self::deposit_event(event);
             }
@@ -413,19 +964,71 @@ decl_module! {
         }
 
         /// <pre>
-        /// Method: create_annual_report(project_id: ProjectId, file_id: FileId, carbon_credits_count: T::Balance)
+        /// Method: reject_project(project_id: ProjectId, reason_hash: H256)
+        /// Arguments: origin: AccountId - Transaction caller
+        ///            project_id - id of the project
+        ///            reason_hash - hash of the document with rejection reason
+        ///
+        /// Access: Required Signer with role of pending signature
+        ///
+        /// Rejects project documentation, returning project to the owner.
+        /// Owner can upload new version of PDD in filesign pallet and submit project again
+        /// 
+        /// </pre>
+        #[weight = 10_000 + T::DbWeight::get().reads_writes(4, 3)]
+        pub fn reject_project(origin, project_id: ProjectId, reason_hash: H256) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            let (old_state, role) = ProjectById::<T>::try_mutate(
+                project_id, |project_option| -> Result<(ProjectStateMask, RoleMask), DispatchError> {
+                    match project_option {
+                        None => Err(Error::<T>::ProjectNotExist.into()),
+                        Some(project) => {
+                            let (signing_order, stage) = Self::get_project_signing_stage(project)?;
+                            let role = signing_order[stage];
+                            // Owner didnt submit the project yet
+                            ensure!(role != accounts::accounts::CC_PROJECT_OWNER_ROLE_MASK, Error::<T>::InvalidState);
+                            Self::ensure_account_role(&caller, role)?;
+                            ensure!(Self::is_correct_project_signer(project, caller.clone(), role), Error::<T>::IncorrectProjectSigner);
+                            let old_state = project.state;
+                            let rejection_index = project.reject();
+                            ProjectRejections::<T>::insert(project_id, rejection_index, RejectionStruct::new(caller.clone(), role, reason_hash));
+                            Ok((old_state, role))
+                        }
+                    }
+                })?;
+            Self::update_project_state_index(project_id, old_state, project::PROJECT_OWNER_SIGN_PENDING);
+            Self::deposit_event(RawEvent::ProjectRejected(caller, project_id, role, reason_hash));
+            Ok(())
+        }
+
+        /// <pre>
+        /// Method: create_annual_report(
+        ///             project_id: ProjectId, 
+        ///             file_id: FileId, 
+        ///             carbon_credits_count: T::Balance,
+        ///             name: Vec<u8>,
+        ///             symbol: Vec<u8>,
+        ///             decimals: u8,
+        ///             monitoring_period: MonitoringPeriod<T::Moment>,
+        ///             )
         /// Arguments: origin: AccountId - Transaction caller
         ///            project_id: ProjectId - Id of project, where to create annual report
         ///            file_id: FileId - Id of pre created file of annual report document
         ///            carbon_credits_count - count of carbon credits to release after signing
+        ///            name: Vec<u8> - name of carbon credits, part of metadata
+        ///            symbol: Vec<u8> - symbol
+        ///            decimals: u8 - number of decimals
+        ///            monitoring_period - start, end and vintage year of emission reduction
         ///
         ///
         /// Access: Owner of the project
         ///
-        /// Create annual report entity with link to annual report file
+        /// Create annual report entity with link to annual report file.
+        /// Monitoring period must not overlap with periods of other annual reports of the project,
+        /// weight covers reading MAX_ANNUAL_REPORTS reports of the project, the rest is refunded
         /// 
         /// </pre> 
-        #[weight = 10_000 + T::DbWeight::get().reads_writes(3, 1)]
+        #[weight = 10_000 + T::DbWeight::get().reads_writes(4 + MAX_ANNUAL_REPORTS, 2)]
         pub fn create_annual_report(
             origin, 
             project_id: ProjectId, 
@@ -434,32 +1037,43 @@ decl_module! {
             name: Vec<u8>,
             symbol: Vec<u8>,
             decimals: u8,
-        ) -> DispatchResult {
+            monitoring_period: annual_report::MonitoringPeriod<Moment<T>>,
+        ) -> DispatchResultWithPostInfo {
             let caller = ensure_signed(origin)?;
             ensure!(accounts::Module::<T>::account_is_cc_project_owner(&caller), Error::<T>::AccountNotOwner);
             ensure!(pallet_evercity_filesign::Module::<T>::address_is_owner_for_file(file_id, &caller), Error::<T>::AccountNotFileOwner);
-            ProjectById::<T>::try_mutate(
-                project_id, |project_option| -> DispatchResult {
+            let reports_read = ProjectById::<T>::try_mutate(
+                project_id, |project_option| -> Result<u64, DispatchError> {
                     match project_option {
                         None => Err(Error::<T>::ProjectNotExist.into()),
                         Some(project) => {
                             ensure!(project.owner == caller, Error::<T>::AccountNotOwner);
                             ensure!(project.state == project::REGISTERED, Error::<T>::ProjectNotRegistered);
-                            ensure!(project.annual_reports.iter()
-                                        .all(|x| x.state == annual_report::REPORT_ISSUED),
+                            ensure!(project.live_annual_reports_count < MAX_ANNUAL_REPORTS, Error::<T>::AnnualReportsLimitExceeded);
+                            let standard = Self::get_project_standard_definition(project)?;
+                            // Without concurrent reports each report is created after the previous one is issued,
+                            // so it is enough to check the last one
+                            ensure!(standard.allow_concurrent_reports || project.annual_reports_count == 0 ||
+                                    AnnualReports::<T>::get(project_id, project.annual_reports_count - 1)
+                                        .map_or(true, |x| x.state == annual_report::REPORT_ISSUED),
                                 Error::<T>::NotIssuedAnnualReportsExist
                             );
+                            let reports_read = Self::ensure_monitoring_period_available(project, &monitoring_period)?;
                             let meta = annual_report::CarbonCreditsMeta::new(name, symbol, decimals);
                             ensure!(meta.is_metadata_valid(), Error::<T>::BadMetadataParameters);
-                            project.annual_reports
-                                        .push(annual_report::AnnualReportStruct::<T::AccountId, T, T::Balance>::new(file_id, carbon_credits_count, Timestamp::<T>::get(), meta));
-                            Ok(())
+                            AnnualReports::<T>::insert(project_id, project.annual_reports_count, 
+                                annual_report::AnnualReportStruct::<T::AccountId, T, T::Balance>::new(
+                                    file_id, carbon_credits_count, Timestamp::<T>::get(), meta, monitoring_period.clone()
+                                ));
+                            project.annual_reports_count += 1;
+                            project.live_annual_reports_count += 1;
+                            Ok(reports_read)
                         }
                     }
              })?;
             // SendEvent
-            Self::deposit_event(RawEvent::AnnualReportCreated(caller, project_id));
-            Ok(())
+            Self::deposit_event(RawEvent::AnnualReportCreated(caller, project_id, monitoring_period));
+            Ok(Some(Self::create_annual_report_weight(reports_read)).into())
         }
 
         /// <pre>
@@ -472,6 +1086,7 @@ decl_module! {
         ///             name: Vec<u8>,
         ///             symbol: Vec<u8>,
         ///             decimals: u8,
+        ///             monitoring_period: MonitoringPeriod<T::Moment>,
         ///             ) 
         /// 
         /// Arguments: origin: AccountId - Transaction caller
@@ -483,14 +1098,17 @@ decl_module! {
         ///            name: Vec<u8> - name of carbon credits, part of metadata
         ///            symbol: Vec<u8> - symbol
         ///            decimals: u8 - number of decimals
+        ///            monitoring_period - start, end and vintage year of emission reduction
         ///
         ///
         /// Access: Owner of the project
         ///
-        /// Create annual report entity with link to annual report file
+        /// Create annual report entity with link to annual report file.
+        /// Monitoring period must not overlap with periods of other annual reports of the project,
+        /// weight covers reading MAX_ANNUAL_REPORTS reports of the project, the rest is refunded
         /// 
         /// </pre> 
-        #[weight = 10_000 + T::DbWeight::get().reads_writes(3, 1)]
+        #[weight = 10_000 + T::DbWeight::get().reads_writes(4 + MAX_ANNUAL_REPORTS, 2)]
         pub fn create_annual_report_with_file(
             origin, 
             project_id: ProjectId, 
@@ -501,32 +1119,43 @@ decl_module! {
             name: Vec<u8>,
             symbol: Vec<u8>,
             decimals: u8,
-        ) -> DispatchResult {
+            monitoring_period: annual_report::MonitoringPeriod<Moment<T>>,
+        ) -> DispatchResultWithPostInfo {
             let caller = ensure_signed(origin.clone())?;
             ensure!(accounts::Module::<T>::account_is_cc_project_owner(&caller), Error::<T>::AccountNotOwner);
             let meta = annual_report::CarbonCreditsMeta::new(name, symbol, decimals);
             ensure!(meta.is_metadata_valid(), Error::<T>::BadMetadataParameters);
-            ProjectById::<T>::try_mutate(
-                project_id, |project_option| -> DispatchResult {
+            let reports_read = ProjectById::<T>::try_mutate(
+                project_id, |project_option| -> Result<u64, DispatchError> {
                     match project_option {
                         None => Err(Error::<T>::ProjectNotExist.into()),
                         Some(project) => {
                             ensure!(project.owner == caller, Error::<T>::AccountNotOwner);
                             ensure!(project.state == project::REGISTERED, Error::<T>::ProjectNotRegistered);
-                            ensure!(project.annual_reports.iter()
-                                        .all(|x| x.state == annual_report::REPORT_ISSUED),
+                            ensure!(project.live_annual_reports_count < MAX_ANNUAL_REPORTS, Error::<T>::AnnualReportsLimitExceeded);
+                            let standard = Self::get_project_standard_definition(project)?;
+                            // Without concurrent reports each report is created after the previous one is issued,
+                            // so it is enough to check the last one
+                            ensure!(standard.allow_concurrent_reports || project.annual_reports_count == 0 ||
+                                    AnnualReports::<T>::get(project_id, project.annual_reports_count - 1)
+                                        .map_or(true, |x| x.state == annual_report::REPORT_ISSUED),
                                 Error::<T>::NotIssuedAnnualReportsExist
                             );
+                            let reports_read = Self::ensure_monitoring_period_available(project, &monitoring_period)?;
                             pallet_evercity_filesign::Module::<T>::create_new_file(origin, tag, filehash, Some(file_id))?;
-                            project.annual_reports
-                                        .push(annual_report::AnnualReportStruct::<T::AccountId, T, T::Balance>::new(file_id, carbon_credits_count, Timestamp::<T>::get(), meta));
-                            Ok(())
+                            AnnualReports::<T>::insert(project_id, project.annual_reports_count, 
+                                annual_report::AnnualReportStruct::<T::AccountId, T, T::Balance>::new(
+                                    file_id, carbon_credits_count, Timestamp::<T>::get(), meta, monitoring_period.clone()
+                                ));
+                            project.annual_reports_count += 1;
+                            project.live_annual_reports_count += 1;
+                            Ok(reports_read)
                         }
                     }
              })?;
             // SendEvent
-            Self::deposit_event(RawEvent::AnnualReportCreated(caller, project_id));
-            Ok(())
+            Self::deposit_event(RawEvent::AnnualReportCreated(caller, project_id, monitoring_period));
+            Ok(Some(Self::create_annual_report_weight(reports_read)).into())
         }
 
         /// <pre>
@@ -538,33 +1167,31 @@ decl_module! {
         ///
         /// Access: Owner of the project
         ///
-        /// Change annual report balance. Can only be changed in preparing step
+        /// Change last annual report balance. Can only be changed in preparing step
         /// 
         /// </pre> 
-        #[weight = 10_000 + T::DbWeight::get().reads_writes(2, 1)]
+        #[weight = 10_000 + T::DbWeight::get().reads_writes(3, 1)]
         pub fn change_report_carbon_credits_count(origin, project_id: ProjectId, new_carbon_credits_count: T::Balance) -> DispatchResult {
-            let caller = ensure_signed(origin)?;
-            ensure!(accounts::Module::<T>::account_is_cc_project_owner(&caller), Error::<T>::AccountNotOwner);
-            ProjectById::<T>::try_mutate(
-                project_id, |project_to_mutate| -> DispatchResult {
-                    match project_to_mutate  {
-                        None => return Err(Error::<T>::ProjectNotExist.into()),
-                        Some(proj) => {
-                            ensure!(proj.owner == caller, Error::<T>::AccountNotOwner);
-                            let len = proj.annual_reports.len();
-                            ensure!(len > 0, Error::<T>::NoAnnualReports);
-                            // Ensure, that report in initial preparing state REPORT_PROJECT_OWNER_SIGN_PENDING
-                            ensure!(proj.annual_reports[len - 1].state == annual_report::REPORT_PROJECT_OWNER_SIGN_PENDING, Error::<T>::InvalidState);
-                            proj.annual_reports[len - 1].change_carbon_credits_count(new_carbon_credits_count);
-                        }
-                    }
-                    Ok(())
-             })?;
-            // SendEvent
-            Self::deposit_event(RawEvent::AnnualReportCreditsCountChanged(caller, project_id, new_carbon_credits_count));
-            Ok(())
+            Self::do_change_report_carbon_credits_count(origin, project_id, None, new_carbon_credits_count)
         }
 
+        /// <pre>
+        /// Method: change_annual_report_carbon_credits_count(project_id: ProjectId, report_index: u64, new_carbon_credits_count: T::Balance)
+        /// Arguments: origin: AccountId - Transaction caller
+        ///            project_id: ProjectId - Id of project
+        ///            report_index: u64 - index of annual report in the project, starting from 0
+        ///            new_carbon_credits_count - new count of carbon credits to release after signing
+        ///
+        ///
+        /// Access: Owner of the project
+        ///
+        /// Change annual report balance. Can only be changed in preparing step
+        /// 
+        /// </pre> 
+        #[weight = 10_000 + T::DbWeight::get().reads_writes(3, 1)]
+        pub fn change_annual_report_carbon_credits_count(origin, project_id: ProjectId, report_index: u64, new_carbon_credits_count: T::Balance) -> DispatchResult {
+            Self::do_change_report_carbon_credits_count(origin, project_id, Some(report_index), new_carbon_credits_count)
+        }
 
         /// <pre>
         /// Method: delete_last_annual_report(project_id: ProjectId: T::Balance)
@@ -577,31 +1204,28 @@ decl_module! {
         /// Deletes project's last annual report if it is not issued
         /// 
         /// </pre> 
-        #[weight = 10_000 + T::DbWeight::get().reads_writes(2, 1)]
+        #[weight = 10_000 + T::DbWeight::get().reads_writes(5, 5)]
         pub fn delete_last_annual_report(origin, project_id: ProjectId) -> DispatchResult {
-            let caller = ensure_signed(origin)?;
-            ensure!(accounts::Module::<T>::account_is_cc_project_owner(&caller), Error::<T>::AccountNotOwner);
-            ProjectById::<T>::try_mutate(
-                project_id, |project_to_mutate| -> DispatchResult {
-                    match project_to_mutate  {
-                        None => return Err(Error::<T>::ProjectNotExist.into()),
-                        Some(proj) => {
-                            ensure!(proj.owner == caller, Error::<T>::AccountNotOwner);
-                            let len = proj.annual_reports.len();
-                            ensure!(len > 0, Error::<T>::NoAnnualReports);
-                            // Ensure, that report not in final issued state
-                            // To prevent deleting ready reports, that have its carbon credits released
-                            ensure!(proj.annual_reports[len - 1].state != annual_report::REPORT_ISSUED, Error::<T>::InvalidState);
-                            proj.annual_reports.remove(len - 1);
-                        }
-                    }
-                    Ok(())
-             })?;
-            // SendEvent
-            Self::deposit_event(RawEvent::AnnualReportDeleted(caller, project_id));
-            Ok(())
+            Self::do_delete_annual_report(origin, project_id, None)
         }
 
+        /// <pre>
+        /// Method: delete_annual_report(project_id: ProjectId, report_index: u64)
+        /// Arguments: origin: AccountId - Transaction caller
+        ///            project_id: ProjectId - Id of project
+        ///            report_index: u64 - index of annual report in the project, starting from 0
+        ///
+        ///
+        /// Access: Owner of the project
+        ///
+        /// Deletes project's annual report if it is not issued.
+        /// Index of the deleted report stays empty, indexes of other reports dont change
+        /// 
+        /// </pre> 
+        #[weight = 10_000 + T::DbWeight::get().reads_writes(5, 5)]
+        pub fn delete_annual_report(origin, project_id: ProjectId, report_index: u64) -> DispatchResult {
+            Self::do_delete_annual_report(origin, project_id, Some(report_index))
+        }
 
         /// <pre>
         /// Method: assign_last_annual_report_signer(signer: T::AccountId, role: RoleMask, project_id: ProjectId))
@@ -615,70 +1239,66 @@ decl_module! {
         /// also adds signer to filesign document 
         /// 
         /// </pre>
-        #[weight = 10_000 + T::DbWeight::get().reads_writes(3, 2)]
+        #[weight = 10_000 + T::DbWeight::get().reads_writes(5, 3)]
         pub fn assign_last_annual_report_signer(origin, signer: T::AccountId, role: RoleMask, project_id: ProjectId) -> DispatchResult {
-            let caller = ensure_signed(origin.clone())?;
-            ensure!(pallet_evercity_accounts::Module::<T>::account_is_selected_role(&signer, role), Error::<T>::AccountIncorrectRole);
-            ProjectById::<T>::try_mutate(
-                project_id, |project_to_mutate| -> DispatchResult {
-                    match project_to_mutate  {
-                        None => return Err(Error::<T>::ProjectNotExist.into()),
-                        Some(proj) => {
-                            ensure!(proj.owner == caller, Error::<T>::AccountNotOwner);
-                            let len = proj.annual_reports.len();
-                            ensure!(len > 0, Error::<T>::NoAnnualReports);
-                            proj.annual_reports[len - 1].assign_required_signer((signer.clone(), role));
-                            // Assign signer in filesign pallet:
-                            pallet_evercity_filesign::Module::<T>::assign_signer(origin.clone(), proj.annual_reports[len - 1].file_id, signer.clone())?;
-                        }
-                    }
-                    Ok(())
-             })?;
-            Self::deposit_event(RawEvent::AnnualReportSignerAdded(caller, signer, role, project_id));
-            Ok(())
+            Self::do_assign_annual_report_signer(origin, signer, role, project_id, None)
         }
 
         /// <pre>
-        /// Method: remove_last_annual_report_signer(signer: T::AccountId, role: RoleMask, project_id: ProjectId))
+        /// Method: assign_annual_report_signer(signer: T::AccountId, role: RoleMask, project_id: ProjectId, report_index: u64)
         /// Arguments: origin: AccountId - Transaction caller
         ///            signer: T::AccountId - assign signer account
         ///            role - Role of the signer
         ///            project_id - id of the project
+        ///            report_index - index of annual report in the project, starting from 0
         ///
         /// Access: Owner of the project
-        /// remove signer, that was added for signing фттгфд кузщке document
-        /// also deletes signer to filesign document 
+        /// assign signer, that is required for signing annual report document
+        /// also adds signer to filesign document 
         /// 
         /// </pre>
-        #[weight = 10_000 + T::DbWeight::get().reads_writes(3, 2)]
-        pub fn remove_last_annual_report_signer(origin, signer: T::AccountId, role: RoleMask, project_id: ProjectId) -> DispatchResult {
-            let caller = ensure_signed(origin.clone())?;
-            ensure!(pallet_evercity_accounts::Module::<T>::account_is_selected_role(&signer, role), Error::<T>::AccountIncorrectRole);
-            ProjectById::<T>::try_mutate(
-                project_id, |project_to_mutate| -> DispatchResult {
-                    match project_to_mutate  {
-                        None => return Err(Error::<T>::ProjectNotExist.into()),
-                        Some(proj) => {
-                            ensure!(proj.owner == caller, Error::<T>::AccountNotOwner);
-                            let len = proj.annual_reports.len();
-                            ensure!(len > 0, Error::<T>::NoAnnualReports);
-                            // Check if signer did not already sign the project
-                            ensure!(proj.annual_reports[len - 1].is_required_signer((signer.clone(), role)), Error::<T>::AccountNotGivenRoleSigner);
-                            let has_signed = pallet_evercity_filesign::Module::<T>::address_has_signed_the_file(proj.annual_reports[len - 1].file_id, &signer);
-                            ensure!(!has_signed, Error::<T>::AccountAlreadySigned);
-                            proj.annual_reports[len - 1].remove_required_signer((signer.clone(), role));
-                            // delete signer in filesign pallet
-                            pallet_evercity_filesign::Module::<T>::delete_signer(origin.clone(), proj.annual_reports[len - 1].file_id, signer.clone())?;
-                        }
-                    }
-                    Ok(())
-             })?;
-            Self::deposit_event(RawEvent::AnnualReportSignerRemoved(caller, signer, role, project_id));
-            Ok(())
+        #[weight = 10_000 + T::DbWeight::get().reads_writes(5, 3)]
+        pub fn assign_annual_report_signer(origin, signer: T::AccountId, role: RoleMask, project_id: ProjectId, report_index: u64) -> DispatchResult {
+            Self::do_assign_annual_report_signer(origin, signer, role, project_id, Some(report_index))
         }
 
         /// <pre>
-        /// Method: sign_last_annual_report(project_id: ProjectId)
+        /// Method: remove_last_annual_report_signer(signer: T::AccountId, role: RoleMask, project_id: ProjectId))
+        /// Arguments: origin: AccountId - Transaction caller
+        ///            signer: T::AccountId - assign signer account
+        ///            role - Role of the signer
+        ///            project_id - id of the project
+        ///
+        /// Access: Owner of the project
+        /// remove signer, that was added for signing фттгфд кузщке document
+        /// also deletes signer to filesign document 
+        /// 
+        /// </pre>
+        #[weight = 10_000 + T::DbWeight::get().reads_writes(6, 3)]
+        pub fn remove_last_annual_report_signer(origin, signer: T::AccountId, role: RoleMask, project_id: ProjectId) -> DispatchResult {
+            Self::do_remove_annual_report_signer(origin, signer, role, project_id, None)
+        }
+
+        /// <pre>
+        /// Method: remove_annual_report_signer(signer: T::AccountId, role: RoleMask, project_id: ProjectId, report_index: u64)
+        /// Arguments: origin: AccountId - Transaction caller
+        ///            signer: T::AccountId - assign signer account
+        ///            role - Role of the signer
+        ///            project_id - id of the project
+        ///            report_index - index of annual report in the project, starting from 0
+        ///
+        /// Access: Owner of the project
+        /// remove signer, that was added for signing annual report document
+        /// also deletes signer to filesign document 
+        /// 
+        /// </pre>
+        #[weight = 10_000 + T::DbWeight::get().reads_writes(6, 3)]
+        pub fn remove_annual_report_signer(origin, signer: T::AccountId, role: RoleMask, project_id: ProjectId, report_index: u64) -> DispatchResult {
+            Self::do_remove_annual_report_signer(origin, signer, role, project_id, Some(report_index))
+        }
+
+        /// <pre>
+        /// Method: sign_last_annual_report(project_id: ProjectId)
         /// Arguments: origin: AccountId - Transaction caller
         ///
         /// Access: Assigned signer
@@ -686,31 +1306,59 @@ decl_module! {
         /// Signs annual repor document, changing state of the project state machine
         /// 
         /// </pre>
-        #[weight = 10_000 + T::DbWeight::get().reads_writes(3, 2)]
+        #[weight = 10_000 + T::DbWeight::get().reads_writes(4, 2)]
         pub fn sign_last_annual_report(origin, project_id: ProjectId) -> DispatchResult {
-            let caller = ensure_signed(origin.clone())?;
-            let mut event_opt: Option<Event<T>> = None;
-            ProjectById::<T>::try_mutate(
-                project_id, |project_to_mutate| -> DispatchResult {
-                    match project_to_mutate {
-                        None => return Err(Error::<T>::ProjectNotExist.into()),
-                        Some(project) => {
-                            let len = project.annual_reports.len();
-                            ensure!(len > 0, Error::<T>::NoAnnualReports);
-                            let annual_report_file_id =  project.annual_reports[len - 1].file_id;
-                            ensure!(pallet_evercity_filesign::Module::<T>::address_is_signer_for_file(annual_report_file_id, &caller), 
-                                Error::<T>::IncorrectAnnualReportSigner);
-                            Self::change_project_annual_report_state(project, caller, &mut event_opt)?;
-                            pallet_evercity_filesign::Module::<T>::sign_latest_version(origin, 
-                                annual_report_file_id)?;
-                        }
-                    }
-                    Ok(())
-            })?;
-            if let Some(event) = event_opt {
-                Self::deposit_event(event);
-            }
-            Ok(())
+            Self::do_sign_annual_report(origin, project_id, None)
+        }
+
+        /// <pre>
+        /// Method: sign_annual_report(project_id: ProjectId, report_index: u64)
+        /// Arguments: origin: AccountId - Transaction caller
+        ///            project_id - id of the project
+        ///            report_index - index of annual report in the project, starting from 0
+        ///
+        /// Access: Assigned signer
+        ///
+        /// Signs annual report document, changing state of the annual report state machine
+        /// 
+        /// </pre>
+        #[weight = 10_000 + T::DbWeight::get().reads_writes(4, 2)]
+        pub fn sign_annual_report(origin, project_id: ProjectId, report_index: u64) -> DispatchResult {
+            Self::do_sign_annual_report(origin, project_id, Some(report_index))
+        }
+
+        /// <pre>
+        /// Method: reject_last_annual_report(project_id: ProjectId, reason_hash: H256)
+        /// Arguments: origin: AccountId - Transaction caller
+        ///            project_id - id of the project
+        ///            reason_hash - hash of the document with rejection reason
+        ///
+        /// Access: Assigned signer with role of pending signature
+        ///
+        /// Rejects last annual report, returning it to the owner in preparing state,
+        /// so that carbon credits count can be changed before submitting it again
+        /// 
+        /// </pre>
+        #[weight = 10_000 + T::DbWeight::get().reads_writes(3, 1)]
+        pub fn reject_last_annual_report(origin, project_id: ProjectId, reason_hash: H256) -> DispatchResult {
+            Self::do_reject_annual_report(origin, project_id, None, reason_hash)
+        }
+
+        /// <pre>
+        /// Method: reject_annual_report(project_id: ProjectId, report_index: u64, reason_hash: H256)
+        /// Arguments: origin: AccountId - Transaction caller
+        ///            project_id - id of the project
+        ///            report_index - index of annual report in the project, starting from 0
+        ///            reason_hash - hash of the document with rejection reason
+        ///
+        /// Access: Assigned signer with role of pending signature
+        ///
+        /// Rejects annual report, returning it to the owner in preparing state
+        /// 
+        /// </pre>
+        #[weight = 10_000 + T::DbWeight::get().reads_writes(3, 1)]
+        pub fn reject_annual_report(origin, project_id: ProjectId, report_index: u64, reason_hash: H256) -> DispatchResult {
+            Self::do_reject_annual_report(origin, project_id, Some(report_index), reason_hash)
         }
 
         /// <pre>
@@ -723,15 +1371,16 @@ decl_module! {
         /// 
         /// Arguments: origin: AccountId - Transaction caller
         ///            asset_id: <T as pallet_assets::Config>::AssetId - Asset Id in assets pallet
-        ///            new_carbon_credits_holder - carbo credits holder, can be other than project owner
+        ///            new_carbon_credits_holder - carbon credits holder, can be other than project owner
         ///            min_balance - min balance for assets pallet
         ///
-        /// Access: Project owner
+        /// Access: Issuer role of the standard - project owner by default, or registry, that signed the project
         ///
-        /// Creates assets in assets pallet, creates carbon credits passport and calls mint in assets pallet
+        /// Creates assets in assets pallet with issuer as admin, creates carbon credits passport 
+        /// and mints carbon credits of the last annual report to the new holder. Fails atomically
         /// 
         /// </pre>
-        #[weight = 10_000 + T::DbWeight::get().reads_writes(5, 4)]
+        #[weight = 10_000 + T::DbWeight::get().reads_writes(13, 12)]
         pub fn release_carbon_credits(
             origin, 
             project_id: ProjectId,
@@ -739,72 +1388,76 @@ decl_module! {
             new_carbon_credits_holder: T::AccountId,
             min_balance: <T as pallet_assets::Config>::Balance,
         ) -> DispatchResult {
-            let project_owner = ensure_signed(origin.clone())?;
-            ensure!(accounts::Module::<T>::account_is_cc_project_owner(&project_owner), Error::<T>::AccountNotOwner);
+            Self::do_release_carbon_credits(origin, project_id, None, asset_id, new_carbon_credits_holder, min_balance, None)
+        }
 
-            ProjectById::<T>::try_mutate(
-                project_id, |project_option| -> DispatchResult {
-                    match project_option {
-                        None => Err(Error::<T>::ProjectNotExist.into()),
-                        Some(project) => {
-                            ensure!(project.owner == project_owner, Error::<T>::AccountNotOwner);
-                            ensure!(project.state == project::REGISTERED, Error::<T>::ProjectNotRegistered);
-        
-                            // Check that there is at least one annual report
-                            let reports_len = project.annual_reports.len();
-                            ensure!(reports_len > 0,
-                                Error::<T>::NoAnnualReports
-                            );
-        
-                            // ensure that carbon credits not released, and then set it to released state
-                            let last_annual_report = &mut project.annual_reports[reports_len - 1];
-                            ensure!(!last_annual_report.is_carbon_credits_released(), Error::<T>::CCAlreadyCreated);
-                            last_annual_report.set_carbon_credits_released();
-            
-                            // Create Asset:
-                            let new_carbon_credits_holder_source = <T::Lookup as StaticLookup>::unlookup(new_carbon_credits_holder);
-                            let create_asset_call = pallet_assets::Call::<T>::create(asset_id, new_carbon_credits_holder_source, 0, min_balance);
-                            let create_asset_result = create_asset_call.dispatch_bypass_filter(origin.clone());
-                            ensure!(!create_asset_result.is_err(), Error::<T>::ErrorCreatingAsset);
-        
-          
-                            // Set metadata from annual report
-                            // Changing metadata of annual report to empty struct
-                            let mut meta = annual_report::CarbonCreditsMeta::default();
-                            sp_std::mem::swap(&mut meta, &mut last_annual_report.carbon_credits_meta);
-                            let set_metadata_call = pallet_assets::Call::<T>::set_metadata(asset_id, 
-                                                    meta.name, 
-                                                    meta.symbol, 
-                                                    meta.decimals
-                                                );
-        
-                            let result = set_metadata_call.dispatch_bypass_filter(origin.clone());
-                            ensure!(!result.is_err(), {
-                                // destroy if failed
-                                let _ = pallet_assets::Call::<T>::destroy(asset_id, 0);
-                                Error::<T>::SetMetadataFailed
-                            });
-        
-                            // Mint Carbon Credits
-                            let cc_amount = last_annual_report.carbon_credits_count();
-                            let new_carbon_credits_holder_source = <T::Lookup as StaticLookup>::unlookup(project_owner.clone());
-                            let mint_call = pallet_assets::Call::<T>::mint(asset_id, new_carbon_credits_holder_source, cc_amount);
-                            let result = mint_call.dispatch_bypass_filter(origin);
-                            ensure!(!result.is_err(), {
-                                // destroy if failed
-                                let _ = pallet_assets::Call::<T>::destroy(asset_id, 0);
-                                Error::<T>::ErrorMintingAsset
-                            });
-        
-                            // Create passport
-                            <CarbonCreditPassportRegistry<T>>::insert(asset_id, CarbonCreditsPassport::new(asset_id, project_id, project.annual_reports.len()));
-                            Ok(())
-                        }
-                    }
-             })?;
-    
-            Self::deposit_event(RawEvent::CarbonCreditsMinted(project_owner, project_id, asset_id));
-            Ok(())
+        /// <pre>
+        /// Method: release_annual_report_carbon_credits(
+        ///         project_id: ProjectId
+        ///         report_index: u64,
+        ///         asset_id: <T as pallet_assets::Config>::AssetId,
+        ///         new_carbon_credits_holder: T::AccountId,
+        ///         min_balance: <T as pallet_assets::Config>::Balance,
+        ///     )
+        /// 
+        /// Arguments: origin: AccountId - Transaction caller
+        ///            report_index - index of annual report in the project, starting from 0
+        ///            asset_id: <T as pallet_assets::Config>::AssetId - Asset Id in assets pallet
+        ///            new_carbon_credits_holder - carbon credits holder, can be other than project owner
+        ///            min_balance - min balance for assets pallet
+        ///
+        /// Access: Issuer role of the standard - project owner by default, or registry, that signed the project
+        ///
+        /// Creates assets in assets pallet with issuer as admin, creates carbon credits passport 
+        /// and mints carbon credits of the given annual report to the new holder. Fails atomically
+        /// 
+        /// </pre>
+        #[weight = 10_000 + T::DbWeight::get().reads_writes(13, 12)]
+        pub fn release_annual_report_carbon_credits(
+            origin, 
+            project_id: ProjectId,
+            report_index: u64,
+            asset_id: <T as pallet_assets::Config>::AssetId,
+            new_carbon_credits_holder: T::AccountId,
+            min_balance: <T as pallet_assets::Config>::Balance,
+        ) -> DispatchResult {
+            Self::do_release_carbon_credits(origin, project_id, Some(report_index), asset_id, new_carbon_credits_holder, min_balance, None)
+        }
+
+        /// <pre>
+        /// Method: release_carbon_credits_tranche(
+        ///         project_id: ProjectId
+        ///         report_index: u64,
+        ///         asset_id: <T as pallet_assets::Config>::AssetId,
+        ///         new_carbon_credits_holder: T::AccountId,
+        ///         min_balance: <T as pallet_assets::Config>::Balance,
+        ///         amount: <T as pallet_assets::Config>::Balance,
+        ///     )
+        /// 
+        /// Arguments: origin: AccountId - Transaction caller
+        ///            report_index - index of annual report in the project, starting from 0
+        ///            asset_id: <T as pallet_assets::Config>::AssetId - Asset Id in assets pallet
+        ///            new_carbon_credits_holder - carbon credits holder, can be other than project owner
+        ///            min_balance - min balance for assets pallet, used only in the first tranche
+        ///            amount - carbon credits to release in this tranche
+        ///
+        /// Access: Issuer role of the standard - project owner by default, or registry, that signed the project
+        ///
+        /// Releases part of carbon credits of the given annual report. The first tranche creates asset 
+        /// and carbon credits passport, next tranches mint to the same asset up to the verified total
+        /// 
+        /// </pre>
+        #[weight = 10_000 + T::DbWeight::get().reads_writes(13, 12)]
+        pub fn release_carbon_credits_tranche(
+            origin, 
+            project_id: ProjectId,
+            report_index: u64,
+            asset_id: <T as pallet_assets::Config>::AssetId,
+            new_carbon_credits_holder: T::AccountId,
+            min_balance: <T as pallet_assets::Config>::Balance,
+            amount: <T as pallet_assets::Config>::Balance,
+        ) -> DispatchResult {
+            Self::do_release_carbon_credits(origin, project_id, Some(report_index), asset_id, new_carbon_credits_holder, min_balance, Some(amount))
         }
 
         /// <pre>
@@ -820,25 +1473,35 @@ decl_module! {
         ///  Transfers carbon creadits of asset id in given amount to an adress
         /// 
         /// </pre>
-        #[weight = 10_000 + T::DbWeight::get().reads_writes(2, 1)]
+        #[weight = <T as Config>::WeightInfo::transfer_carbon_credits()]
         pub fn transfer_carbon_credits(
             origin, 
             asset_id: <T as pallet_assets::Config>::AssetId, 
             new_carbon_credits_holder: T::AccountId, 
             amount: T::Balance
         ) -> DispatchResult {
-            let owner = ensure_signed(origin.clone())?;
-            // check passport creds
-            let passport = CarbonCreditPassportRegistry::<T>::get(asset_id);
-            ensure!(passport.is_some(), Error::<T>::PassportNotExist);
-
-            let new_carbon_credits_holder_source = <T::Lookup as StaticLookup>::unlookup(new_carbon_credits_holder.clone());
-            let transfer_call = pallet_assets::Call::<T>::transfer(asset_id, new_carbon_credits_holder_source, amount);
-            let result = transfer_call.dispatch_bypass_filter(origin);
-            ensure!(!result.is_err(), Error::<T>::TransferFailed);
+            Self::do_transfer_carbon_credits(origin, asset_id, new_carbon_credits_holder, amount)
+        }
 
-            Self::deposit_event(RawEvent::CarbonCreditsTransfered(owner, new_carbon_credits_holder, asset_id));
-            Ok(())
+        /// <pre>
+        /// Method: batch_transfer_carbon_credits(
+        ///    transfers: Vec<(<T as pallet_assets::Config>::AssetId, T::AccountId, T::Balance)>
+        ///) 
+        /// Arguments: origin: AccountId - Transaction caller
+        ///            transfers - list of (asset_id, new_carbon_credits_holder, amount), at most MAX_BATCH_TRANSFERS items
+        ///
+        /// Access: Carbon Credits holder
+        ///
+        /// Transfers carbon credits of several assets to several adresses. Fails atomically, if any transfer fails
+        /// 
+        /// </pre>
+        #[weight = <T as Config>::WeightInfo::batch_transfer_carbon_credits(transfers.len() as u32)]
+        pub fn batch_transfer_carbon_credits(
+            origin, 
+            transfers: Vec<(<T as pallet_assets::Config>::AssetId, T::AccountId, T::Balance)>,
+        ) -> DispatchResult {
+            ensure!(!transfers.is_empty() && transfers.len() <= MAX_BATCH_TRANSFERS, Error::<T>::InvalidTransferBatch);
+            Self::do_batch_transfer_carbon_credits(origin, transfers)
         }
 
         /// <pre>
@@ -852,145 +1515,954 @@ decl_module! {
         ///
         /// Access: Holder of carbon credits
         ///
-        /// Burns amount of carbon credits
+        /// Burns amount of carbon credits, issuing retirement certificate to the holder
         /// 
         /// </pre>
-        #[weight = 10_000 + T::DbWeight::get().reads_writes(3, 2)]
+        #[weight = 10_000 + T::DbWeight::get().reads_writes(12, 12)]
         pub fn burn_carbon_credits(
             origin, 
             asset_id: <T as pallet_assets::Config>::AssetId, 
             amount: T::Balance
         ) -> DispatchResult {
             let credits_holder = ensure_signed(origin.clone())?;
-            // check passport creds
-            let passport = CarbonCreditPassportRegistry::<T>::get(asset_id);
-            ensure!(passport.is_some(), Error::<T>::PassportNotExist);
-            ensure!(pallet_assets::Pallet::<T>::balance(asset_id, credits_holder.clone()) >= amount,
-                Error::<T>::InsufficientCarbonCredits
-            );
-
-            BurnCertificates::<T>::try_mutate(
-                credits_holder.clone(), |certificates| -> DispatchResult {
-                    match certificates.iter_mut().find(|x| x.asset_id == asset_id) {
-                        Some(cert) => {
-                            cert.burn_amount += amount;
-                        },
-                        None => {
-                            certificates.push(CarbonCreditsBurnCertificate::new(asset_id, amount));
-                        }
-                    }
+            Self::do_burn_carbon_credits(origin, asset_id, amount, credits_holder, Vec::new(), Vec::new())
+        }
 
-                    let burn_call = pallet_assets::Call::<T>::burn_self_assets(asset_id, amount);
-                    let result = burn_call.dispatch_bypass_filter(origin);
-                    ensure!(!result.is_err(), Error::<T>::BurnFailed);
-                    Ok(())
-                }
+        /// <pre>
+        /// Method: retire_carbon_credits(
+        ///    asset_id: <T as pallet_assets::Config>::AssetId, 
+        ///    amount: T::Balance,
+        ///    beneficiary_name: Vec<u8>,
+        ///    reason: Vec<u8>,
+        ///) 
+        /// Arguments: origin: AccountId - Transaction caller
+        ///            asset_id - id of asset_id
+        ///            amount - amount to burn
+        ///            beneficiary_name - name of the organisation, which emissions are offset
+        ///            reason - purpose of the retirement
+        ///
+        /// Access: Holder of carbon credits
+        ///
+        /// Burns amount of carbon credits, issuing retirement certificate with beneficiary name and reason to the holder
+        /// 
+        /// </pre>
+        #[weight = 10_000 + T::DbWeight::get().reads_writes(12, 12)]
+        pub fn retire_carbon_credits(
+            origin, 
+            asset_id: <T as pallet_assets::Config>::AssetId, 
+            amount: T::Balance,
+            beneficiary_name: Vec<u8>,
+            reason: Vec<u8>,
+        ) -> DispatchResult {
+            let credits_holder = ensure_signed(origin.clone())?;
+            Self::do_burn_carbon_credits(origin, asset_id, amount, credits_holder, beneficiary_name, reason)
+        }
 
-            )?;
+        /// <pre>
+        /// Method: burn_carbon_credits_for(
+        ///    asset_id: <T as pallet_assets::Config>::AssetId, 
+        ///    amount: T::Balance,
+        ///    beneficiary: T::AccountId,
+        ///) 
+        /// Arguments: origin: AccountId - Transaction caller
+        ///            asset_id - id of asset_id
+        ///            amount - amount to burn
+        ///            beneficiary - account, on behalf of which carbon credits are retired
+        ///
+        /// Access: Holder of carbon credits
+        ///
+        /// Burns amount of carbon credits of the caller, issuing retirement certificate to the beneficiary
+        /// 
+        /// </pre>
+        #[weight = 10_000 + T::DbWeight::get().reads_writes(12, 12)]
+        pub fn burn_carbon_credits_for(
+            origin, 
+            asset_id: <T as pallet_assets::Config>::AssetId, 
+            amount: T::Balance,
+            beneficiary: T::AccountId,
+        ) -> DispatchResult {
+            Self::do_burn_carbon_credits(origin, asset_id, amount, beneficiary, Vec::new(), Vec::new())
+        }
 
-            Self::deposit_event(RawEvent::CarbonCreditsAssetBurned(credits_holder, asset_id));
+        /// <pre>
+        /// Method: cancel_buffer_carbon_credits(
+        ///    asset_id: <T as pallet_assets::Config>::AssetId, 
+        ///    amount: T::Balance,
+        ///) 
+        /// Arguments: origin: AccountId - Transaction caller
+        ///            asset_id - id of carbon credits asset
+        ///            amount - amount of buffer carbon credits to cancel
+        ///
+        /// Access: Root
+        ///
+        /// Burns carbon credits of the asset in buffer pool account to cover reversal
+        /// 
+        /// </pre>
+        #[weight = 10_000 + T::DbWeight::get().reads_writes(11, 8)]
+        pub fn cancel_buffer_carbon_credits(
+            origin, 
+            asset_id: <T as pallet_assets::Config>::AssetId, 
+            amount: T::Balance,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            let (project_id, vintage, burned) = CarbonCreditPassportRegistry::<T>::try_mutate(asset_id, |passport_option| -> Result<(ProjectId, u16, T::Balance), DispatchError> {
+                let passport = passport_option.as_mut().ok_or(Error::<T>::PassportNotExist)?;
+                ensure!(!amount.is_zero() && amount <= passport.get_buffer_carbon_credits(), 
+                    Error::<T>::InsufficientBufferCarbonCredits
+                );
+                let buffer_pool = Self::buffer_pool_account_id();
+                let burn_call = pallet_assets::Call::<T>::burn_self_assets(asset_id, amount);
+                let (burned, _) = Self::dispatch_taking_serial_ranges(asset_id, &buffer_pool, burn_call,
+                    frame_system::RawOrigin::Signed(buffer_pool.clone()).into(), Error::<T>::BurnFailed
+                )?;
+                passport.cancel_buffer_carbon_credits(burned);
+                Ok((passport.get_project_id(), passport.get_vintage(), burned))
+            })?;
+            Self::update_carbon_credits_totals(project_id, vintage, |totals| totals.cancel(burned));
+
+            Self::deposit_event(RawEvent::BufferCarbonCreditsCancelled(asset_id, burned));
+            Ok(())
+        }
+
+        /// <pre>
+        /// Method: report_reversal(
+        ///    project_id: ProjectId, 
+        ///    amount: T::Balance,
+        ///    evidence_file_id: FileId,
+        ///) 
+        /// Arguments: origin: AccountId - Transaction caller
+        ///            project_id - id of the project
+        ///            amount - lost emission reductions
+        ///            evidence_file_id - id of the file with evidence of the loss in filesign pallet
+        ///
+        /// Access: Registry, that is signer of the project
+        ///
+        /// Covers the loss by cancelling buffer carbon credits of the project, then by burning unretired carbon credits
+        /// of all holders by the asset admin, starting from the latest annual report. Carbon credits of at most 
+        /// MAX_REVERSAL_HOLDERS holders are burned. Passports of affected assets are marked
+        /// 
+        /// </pre>
+        #[weight = 10_000 + T::DbWeight::get().reads_writes(
+            7 + 6 * MAX_ANNUAL_REPORTS + 5 * MAX_REVERSAL_HOLDERS as u64, 
+            4 + 5 * MAX_ANNUAL_REPORTS + 3 * MAX_REVERSAL_HOLDERS as u64
+        )]
+        pub fn report_reversal(
+            origin, 
+            project_id: ProjectId,
+            amount: T::Balance,
+            evidence_file_id: FileId,
+        ) -> DispatchResult {
+            Self::do_report_reversal(origin, project_id, amount, evidence_file_id)
+        }
+
+        /// <pre>
+        /// Method: freeze_carbon_credits(asset_id: <T as pallet_assets::Config>::AssetId) 
+        /// Arguments: origin: AccountId - Transaction caller
+        ///            asset_id - id of carbon credits asset
+        ///
+        /// Access: Registry, that is signer of the project
+        ///
+        /// Stops transfers and retirements of carbon credits asset, e.g. while fraud is investigated
+        /// 
+        /// </pre>
+        #[weight = 10_000 + T::DbWeight::get().reads_writes(3, 1)]
+        pub fn freeze_carbon_credits(origin, asset_id: <T as pallet_assets::Config>::AssetId) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            Self::set_carbon_credits_frozen(&caller, asset_id, true)?;
+            Self::deposit_event(RawEvent::CarbonCreditsFrozen(caller, asset_id));
+            Ok(())
+        }
+
+        /// <pre>
+        /// Method: thaw_carbon_credits(asset_id: <T as pallet_assets::Config>::AssetId) 
+        /// Arguments: origin: AccountId - Transaction caller
+        ///            asset_id - id of carbon credits asset
+        ///
+        /// Access: Registry, that is signer of the project
+        ///
+        /// Allows transfers and retirements of frozen carbon credits asset again
+        /// 
+        /// </pre>
+        #[weight = 10_000 + T::DbWeight::get().reads_writes(3, 1)]
+        pub fn thaw_carbon_credits(origin, asset_id: <T as pallet_assets::Config>::AssetId) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            Self::set_carbon_credits_frozen(&caller, asset_id, false)?;
+            Self::deposit_event(RawEvent::CarbonCreditsThawed(caller, asset_id));
+            Ok(())
+        }
+
+        /// <pre>
+        /// Method: freeze_carbon_credits_holder(
+        ///    asset_id: <T as pallet_assets::Config>::AssetId, 
+        ///    holder: T::AccountId,
+        ///    reason_hash: H256,
+        ///) 
+        /// Arguments: origin: AccountId - Transaction caller
+        ///            asset_id - id of carbon credits asset
+        ///            holder - account, which carbon credits are frozen
+        ///            reason_hash - hash of the document, describing freeze reason
+        ///
+        /// Access: Registry, that is signer of the project
+        ///
+        /// Stops transfers and retirements of carbon credits asset from the holder account only
+        /// 
+        /// </pre>
+        #[weight = 10_000 + T::DbWeight::get().reads_writes(4, 1)]
+        pub fn freeze_carbon_credits_holder(
+            origin, 
+            asset_id: <T as pallet_assets::Config>::AssetId,
+            holder: T::AccountId,
+            reason_hash: H256,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            Self::ensure_asset_registry(&caller, asset_id)?;
+            ensure!(!HolderFreezes::<T>::contains_key(asset_id, &holder), Error::<T>::CarbonCreditsHolderFreezeUnchanged);
+            HolderFreezes::<T>::insert(asset_id, &holder, HolderFreezeStruct::new(caller.clone(), reason_hash));
+
+            Self::deposit_event(RawEvent::CarbonCreditsHolderFrozen(caller, asset_id, holder));
+            Ok(())
+        }
+
+        /// <pre>
+        /// Method: thaw_carbon_credits_holder(
+        ///    asset_id: <T as pallet_assets::Config>::AssetId, 
+        ///    holder: T::AccountId,
+        ///) 
+        /// Arguments: origin: AccountId - Transaction caller
+        ///            asset_id - id of carbon credits asset
+        ///            holder - account, which carbon credits are frozen
+        ///
+        /// Access: Registry, that is signer of the project
+        ///
+        /// Removes freeze of carbon credits asset from the holder account
+        /// 
+        /// </pre>
+        #[weight = 10_000 + T::DbWeight::get().reads_writes(4, 1)]
+        pub fn thaw_carbon_credits_holder(
+            origin, 
+            asset_id: <T as pallet_assets::Config>::AssetId,
+            holder: T::AccountId,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            Self::ensure_asset_registry(&caller, asset_id)?;
+            ensure!(HolderFreezes::<T>::contains_key(asset_id, &holder), Error::<T>::CarbonCreditsHolderFreezeUnchanged);
+            HolderFreezes::<T>::remove(asset_id, &holder);
+
+            Self::deposit_event(RawEvent::CarbonCreditsHolderThawed(caller, asset_id, holder));
             Ok(())
         }
     }
 }
 
 impl<T: Config> Module<T> {
-    /// Changes state of a project by signing
-    fn change_project_state(project: &mut ProjectStruct<T::AccountId, T, T::Balance>, caller: T::AccountId, event: &mut Option<Event<T>>) -> DispatchResult {
-        match &mut project.get_standard() {
-            // Project Owner submits PDD (changing status to Registration) => 
-            // => Auditor Approves PDD => Standard Certifies PDD => Registry Registers PDD (changing status to Issuance)
-            Standard::GOLD_STANDARD  => {
-                match project.state {
-                    project::PROJECT_OWNER_SIGN_PENDING => {
-                        ensure!(accounts::Module::<T>::account_is_cc_project_owner(&caller), Error::<T>::AccountNotOwner);
-                        ensure!(project.owner == caller, Error::<T>::AccountNotOwner);
-                        ensure!(project.is_ready_for_signing(), Error::<T>::IncorrectFileId);
-                        ensure!(Self::is_correct_project_signer(project, caller.clone(), accounts::accounts::CC_PROJECT_OWNER_ROLE_MASK), 
-                            Error::<T>::IncorrectProjectSigner);
-                        project.state = project::AUDITOR_SIGN_PENDING;
-                        project.status = project::ProjectStatus::REGISTRATION;
-                        *event = Some(RawEvent::ProjectSubmited(caller, project.id));
-                    },
-                    project::AUDITOR_SIGN_PENDING => {
-                        ensure!(accounts::Module::<T>::account_is_cc_auditor(&caller), Error::<T>::AccountNotAuditor);
-                        ensure!(Self::is_correct_project_signer(project, caller.clone(), accounts::accounts::CC_AUDITOR_ROLE_MASK), 
-                            Error::<T>::IncorrectProjectSigner);
-                        project.state = project::STANDARD_SIGN_PENDING;
-                        *event = Some(RawEvent::ProjectSignedByAduitor(caller, project.id));
-                    },
-                    project::STANDARD_SIGN_PENDING => {
-                        ensure!(accounts::Module::<T>::account_is_cc_standard(&caller), Error::<T>::AccountNotStandard);
-                        ensure!(Self::is_correct_project_signer(project, caller.clone(), accounts::accounts::CC_STANDARD_ROLE_MASK), 
-                            Error::<T>::IncorrectProjectSigner);
-                        project.state = project::REGISTRY_SIGN_PENDING;
-                        *event = Some(RawEvent::ProjectSignedByStandard(caller, project.id));
-                    },
-                    project::REGISTRY_SIGN_PENDING => {
-                        ensure!(accounts::Module::<T>::account_is_cc_registry(&caller), Error::<T>::AccountNotRegistry);
-                        ensure!(Self::is_correct_project_signer(project, caller.clone(), accounts::accounts::CC_REGISTRY_ROLE_MASK), 
-                            Error::<T>::IncorrectProjectSigner);
-                        project.state = project::REGISTERED;
-                        project.status = project::ProjectStatus::ISSUANCE;
-                        *event = Some(RawEvent::ProjectSignedByRegistry(caller, project.id));
-                    },
-                    _ => return Err(Error::<T>::InvalidState.into())
+    fn do_change_report_carbon_credits_count(origin: T::Origin, project_id: ProjectId, report_index: Option<u64>, new_carbon_credits_count: T::Balance) -> DispatchResult {
+        let caller = ensure_signed(origin)?;
+        ensure!(accounts::Module::<T>::account_is_cc_project_owner(&caller), Error::<T>::AccountNotOwner);
+        let project = ProjectById::<T>::get(project_id).ok_or(Error::<T>::ProjectNotExist)?;
+        ensure!(project.owner == caller, Error::<T>::AccountNotOwner);
+        let index = Self::get_annual_report_index(&project, report_index)?;
+        AnnualReports::<T>::try_mutate(
+            project_id, index, |report_to_mutate| -> DispatchResult {
+                match report_to_mutate {
+                    None => return Err(Error::<T>::AnnualReportNotExist.into()),
+                    Some(report) => {
+                        // Ensure, that report in initial preparing state REPORT_PROJECT_OWNER_SIGN_PENDING
+                        ensure!(report.state == annual_report::REPORT_PROJECT_OWNER_SIGN_PENDING, Error::<T>::InvalidState);
+                        report.change_carbon_credits_count(new_carbon_credits_count);
+                    }
+                }
+                Ok(())
+         })?;
+        // SendEvent
+        Self::deposit_event(RawEvent::AnnualReportCreditsCountChanged(caller, project_id, new_carbon_credits_count));
+        Ok(())
+    }
+
+    fn do_delete_annual_report(origin: T::Origin, project_id: ProjectId, report_index: Option<u64>) -> DispatchResult {
+        let caller = ensure_signed(origin)?;
+        ensure!(accounts::Module::<T>::account_is_cc_project_owner(&caller), Error::<T>::AccountNotOwner);
+        let mut project = ProjectById::<T>::get(project_id).ok_or(Error::<T>::ProjectNotExist)?;
+        ensure!(project.owner == caller, Error::<T>::AccountNotOwner);
+        let index = Self::get_annual_report_index(&project, report_index)?;
+        let deleted_report = AnnualReports::<T>::get(project_id, index).ok_or(Error::<T>::AnnualReportNotExist)?;
+        // Ensure, that report not in final issued state
+        // To prevent deleting ready reports, that have its carbon credits released
+        ensure!(deleted_report.state != annual_report::REPORT_ISSUED, Error::<T>::InvalidState);
+        // Passports reference reports by their index in the project, so indexes are never reused
+        AnnualReports::<T>::remove(project_id, index);
+        AnnualReportRejections::<T>::remove_prefix((project_id, index));
+        project.live_annual_reports_count -= 1;
+        ProjectById::<T>::insert(project_id, project);
+        deleted_report.get_required_signers().iter()
+            .for_each(|(signer, _)| Self::remove_project_signer_index(signer, project_id));
+        // SendEvent
+        Self::deposit_event(RawEvent::AnnualReportDeleted(caller, project_id));
+        Ok(())
+    }
+
+    fn do_assign_annual_report_signer(origin: T::Origin, signer: T::AccountId, role: RoleMask, project_id: ProjectId, report_index: Option<u64>) -> DispatchResult {
+        let caller = ensure_signed(origin.clone())?;
+        ensure!(pallet_evercity_accounts::Module::<T>::account_is_selected_role(&signer, role), Error::<T>::AccountIncorrectRole);
+        let project = ProjectById::<T>::get(project_id).ok_or(Error::<T>::ProjectNotExist)?;
+        ensure!(project.owner == caller, Error::<T>::AccountNotOwner);
+        let index = Self::get_annual_report_index(&project, report_index)?;
+        let is_new_signer = AnnualReports::<T>::try_mutate(
+            project_id, index, |report_to_mutate| -> Result<bool, DispatchError> {
+                match report_to_mutate {
+                    None => Err(Error::<T>::AnnualReportNotExist.into()),
+                    Some(report) => {
+                        let is_new_signer = !report.is_required_signer((signer.clone(), role));
+                        report.assign_required_signer((signer.clone(), role));
+                        // Assign signer in filesign pallet:
+                        pallet_evercity_filesign::Module::<T>::assign_signer(origin.clone(), report.file_id, signer.clone())?;
+                        Ok(is_new_signer)
+                    }
+                }
+         })?;
+        if is_new_signer {
+            Self::add_project_signer_index(&signer, project_id);
+        }
+        Self::deposit_event(RawEvent::AnnualReportSignerAdded(caller, signer, role, project_id));
+        Ok(())
+    }
+
+    fn do_remove_annual_report_signer(origin: T::Origin, signer: T::AccountId, role: RoleMask, project_id: ProjectId, report_index: Option<u64>) -> DispatchResult {
+        let caller = ensure_signed(origin.clone())?;
+        ensure!(pallet_evercity_accounts::Module::<T>::account_is_selected_role(&signer, role), Error::<T>::AccountIncorrectRole);
+        let project = ProjectById::<T>::get(project_id).ok_or(Error::<T>::ProjectNotExist)?;
+        ensure!(project.owner == caller, Error::<T>::AccountNotOwner);
+        let index = Self::get_annual_report_index(&project, report_index)?;
+        AnnualReports::<T>::try_mutate(
+            project_id, index, |report_to_mutate| -> DispatchResult {
+                match report_to_mutate {
+                    None => return Err(Error::<T>::AnnualReportNotExist.into()),
+                    Some(report) => {
+                        // Check if signer did not already sign the project
+                        ensure!(report.is_required_signer((signer.clone(), role)), Error::<T>::AccountNotGivenRoleSigner);
+                        let has_signed = pallet_evercity_filesign::Module::<T>::address_has_signed_the_file(report.file_id, &signer);
+                        ensure!(!has_signed, Error::<T>::AccountAlreadySigned);
+                        report.remove_required_signer((signer.clone(), role));
+                        // delete signer in filesign pallet
+                        pallet_evercity_filesign::Module::<T>::delete_signer(origin.clone(), report.file_id, signer.clone())?;
+                    }
+                }
+                Ok(())
+         })?;
+        Self::remove_project_signer_index(&signer, project_id);
+        Self::deposit_event(RawEvent::AnnualReportSignerRemoved(caller, signer, role, project_id));
+        Ok(())
+    }
+
+    fn do_sign_annual_report(origin: T::Origin, project_id: ProjectId, report_index: Option<u64>) -> DispatchResult {
+        let caller = ensure_signed(origin.clone())?;
+        let mut event_opt: Option<Event<T>> = None;
+        let project = ProjectById::<T>::get(project_id).ok_or(Error::<T>::ProjectNotExist)?;
+        let index = Self::get_annual_report_index(&project, report_index)?;
+        AnnualReports::<T>::try_mutate(
+            project_id, index, |report_to_mutate| -> DispatchResult {
+                match report_to_mutate {
+                    None => return Err(Error::<T>::AnnualReportNotExist.into()),
+                    Some(report) => {
+                        let annual_report_file_id = report.file_id;
+                        ensure!(pallet_evercity_filesign::Module::<T>::address_is_signer_for_file(annual_report_file_id, &caller), 
+                            Error::<T>::IncorrectAnnualReportSigner);
+                        Self::change_annual_report_state(&project, report, caller, &mut event_opt)?;
+                        pallet_evercity_filesign::Module::<T>::sign_latest_version(origin, 
+                            annual_report_file_id)?;
+                    }
                 }
                 Ok(())
+        })?;
+        if let Some(event) = event_opt {
+            Self::deposit_event(event);
+        }
+        Ok(())
+    }
+
+    fn do_reject_annual_report(origin: T::Origin, project_id: ProjectId, report_index: Option<u64>, reason_hash: H256) -> DispatchResult {
+        let caller = ensure_signed(origin)?;
+        let project = ProjectById::<T>::get(project_id).ok_or(Error::<T>::ProjectNotExist)?;
+        let index = Self::get_annual_report_index(&project, report_index)?;
+        let role = AnnualReports::<T>::try_mutate(
+            project_id, index, |report_to_mutate| -> Result<RoleMask, DispatchError> {
+                match report_to_mutate {
+                    None => Err(Error::<T>::AnnualReportNotExist.into()),
+                    Some(report) => {
+                        let (signing_order, stage) = Self::get_annual_report_signing_stage(&project, report)?;
+                        let role = signing_order[stage];
+                        // Owner didnt submit the report yet
+                        ensure!(role != accounts::accounts::CC_PROJECT_OWNER_ROLE_MASK, Error::<T>::InvalidState);
+                        Self::ensure_account_role(&caller, role)?;
+                        ensure!(Self::is_correct_annual_report_signer(report, caller.clone(), role),
+                            Error::<T>::IncorrectAnnualReportSigner);
+                        let rejection_index = report.reject();
+                        AnnualReportRejections::<T>::insert((project_id, index), rejection_index, RejectionStruct::new(caller.clone(), role, reason_hash));
+                        Ok(role)
+                    }
+                }
+        })?;
+        Self::deposit_event(RawEvent::AnnualReportRejected(caller, project_id, role, reason_hash));
+        Ok(())
+    }
+
+    /// Releases carbon credits of the annual report, all remaining ones or the given tranche. 
+    /// Issuer, defined by the standard, becomes the admin of the new asset, carbon credits are minted to the new holder. 
+    /// Any failure rolls back all storage changes.
+    #[transactional]
+    fn do_release_carbon_credits(
+        origin: T::Origin, 
+        project_id: ProjectId,
+        report_index: Option<u64>,
+        asset_id: <T as pallet_assets::Config>::AssetId,
+        new_carbon_credits_holder: T::AccountId,
+        min_balance: <T as pallet_assets::Config>::Balance,
+        tranche_amount: Option<<T as pallet_assets::Config>::Balance>,
+    ) -> DispatchResult {
+        let issuer = ensure_signed(origin.clone())?;
+        let project = ProjectById::<T>::get(project_id).ok_or(Error::<T>::ProjectNotExist)?;
+        // Issuer role is fixed in the project on submission
+        let standard = Self::get_project_standard_definition(&project)?;
+        Self::ensure_account_role(&issuer, standard.issuer_role)?;
+        if standard.issuer_role == accounts::accounts::CC_PROJECT_OWNER_ROLE_MASK {
+            ensure!(project.owner == issuer, Error::<T>::AccountNotOwner);
+        } else {
+            ensure!(project.is_required_signer((issuer.clone(), standard.issuer_role)), Error::<T>::IncorrectProjectSigner);
+        }
+        ensure!(project.state == project::REGISTERED, Error::<T>::ProjectNotRegistered);
+        // Check that annual report exists
+        let index = Self::get_annual_report_index(&project, report_index)?;
+        let mut report = AnnualReports::<T>::get(project_id, index).ok_or(Error::<T>::AnnualReportNotExist)?;
+        // Check that annual report is signed by all stakeholders
+        ensure!(report.is_full_signed(), Error::<T>::AnnualReportNotReady);
+        // Carbon credits can be released only for the past emission reduction
+        ensure!(report.monitoring_period.is_ended(&Timestamp::<T>::get()), Error::<T>::MonitoringPeriodNotEnded);
+        ensure!(!report.is_carbon_credits_released(), Error::<T>::CCAlreadyCreated);
+        let amount = tranche_amount.unwrap_or_else(|| report.remaining_carbon_credits());
+        ensure!(!amount.is_zero() && amount <= report.remaining_carbon_credits(), Error::<T>::InvalidCarbonCreditsAmount);
+        // Buffer percent is fixed in the project on submission, so every tranche withholds the same share
+        let buffer_amount = Percent::from_percent(standard.buffer_percent).mul_floor(amount);
+
+        let mut passport = if report.has_carbon_credits_issued() {
+            // Next tranches are minted to the asset of the first one
+            let passport = CarbonCreditPassportRegistry::<T>::get(asset_id).ok_or(Error::<T>::PassportNotExist)?;
+            ensure!(passport.get_project_id() == project_id, Error::<T>::BadPassportProject);
+            ensure!(passport.get_annual_report_index() == index + 1, Error::<T>::BadPassportAnnualReport);
+            passport
+        } else {
+            // Create Asset, issuer is the admin of carbon credits asset
+            AssetAdmins::<T>::insert(asset_id, issuer.clone());
+            let asset_admin_source = <T::Lookup as StaticLookup>::unlookup(issuer);
+            let create_asset_call = pallet_assets::Call::<T>::create(asset_id, asset_admin_source, 0, min_balance);
+            create_asset_call.dispatch_bypass_filter(origin.clone())
+                .map_err(|_| Error::<T>::ErrorCreatingAsset)?;
+
+            // Set metadata from annual report
+            // Changing metadata of annual report to empty struct
+            let mut meta = annual_report::CarbonCreditsMeta::default();
+            sp_std::mem::swap(&mut meta, &mut report.carbon_credits_meta);
+            let set_metadata_call = pallet_assets::Call::<T>::set_metadata(asset_id, meta.name, meta.symbol, meta.decimals);
+            set_metadata_call.dispatch_bypass_filter(origin.clone())
+                .map_err(|_| Error::<T>::SetMetadataFailed)?;
+
+            AnnualReportAssets::<T>::insert(project_id, index, asset_id);
+            // Create passport, annual report index in passport starts from 1
+            CarbonCreditsPassport::new(asset_id, project_id, index as usize + 1, 
+                report.monitoring_period.clone(), report.carbon_credits_count()
+            )
+        };
+
+        // Mint Carbon Credits to the new holder, withholding buffer share.
+        // Each tranche gets serial numbers, following the previous tranches
+        let holder_amount = amount - buffer_amount;
+        let new_carbon_credits_holder_source = <T::Lookup as StaticLookup>::unlookup(new_carbon_credits_holder.clone());
+        let mint_call = pallet_assets::Call::<T>::mint(asset_id, new_carbon_credits_holder_source, holder_amount);
+        mint_call.dispatch_bypass_filter(origin.clone())
+            .map_err(|_| Error::<T>::ErrorMintingAsset)?;
+        let holder_serial_range = SerialRange::following(report.carbon_credits_issued(), holder_amount);
+        SerialRanges::<T>::mutate(asset_id, &new_carbon_credits_holder, |ranges| {
+            serial_range::add_serial_ranges(ranges, sp_std::vec![holder_serial_range])
+        });
+        if !buffer_amount.is_zero() {
+            let buffer_pool = Self::buffer_pool_account_id();
+            let buffer_pool_source = <T::Lookup as StaticLookup>::unlookup(buffer_pool.clone());
+            let mint_call = pallet_assets::Call::<T>::mint(asset_id, buffer_pool_source, buffer_amount);
+            mint_call.dispatch_bypass_filter(origin)
+                .map_err(|_| Error::<T>::ErrorMintingAsset)?;
+            let buffer_serial_range = SerialRange::following(holder_serial_range.end, buffer_amount);
+            SerialRanges::<T>::mutate(asset_id, &buffer_pool, |ranges| {
+                serial_range::add_serial_ranges(ranges, sp_std::vec![buffer_serial_range])
+            });
+        }
+
+        let vintage = report.monitoring_period.vintage;
+        passport.issue_carbon_credits(amount, buffer_amount);
+        report.issue_carbon_credits(amount);
+        <CarbonCreditPassportRegistry<T>>::insert(asset_id, passport);
+        AnnualReports::<T>::insert(project_id, index, report);
+        Self::update_carbon_credits_totals(project_id, vintage, |totals| totals.issue(amount, buffer_amount));
+
+        Self::deposit_event(RawEvent::CarbonCreditsMinted(project.owner, project_id, asset_id, vintage));
+        Ok(())
+    }
+
+    fn do_transfer_carbon_credits(
+        origin: T::Origin,
+        asset_id: <T as pallet_assets::Config>::AssetId, 
+        new_carbon_credits_holder: T::AccountId, 
+        amount: T::Balance
+    ) -> DispatchResult {
+        let owner = ensure_signed(origin.clone())?;
+        // check passport creds
+        let passport = CarbonCreditPassportRegistry::<T>::get(asset_id).ok_or(Error::<T>::PassportNotExist)?;
+        ensure!(!passport.is_frozen(), Error::<T>::CarbonCreditsAssetFrozen);
+        ensure!(!HolderFreezes::<T>::contains_key(asset_id, &owner), Error::<T>::CarbonCreditsHolderFrozen);
+
+        let new_carbon_credits_holder_source = <T::Lookup as StaticLookup>::unlookup(new_carbon_credits_holder.clone());
+        let transfer_call = pallet_assets::Call::<T>::transfer(asset_id, new_carbon_credits_holder_source, amount);
+        let (_, serial_ranges) = Self::dispatch_taking_serial_ranges(asset_id, &owner, transfer_call, origin, Error::<T>::TransferFailed)?;
+        SerialRanges::<T>::mutate(asset_id, &new_carbon_credits_holder, |ranges| serial_range::add_serial_ranges(ranges, serial_ranges));
+
+        Self::deposit_event(RawEvent::CarbonCreditsTransfered(owner, new_carbon_credits_holder, asset_id));
+        Ok(())
+    }
+
+    #[transactional]
+    fn do_batch_transfer_carbon_credits(
+        origin: T::Origin,
+        transfers: Vec<(<T as pallet_assets::Config>::AssetId, T::AccountId, T::Balance)>,
+    ) -> DispatchResult {
+        transfers.into_iter().try_for_each(|(asset_id, new_carbon_credits_holder, amount)| {
+            Self::do_transfer_carbon_credits(origin.clone(), asset_id, new_carbon_credits_holder, amount)
+        })
+    }
+
+    /// Updates aggregate carbon credits of the project, its standard, the vintage and the whole ledger
+    fn update_carbon_credits_totals(project_id: ProjectId, vintage: u16, update: impl Fn(&mut CarbonCreditsTotals<T::Balance>)) {
+        ProjectCarbonCreditsTotals::<T>::mutate(project_id, &update);
+        if let Some(project) = ProjectById::<T>::get(project_id) {
+            StandardCarbonCreditsTotals::<T>::mutate(project.get_standard(), &update);
+        }
+        VintageCarbonCreditsTotals::<T>::mutate(vintage, &update);
+        CarbonCreditsTotal::<T>::mutate(&update);
+    }
+
+    /// Dispatches assets call, moving carbon credits out of the account, and takes serial numbers of the moved amount.
+    /// Assets pallet can also move the dust, remaining below min balance, so the moved amount is the balance delta,
+    /// not the requested one
+    fn dispatch_taking_serial_ranges(
+        asset_id: AssetId<T>,
+        account: &T::AccountId,
+        call: pallet_assets::Call<T>,
+        origin: T::Origin,
+        error: Error<T>,
+    ) -> Result<(T::Balance, Vec<SerialRange<T::Balance>>), DispatchError> {
+        let balance_before = pallet_assets::Pallet::<T>::balance(asset_id, account.clone());
+        call.dispatch_bypass_filter(origin).map_err(|_| error)?;
+        let moved = balance_before.saturating_sub(pallet_assets::Pallet::<T>::balance(asset_id, account.clone()));
+        Ok((moved, Self::take_serial_ranges(asset_id, account, moved)))
+    }
+
+    /// Removes the amount of serial numbers from the start of the account ranges
+    fn take_serial_ranges(asset_id: AssetId<T>, account: &T::AccountId, amount: T::Balance) -> Vec<SerialRange<T::Balance>> {
+        SerialRanges::<T>::mutate_exists(asset_id, account, |ranges_option| {
+            let mut ranges = ranges_option.take().unwrap_or_default();
+            let taken = serial_range::take_serial_ranges(&mut ranges, amount);
+            if !ranges.is_empty() {
+                *ranges_option = Some(ranges);
+            }
+            taken
+        })
+    }
+
+    /// Freezes or thaws carbon credits asset by registry, that is signer of its project
+    fn set_carbon_credits_frozen(caller: &T::AccountId, asset_id: AssetId<T>, is_frozen: bool) -> DispatchResult {
+        Self::ensure_asset_registry(caller, asset_id)?;
+        CarbonCreditPassportRegistry::<T>::try_mutate(asset_id, |passport_option| -> DispatchResult {
+            let passport = passport_option.as_mut().ok_or(Error::<T>::PassportNotExist)?;
+            ensure!(passport.is_frozen() != is_frozen, Error::<T>::CarbonCreditsAssetFreezeUnchanged);
+            passport.set_frozen(is_frozen);
+            Ok(())
+        })
+    }
+
+    /// Checks, that the account is registry, that signed the project of carbon credits asset
+    fn ensure_asset_registry(caller: &T::AccountId, asset_id: AssetId<T>) -> DispatchResult {
+        Self::ensure_account_role(caller, accounts::accounts::CC_REGISTRY_ROLE_MASK)?;
+        let passport = CarbonCreditPassportRegistry::<T>::get(asset_id).ok_or(Error::<T>::PassportNotExist)?;
+        let project = ProjectById::<T>::get(passport.get_project_id()).ok_or(Error::<T>::ProjectNotExist)?;
+        ensure!(project.is_required_signer((caller.clone(), accounts::accounts::CC_REGISTRY_ROLE_MASK)), 
+            Error::<T>::IncorrectProjectSigner
+        );
+        Ok(())
+    }
+
+    #[transactional]
+    fn do_report_reversal(
+        origin: T::Origin,
+        project_id: ProjectId,
+        amount: T::Balance,
+        evidence_file_id: FileId,
+    ) -> DispatchResult {
+        let caller = ensure_signed(origin)?;
+        Self::ensure_account_role(&caller, accounts::accounts::CC_REGISTRY_ROLE_MASK)?;
+        let mut project = ProjectById::<T>::get(project_id).ok_or(Error::<T>::ProjectNotExist)?;
+        ensure!(project.is_required_signer((caller.clone(), accounts::accounts::CC_REGISTRY_ROLE_MASK)), 
+            Error::<T>::IncorrectProjectSigner
+        );
+        ensure!(pallet_evercity_filesign::Module::<T>::address_is_owner_for_file(evidence_file_id, &caller), 
+            Error::<T>::AccountNotFileOwner
+        );
+        ensure!(!amount.is_zero(), Error::<T>::InvalidCarbonCreditsAmount);
+
+        // The latest annual reports are affected first
+        let mut passports: Vec<_> = (0..project.annual_reports_count).rev()
+            .filter_map(|index| AnnualReportAssets::<T>::get(project_id, index))
+            .filter_map(|asset_id| CarbonCreditPassportRegistry::<T>::get(asset_id).map(|passport| (asset_id, passport)))
+            .collect();
+        let mut reversed: Vec<T::Balance> = passports.iter().map(|_| T::Balance::zero()).collect();
+        let mut uncovered_amount = amount;
+
+        // Buffer pool covers the loss first
+        let buffer_pool = Self::buffer_pool_account_id();
+        for ((asset_id, passport), reversed_amount) in passports.iter_mut().zip(reversed.iter_mut()) {
+            let cancel_amount = uncovered_amount.min(passport.get_buffer_carbon_credits());
+            if cancel_amount.is_zero() {
+                continue;
+            }
+            let burn_call = pallet_assets::Call::<T>::burn_self_assets(*asset_id, cancel_amount);
+            let (burned, _) = Self::dispatch_taking_serial_ranges(*asset_id, &buffer_pool, burn_call,
+                frame_system::RawOrigin::Signed(buffer_pool.clone()).into(), Error::<T>::BurnFailed
+            )?;
+            passport.cancel_buffer_carbon_credits(burned);
+            *reversed_amount += burned;
+            uncovered_amount = uncovered_amount.saturating_sub(burned);
+        }
+
+        // Then the asset admin burns unretired carbon credits of all holders
+        let mut holders_left = MAX_REVERSAL_HOLDERS;
+        for ((asset_id, _), reversed_amount) in passports.iter().zip(reversed.iter_mut()) {
+            if uncovered_amount.is_zero() || holders_left == 0 {
+                break;
+            }
+            // Assets, created in storage version 1, have neither admin nor serial ranges of holders
+            let admin = match AssetAdmins::<T>::get(asset_id) {
+                Some(admin) => admin,
+                None => continue,
+            };
+            let holders: Vec<T::AccountId> = SerialRanges::<T>::iter_prefix(*asset_id)
+                .map(|(holder, _)| holder)
+                .filter(|holder| *holder != buffer_pool)
+                .take(holders_left)
+                .collect();
+            holders_left -= holders.len();
+            for holder in holders {
+                let burn_amount = uncovered_amount.min(pallet_assets::Pallet::<T>::balance(*asset_id, holder.clone()));
+                if burn_amount.is_zero() {
+                    continue;
+                }
+                let holder_source = <T::Lookup as StaticLookup>::unlookup(holder.clone());
+                let burn_call = pallet_assets::Call::<T>::burn(*asset_id, holder_source, burn_amount);
+                let (burned, _) = Self::dispatch_taking_serial_ranges(*asset_id, &holder, burn_call,
+                    frame_system::RawOrigin::Signed(admin.clone()).into(), Error::<T>::BurnFailed
+                )?;
+                *reversed_amount += burned;
+                uncovered_amount = uncovered_amount.saturating_sub(burned);
+                Self::deposit_event(RawEvent::HolderCarbonCreditsReversed(*asset_id, holder, burned));
             }
         }
+
+        for ((asset_id, mut passport), reversed_amount) in passports.into_iter().zip(reversed) {
+            if reversed_amount.is_zero() {
+                continue;
+            }
+            passport.reverse_carbon_credits(reversed_amount);
+            Self::update_carbon_credits_totals(project_id, passport.get_vintage(), |totals| totals.cancel(reversed_amount));
+            CarbonCreditPassportRegistry::<T>::insert(asset_id, passport);
+            Self::deposit_event(RawEvent::CarbonCreditsReversed(asset_id, reversed_amount));
+        }
+        ReversalsByProject::<T>::insert(project_id, project.reversals_count, 
+            ReversalStruct::new(caller.clone(), evidence_file_id, amount, uncovered_amount)
+        );
+        project.reversals_count += 1;
+        ProjectById::<T>::insert(project_id, project);
+
+        Self::deposit_event(RawEvent::ReversalReported(caller, project_id, amount));
+        Ok(())
     }
 
-    /// Changes state of an annual report by signing
-    fn change_project_annual_report_state(project: &mut ProjectStruct<T::AccountId, T, T::Balance>, caller: T::AccountId, event: &mut Option<Event<T>>) -> DispatchResult {
-        let standard = project.get_standard().clone();
-        let report = match project.annual_reports.last_mut(){
-            None => return Err(Error::<T>::NoAnnualReports.into()),
-            Some(rep) => rep
+    /// Burns carbon credits of the caller, registering individual retirement certificate for the beneficiary
+    fn do_burn_carbon_credits(
+        origin: T::Origin,
+        asset_id: <T as pallet_assets::Config>::AssetId, 
+        amount: T::Balance,
+        beneficiary: T::AccountId,
+        beneficiary_name: Vec<u8>,
+        reason: Vec<u8>,
+    ) -> DispatchResult {
+        let credits_holder = ensure_signed(origin.clone())?;
+        ensure!(burn_certificate::is_valid_retirement_description(&beneficiary_name, &reason), Error::<T>::BadRetirementParameters);
+        // check passport creds
+        let passport = CarbonCreditPassportRegistry::<T>::get(asset_id).ok_or(Error::<T>::PassportNotExist)?;
+        ensure!(!passport.is_frozen(), Error::<T>::CarbonCreditsAssetFrozen);
+        ensure!(!HolderFreezes::<T>::contains_key(asset_id, &credits_holder), Error::<T>::CarbonCreditsHolderFrozen);
+        ensure!(pallet_assets::Pallet::<T>::balance(asset_id, credits_holder.clone()) >= amount,
+            Error::<T>::InsufficientCarbonCredits
+        );
+
+        let burn_call = pallet_assets::Call::<T>::burn_self_assets(asset_id, amount);
+        // Retired amount is the burned one, including the dust
+        let (amount, serial_ranges) = Self::dispatch_taking_serial_ranges(asset_id, &credits_holder, burn_call, origin, Error::<T>::BurnFailed)?;
+
+        BurnedCarbonCredits::<T>::mutate(asset_id, |burned| *burned += amount);
+        Self::update_carbon_credits_totals(passport.get_project_id(), passport.get_vintage(), |totals| totals.retire(amount));
+        BurnCertificates::<T>::mutate(beneficiary.clone(), |certificates| {
+            match certificates.iter_mut().find(|x| x.asset_id == asset_id) {
+                Some(cert) => {
+                    cert.burn_amount += amount;
+                },
+                None => {
+                    certificates.push(CarbonCreditsBurnCertificate::new(asset_id, amount));
+                }
+            }
+        });
+
+        let certificate_id = LastCertificateId::get() + 1;
+        let certificate = RetirementCertificate {
+            id: certificate_id,
+            retired_by: credits_holder.clone(),
+            beneficiary: beneficiary.clone(),
+            beneficiary_name,
+            reason,
+            asset_id,
+            amount,
+            serial_ranges,
+            project_id: passport.get_project_id(),
+            // annual report index in passport starts from 1
+            annual_report_index: passport.get_annual_report_index().saturating_sub(1),
+            timestamp: Timestamp::<T>::get(),
+            block_number: frame_system::Module::<T>::block_number(),
         };
-        match standard {
-            // Project Owner sends report for verification =>  Auditor provides and submits verification report => 
-            // Standard Approves carbon credit issuance => Registry issues carbon credits
-            Standard::GOLD_STANDARD  => {
-                match report.state {
-                    annual_report::REPORT_PROJECT_OWNER_SIGN_PENDING => {
-                        ensure!(accounts::Module::<T>::account_is_cc_project_owner(&caller), Error::<T>::AccountNotOwner);
-                        ensure!(project.owner == caller, Error::<T>::AccountNotOwner);
-                        ensure!(Self::is_correct_annual_report_signer(report, caller.clone(), accounts::accounts::CC_PROJECT_OWNER_ROLE_MASK),
-                            Error::<T>::IncorrectProjectSigner);
-                        ensure!(report.carbon_credits_meta.is_metadata_valid(), Error::<T>::BadMetadataParameters);
-                        report.state = annual_report::REPORT_AUDITOR_SIGN_PENDING;
-                        *event = Some(RawEvent::AnnualReportSubmited(caller, project.id));
-                    },
-                    annual_report::REPORT_AUDITOR_SIGN_PENDING => {
-                        ensure!(accounts::Module::<T>::account_is_cc_auditor(&caller), Error::<T>::AccountNotAuditor);
-                        ensure!(Self::is_correct_annual_report_signer(report, caller.clone(), accounts::accounts::CC_AUDITOR_ROLE_MASK),
-                            Error::<T>::IncorrectProjectSigner);
-                        report.state = annual_report::REPORT_STANDARD_SIGN_PENDING;
-                        *event = Some(RawEvent::AnnualReportSignedByAuditor(caller, project.id));
-                    },
-                    annual_report::REPORT_STANDARD_SIGN_PENDING => {
-                        ensure!(accounts::Module::<T>::account_is_cc_standard(&caller), Error::<T>::AccountNotStandard);
-                        ensure!(Self::is_correct_annual_report_signer(report, caller.clone(), accounts::accounts::CC_STANDARD_ROLE_MASK),
-                            Error::<T>::IncorrectProjectSigner);
-                        report.state = annual_report::REPORT_REGISTRY_SIGN_PENDING;
-                        *event = Some(RawEvent::AnnualReportSignedByStandard(caller, project.id));
-                    },
-                    annual_report::REPORT_REGISTRY_SIGN_PENDING => {
-                        ensure!(accounts::Module::<T>::account_is_cc_registry(&caller), Error::<T>::AccountNotRegistry);
-                        ensure!(Self::is_correct_annual_report_signer(report, caller.clone(), accounts::accounts::CC_REGISTRY_ROLE_MASK),
-                            Error::<T>::IncorrectProjectSigner);
-                        report.state = annual_report::REPORT_ISSUED;
-                        *event = Some(RawEvent::AnnualReportSignedByRegistry(caller, project.id));
-                    },
-                    _ => return Err(Error::<T>::InvalidState.into())
+        RetirementCertificates::<T>::insert(certificate_id, certificate);
+        RetirementCertificatesByAccount::<T>::mutate(beneficiary.clone(), |ids| ids.push(certificate_id));
+        LastCertificateId::put(certificate_id);
+
+        Self::deposit_event(RawEvent::CarbonCreditsAssetBurned(credits_holder.clone(), asset_id));
+        Self::deposit_event(RawEvent::RetirementCertificateIssued(credits_holder, beneficiary, certificate_id));
+        Ok(())
+    }
+
+    /// Checks that monitoring period is valid, lies in the crediting period and is not covered by other annual reports of the project
+    fn ensure_monitoring_period_available(
+        project: &ProjectStruct<T::AccountId>, 
+        monitoring_period: &annual_report::MonitoringPeriod<Moment<T>>
+    ) -> Result<u64, DispatchError> {
+        ensure!(monitoring_period.is_valid(), Error::<T>::InvalidMonitoringPeriod);
+        // Projects, registered before crediting periods were introduced, dont have it
+        if let Some(crediting_period) = CreditingPeriods::<T>::get(project.id) {
+            ensure!(crediting_period.contains(monitoring_period), Error::<T>::MonitoringPeriodOutsideCreditingPeriod);
+        }
+        // Deleted reports are removed from storage, so at most MAX_ANNUAL_REPORTS reports are read
+        let mut reports_read: u64 = 0;
+        let overlaps = AnnualReports::<T>::iter_prefix_values(project.id)
+            .inspect(|_| reports_read += 1)
+            .any(|report| report.monitoring_period.overlaps(monitoring_period));
+        ensure!(!overlaps, Error::<T>::MonitoringPeriodOverlaps);
+        Ok(reports_read)
+    }
+
+    /// Actual weight of annual report creation, that read given number of annual reports of the project
+    fn create_annual_report_weight(reports_read: u64) -> Weight {
+        10_000 + T::DbWeight::get().reads_writes(4 + reports_read, 2)
+    }
+
+    /// Role, whose signature of the pending crediting period renewal is expected, 
+    /// the owner submits renewal, signing its first stage
+    fn get_renewal_pending_role(
+        crediting_period: &CreditingPeriod<Moment<T>>, 
+        signatures: &[RequiredSigner<T::AccountId>],
+    ) -> Result<RoleMask, DispatchError> {
+        ensure!(crediting_period.is_renewal_pending(), Error::<T>::CreditingPeriodRenewalNotPending);
+        crediting_period.renewal_signing_order.get(signatures.len())
+            .copied()
+            .ok_or_else(|| Error::<T>::InvalidState.into())
+    }
+
+    /// Index of the annual report in the project, the last created one if index is not given.
+    /// Deleted reports leave their indexes empty, so the report at the index may not exist
+    fn get_annual_report_index(project: &ProjectStruct<T::AccountId>, report_index: Option<u64>) -> Result<u64, DispatchError> {
+        let count = project.annual_reports_count;
+        ensure!(count > 0, Error::<T>::NoAnnualReports);
+        match report_index {
+            None => Ok(count - 1),
+            Some(index) => {
+                ensure!(index < count, Error::<T>::AnnualReportNotExist);
+                Ok(index)
+            }
+        }
+    }
+
+    /// Changes state of a project by signing, the order of signatures is defined by project standard
+    fn change_project_state(project: &mut ProjectStruct<T::AccountId>, caller: T::AccountId, event: &mut Option<Event<T>>) -> DispatchResult {
+        let (signing_order, stage) = Self::get_project_signing_stage(project)?;
+        let role = signing_order[stage];
+
+        Self::ensure_account_role(&caller, role)?;
+        if role == accounts::accounts::CC_PROJECT_OWNER_ROLE_MASK {
+            ensure!(project.owner == caller, Error::<T>::AccountNotOwner);
+            ensure!(project.is_ready_for_signing(), Error::<T>::IncorrectFileId);
+        }
+        ensure!(Self::is_correct_project_signer(project, caller.clone(), role), Error::<T>::IncorrectProjectSigner);
+
+        match signing_order.get(stage + 1) {
+            // Project Owner submits PDD (changing status to Registration) => other roles sign it
+            Some(next_role) => {
+                project.state = project::project_state_by_role(*next_role).ok_or(Error::<T>::InvalidState)?;
+                project.status = project::ProjectStatus::REGISTRATION;
+                // Changes of the standard definition dont affect submitted project and its annual reports
+                if stage == 0 {
+                    project.set_standard_definition(Self::get_project_standard_definition(project)?);
                 }
-                Ok(())
             },
+            // Last signer registers PDD (changing status to Issuance)
+            None => {
+                project.state = project::REGISTERED;
+                project.status = project::ProjectStatus::ISSUANCE;
+            }
+        }
+        *event = Self::project_signed_event(role, caller, project.id);
+        Ok(())
+    }
+
+    /// Moves project from the index of its old state to the index of the new one
+    fn update_project_state_index(project_id: ProjectId, old_state: ProjectStateMask, new_state: ProjectStateMask) {
+        if old_state != new_state {
+            ProjectsByState::remove(old_state, project_id);
+            ProjectsByState::insert(new_state, project_id, ());
+        }
+    }
+
+    /// Counts new signer assignment of the account in the project or its annual report
+    fn add_project_signer_index(signer: &T::AccountId, project_id: ProjectId) {
+        ProjectsBySigner::<T>::mutate(signer, project_id, |assignments| *assignments += 1);
+    }
+
+    /// Removes project from the signer index, when the last signer assignment of the account 
+    /// in the project and its annual reports is removed
+    fn remove_project_signer_index(signer: &T::AccountId, project_id: ProjectId) {
+        match ProjectsBySigner::<T>::get(signer, project_id) {
+            0 | 1 => ProjectsBySigner::<T>::remove(signer, project_id),
+            assignments => ProjectsBySigner::<T>::insert(signer, project_id, assignments - 1),
+        }
+    }
+
+    /// Project signing order and position of the pending signature in it
+    fn get_project_signing_stage(project: &ProjectStruct<T::AccountId>) -> Result<(Vec<RoleMask>, usize), DispatchError> {
+        let signing_order = Self::get_project_standard_definition(project)?.project_signing_order;
+        let stage = signing_order.iter()
+            .position(|role| project::project_state_by_role(*role) == Some(project.state))
+            .ok_or(Error::<T>::InvalidState)?;
+        Ok((signing_order, stage))
+    }
+
+    /// Changes state of an annual report by signing, the order of signatures is defined by project standard
+    fn change_annual_report_state(project: &ProjectStruct<T::AccountId>, report: &mut annual_report::AnnualReportStruct<T::AccountId, T, T::Balance>, caller: T::AccountId, event: &mut Option<Event<T>>) -> DispatchResult {
+        let (signing_order, stage) = Self::get_annual_report_signing_stage(project, report)?;
+        let role = signing_order[stage];
+
+        Self::ensure_account_role(&caller, role)?;
+        let is_owner_stage = role == accounts::accounts::CC_PROJECT_OWNER_ROLE_MASK;
+        if is_owner_stage {
+            ensure!(project.owner == caller, Error::<T>::AccountNotOwner);
+        }
+        ensure!(Self::is_correct_annual_report_signer(report, caller.clone(), role), Error::<T>::IncorrectProjectSigner);
+        if is_owner_stage {
+            ensure!(report.carbon_credits_meta.is_metadata_valid(), Error::<T>::BadMetadataParameters);
+        }
+
+        match signing_order.get(stage + 1) {
+            Some(next_role) => {
+                report.state = annual_report::report_state_by_role(*next_role).ok_or(Error::<T>::InvalidState)?;
+            },
+            None => {
+                report.state = annual_report::REPORT_ISSUED;
+            }
+        }
+        *event = Self::annual_report_signed_event(role, caller, project.id);
+        Ok(())
+    }
+
+    /// Annual report signing order and position of the pending signature in it
+    fn get_annual_report_signing_stage(
+        project: &ProjectStruct<T::AccountId>, 
+        report: &annual_report::AnnualReportStruct<T::AccountId, T, T::Balance>,
+    ) -> Result<(Vec<RoleMask>, usize), DispatchError> {
+        let signing_order = Self::get_project_standard_definition(project)?.report_signing_order;
+        let stage = signing_order.iter()
+            .position(|role| annual_report::report_state_by_role(*role) == Some(report.state))
+            .ok_or(Error::<T>::InvalidState)?;
+        Ok((signing_order, stage))
+    }
+
+    /// Checks that account has given role in accounts pallet, returning role specific error
+    fn ensure_account_role(account: &T::AccountId, role: RoleMask) -> DispatchResult {
+        let error = match role {
+            accounts::accounts::CC_PROJECT_OWNER_ROLE_MASK => Error::<T>::AccountNotOwner,
+            accounts::accounts::CC_AUDITOR_ROLE_MASK => Error::<T>::AccountNotAuditor,
+            accounts::accounts::CC_STANDARD_ROLE_MASK => Error::<T>::AccountNotStandard,
+            accounts::accounts::CC_REGISTRY_ROLE_MASK => Error::<T>::AccountNotRegistry,
+            accounts::accounts::CC_INVESTOR_ROLE_MASK => Error::<T>::AccountNotInvestor,
+            _ => Error::<T>::AccountIncorrectRole,
+        };
+        ensure!(accounts::Module::<T>::account_is_selected_role(account, role), error);
+        Ok(())
+    }
+
+    fn project_signed_event(role: RoleMask, caller: T::AccountId, project_id: ProjectId) -> Option<Event<T>> {
+        match role {
+            accounts::accounts::CC_PROJECT_OWNER_ROLE_MASK => Some(RawEvent::ProjectSubmited(caller, project_id)),
+            accounts::accounts::CC_AUDITOR_ROLE_MASK => Some(RawEvent::ProjectSignedByAduitor(caller, project_id)),
+            accounts::accounts::CC_STANDARD_ROLE_MASK => Some(RawEvent::ProjectSignedByStandard(caller, project_id)),
+            accounts::accounts::CC_INVESTOR_ROLE_MASK => Some(RawEvent::ProjectSignedByInvestor(caller, project_id)),
+            accounts::accounts::CC_REGISTRY_ROLE_MASK => Some(RawEvent::ProjectSignedByRegistry(caller, project_id)),
+            _ => None,
+        }
+    }
+
+    fn annual_report_signed_event(role: RoleMask, caller: T::AccountId, project_id: ProjectId) -> Option<Event<T>> {
+        match role {
+            accounts::accounts::CC_PROJECT_OWNER_ROLE_MASK => Some(RawEvent::AnnualReportSubmited(caller, project_id)),
+            accounts::accounts::CC_AUDITOR_ROLE_MASK => Some(RawEvent::AnnualReportSignedByAuditor(caller, project_id)),
+            accounts::accounts::CC_STANDARD_ROLE_MASK => Some(RawEvent::AnnualReportSignedByStandard(caller, project_id)),
+            accounts::accounts::CC_INVESTOR_ROLE_MASK => Some(RawEvent::AnnualReportSignedByInvestor(caller, project_id)),
+            accounts::accounts::CC_REGISTRY_ROLE_MASK => Some(RawEvent::AnnualReportSignedByRegistry(caller, project_id)),
+            _ => None,
+        }
+    }
+
+    /// Account of the non-permanence buffer pool, controlled by the pallet
+    pub fn buffer_pool_account_id() -> T::AccountId {
+        T::ModuleId::get().into_account()
+    }
+
+    /// Definition of the project standard with investor stage, if project requires it.
+    /// Submitted project follows the definition, fixed on submission, otherwise the current one is used
+    fn get_project_standard_definition(project: &ProjectStruct<T::AccountId>) -> Result<StandardStruct, DispatchError> {
+        if let Some(standard) = project.get_standard_definition() {
+            return Ok(standard.clone());
+        }
+        let standard = Self::get_standard_definition(project.get_standard()).ok_or(Error::<T>::StandardNotExist)?;
+        if project.is_investor_sign_required() {
+            return Ok(standard.with_investor_stage());
         }
+        Ok(standard)
     }
 
-    fn is_correct_project_signer(project: &ProjectStruct<T::AccountId, T, T::Balance>, account: T::AccountId, role: RoleMask) -> bool {
+    /// Definition of the standard, definitions in storage override built in ones
+    pub fn get_standard_definition(standard: &Standard) -> Option<StandardStruct> {
+        StandardDefinitions::get(standard).or_else(|| standard.default_definition())
+    }
+
+    fn is_correct_project_signer(project: &ProjectStruct<T::AccountId>, account: T::AccountId, role: RoleMask) -> bool {
         pallet_evercity_accounts::Module::<T>::account_is_selected_role(&account, role) &&
         project.is_required_signer((account, role))
     }
@@ -1000,18 +2472,172 @@ impl<T: Config> Module<T> {
         annual_report.is_required_signer((account, role))
     }
 
-    #[cfg(test)]
-    pub fn get_proj_by_id(id: ProjectId) -> Option<ProjectStruct<T::AccountId, T, T::Balance>> {
+    pub fn get_proj_by_id(id: ProjectId) -> Option<ProjectStruct<T::AccountId>> {
         ProjectById::<T>::get(id)
     }
 
     #[cfg(test)]
-    pub fn get_passport_by_assetid(asset_id: AssetId<T>) -> Option<CarbonCreditsPassport<AssetId<T>>> {
-        CarbonCreditPassportRegistry::<T>::get(asset_id)
+    pub fn get_project_metadata(project_id: ProjectId) -> Option<ProjectMetadata> {
+        ProjectMetadataById::get(project_id)
+    }
+
+    #[cfg(test)]
+    pub fn get_crediting_period(project_id: ProjectId) -> Option<CreditingPeriod<Moment<T>>> {
+        CreditingPeriods::<T>::get(project_id)
     }
 
     #[cfg(test)]
+    pub fn get_annual_report(project_id: ProjectId, report_index: u64) -> Option<annual_report::AnnualReportStruct<T::AccountId, T, T::Balance>> {
+        AnnualReports::<T>::get(project_id, report_index)
+    }
+
+    #[cfg(test)]
+    pub fn get_last_annual_report(project_id: ProjectId) -> Option<annual_report::AnnualReportStruct<T::AccountId, T, T::Balance>> {
+        let count = ProjectById::<T>::get(project_id)?.annual_reports_count;
+        AnnualReports::<T>::get(project_id, count.checked_sub(1)?)
+    }
+
+    #[cfg(test)]
+    pub fn get_annual_reports(project_id: ProjectId) -> Vec<annual_report::AnnualReportStruct<T::AccountId, T, T::Balance>> {
+        let count = ProjectById::<T>::get(project_id).map_or(0, |project| project.annual_reports_count);
+        (0..count).filter_map(|index| AnnualReports::<T>::get(project_id, index)).collect()
+    }
+
+    /// Rejections of the project documentation in order of their submission
+    pub fn get_project_rejections(project_id: ProjectId) -> Vec<RejectionStruct<T::AccountId>> {
+        let count = ProjectById::<T>::get(project_id).map_or(0, |project| project.rejections_count);
+        (0..count).filter_map(|index| ProjectRejections::<T>::get(project_id, index)).collect()
+    }
+
+    /// Rejections of the annual report in order of their submission
+    pub fn get_annual_report_rejections(project_id: ProjectId, report_index: u64) -> Vec<RejectionStruct<T::AccountId>> {
+        let count = AnnualReports::<T>::get(project_id, report_index).map_or(0, |report| report.rejections_count);
+        (0..count).filter_map(|index| AnnualReportRejections::<T>::get((project_id, report_index), index)).collect()
+    }
+
+    pub fn get_passport_by_assetid(asset_id: AssetId<T>) -> Option<CarbonCreditsPassport<AssetId<T>, Moment<T>, <T as pallet_assets::Config>::Balance>> {
+        CarbonCreditPassportRegistry::<T>::get(asset_id)
+    }
+
     pub fn get_certificates_by_account(account: T::AccountId) -> Vec<CarbonCreditsBurnCertificate<AssetId<T>, T::Balance>> {
         BurnCertificates::<T>::get(account)
     }
+
+    #[cfg(test)]
+    pub fn get_retirement_certificate(certificate_id: CertificateId) -> Option<RetirementCertificateOf<T>> {
+        RetirementCertificates::<T>::get(certificate_id)
+    }
+
+    pub fn get_retirement_certificates_by_account(account: T::AccountId) -> Vec<RetirementCertificateOf<T>> {
+        RetirementCertificatesByAccount::<T>::get(account).into_iter()
+            .filter_map(RetirementCertificates::<T>::get)
+            .collect()
+    }
+
+    /// Serial ranges of carbon credits of the asset, held by the account, with project id, annual report index and vintage
+    pub fn get_carbon_credits_serial_ranges(asset_id: AssetId<T>, account: T::AccountId) -> Option<CarbonCreditsSerialRanges<T::Balance>> {
+        let passport = CarbonCreditPassportRegistry::<T>::get(asset_id)?;
+        Some(CarbonCreditsSerialRanges {
+            project_id: passport.get_project_id(),
+            // annual report index in passport starts from 1
+            annual_report_index: passport.get_annual_report_index().saturating_sub(1),
+            vintage: passport.get_vintage(),
+            ranges: SerialRanges::<T>::get(asset_id, account),
+        })
+    }
+
+    /// Carbon credits asset with the project and the annual report, it was released from
+    pub fn get_carbon_credits_asset_info(asset_id: AssetId<T>) -> Option<CarbonCreditsAssetInfoOf<T>> {
+        let passport = CarbonCreditPassportRegistry::<T>::get(asset_id)?;
+        let project = ProjectById::<T>::get(passport.get_project_id())?;
+        // annual report index in passport starts from 1
+        let annual_report_index = passport.get_annual_report_index().saturating_sub(1);
+        let annual_report = AnnualReports::<T>::get(passport.get_project_id(), annual_report_index)?;
+        Some(CarbonCreditsAssetInfo {
+            annual_report_index,
+            annual_report_file_id: annual_report.file_id,
+            carbon_credits_count: annual_report.carbon_credits_count(),
+            vintage: passport.get_vintage(),
+            burned_carbon_credits: BurnedCarbonCredits::<T>::get(asset_id),
+            passport,
+            project,
+        })
+    }
+
+    pub fn get_projects_by_owner(owner: T::AccountId) -> Vec<ProjectStruct<T::AccountId>> {
+        let mut project_ids: Vec<_> = ProjectsByOwner::<T>::iter_prefix(owner).map(|(project_id, _)| project_id).collect();
+        project_ids.sort_unstable();
+        project_ids.into_iter()
+            .filter_map(ProjectById::<T>::get)
+            .collect()
+    }
+
+    /// Carbon credits assets of all released annual reports of the project, by report index
+    pub fn get_project_assets(project_id: ProjectId) -> Vec<(u64, AssetId<T>)> {
+        let count = ProjectById::<T>::get(project_id).map_or(0, |project| project.annual_reports_count);
+        (0..count)
+            .filter_map(|index| AnnualReportAssets::<T>::get(project_id, index).map(|asset_id| (index, asset_id)))
+            .collect()
+    }
+
+    pub fn get_project_carbon_credits_totals(project_id: ProjectId) -> CarbonCreditsTotals<T::Balance> {
+        ProjectCarbonCreditsTotals::<T>::get(project_id)
+    }
+
+    pub fn get_carbon_credits_total() -> CarbonCreditsTotals<T::Balance> {
+        CarbonCreditsTotal::<T>::get()
+    }
+
+    pub fn get_standard_carbon_credits_totals(standard: Standard) -> CarbonCreditsTotals<T::Balance> {
+        StandardCarbonCreditsTotals::<T>::get(standard)
+    }
+
+    pub fn get_vintage_carbon_credits_totals(vintage: u16) -> CarbonCreditsTotals<T::Balance> {
+        VintageCarbonCreditsTotals::<T>::get(vintage)
+    }
+
+    pub fn get_projects_by_state(state: ProjectStateMask) -> Vec<ProjectId> {
+        let mut project_ids: Vec<_> = ProjectsByState::iter_prefix(state).map(|(project_id, _)| project_id).collect();
+        project_ids.sort_unstable();
+        project_ids
+    }
+
+    /// Projects, where account is required signer of the project or its annual reports
+    pub fn get_projects_by_signer(signer: T::AccountId) -> Vec<ProjectId> {
+        let mut project_ids: Vec<_> = ProjectsBySigner::<T>::iter_prefix(signer).map(|(project_id, _)| project_id).collect();
+        project_ids.sort_unstable();
+        project_ids
+    }
+
+    /// Projects, waiting for the signature of the account with its required signer role
+    pub fn get_projects_pending_signature(signer: T::AccountId) -> Vec<ProjectId> {
+        Self::get_projects_by_signer(signer.clone()).into_iter()
+            .filter(|project_id| ProjectById::<T>::get(project_id).map_or(false, |project| {
+                Self::get_project_signing_stage(&project)
+                    .map_or(false, |(signing_order, stage)| project.is_required_signer((signer.clone(), signing_order[stage])))
+            }))
+            .collect()
+    }
+
+    /// Annual reports, waiting for the signature of the account with its required signer role, as project id and report index
+    pub fn get_annual_reports_pending_signature(signer: T::AccountId) -> Vec<(ProjectId, u64)> {
+        Self::get_projects_by_signer(signer.clone()).into_iter()
+            .filter_map(ProjectById::<T>::get)
+            .flat_map(|project| {
+                (0..project.annual_reports_count)
+                    .filter(|index| AnnualReports::<T>::get(project.id, index).map_or(false, |report| {
+                        Self::get_annual_report_signing_stage(&project, &report)
+                            .map_or(false, |(signing_order, stage)| report.is_required_signer((signer.clone(), signing_order[stage])))
+                    }))
+                    .map(|index| (project.id, index))
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    /// Reversals of the project in order of their reporting
+    pub fn get_reversals(project_id: ProjectId) -> Vec<ReversalStruct<T::AccountId, T::Balance>> {
+        let count = ProjectById::<T>::get(project_id).map_or(0, |project| project.reversals_count);
+        (0..count).filter_map(|index| ReversalsByProject::<T>::get(project_id, index)).collect()
+    }
 }
\ No newline at end of file
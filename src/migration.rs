@@ -0,0 +1,193 @@
+use frame_support::{
+    codec::{Decode, Encode},
+    sp_runtime::RuntimeDebug,
+    dispatch::Vec,
+    storage::{IterableStorageMap, StorageMap, StorageDoubleMap},
+    traits::Get,
+    weights::Weight,
+    sp_runtime::traits::Zero,
+};
+use crate::{
+    Config, ProjectById, AnnualReports, CarbonCreditPassportRegistry, ProjectsByOwner, ProjectsByState, ProjectsBySigner,
+    AnnualReportAssets, BurnCertificates, BurnedCarbonCredits, ProjectsCountByStandard, Module,
+};
+use crate::standard::Standard;
+use crate::project::{ProjectStruct, ProjectId, ProjectStatus, ProjectStateMask, PROJECT_OWNER_SIGN_PENDING};
+use crate::carbon_credits_passport::CarbonCreditsPassport;
+use crate::annual_report::{AnnualReportStateMask, CarbonCreditsMeta};
+use crate::required_signers::RequiredSigner;
+use pallet_evercity_filesign::file::FileId;
+
+/// Storage layout version of the pallet
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq)]
+pub enum Releases {
+    /// Annual reports are embedded into projects
+    V1,
+    /// Annual reports are stored in separate storage, indexed by project and report index.
+    /// Annual reports and passports have monitoring period and track carbon credits, issued in tranches.
+    /// V2 is not released yet, so its layout and migrate_to_v2 are still changed in place.
+    /// Storage changes after the release of V2 need a new version and migration
+    V2,
+}
+
+impl Default for Releases {
+    fn default() -> Self {
+        Releases::V1
+    }
+}
+
+// Storage version 1 types copy the layout of the released version, 
+// so changes of current types dont break decoding of version 1 storage
+
+/// Standard in storage version 1
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq)]
+#[allow(non_camel_case_types)]
+pub enum StandardV1 {
+    GOLD_STANDARD,
+}
+
+impl From<StandardV1> for Standard {
+    fn from(old: StandardV1) -> Self {
+        match old {
+            StandardV1::GOLD_STANDARD => Standard::GOLD_STANDARD,
+        }
+    }
+}
+
+/// Project status in storage version 1
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq)]
+#[allow(non_camel_case_types)]
+pub enum ProjectStatusV1 {
+    PREPARING,
+    REGISTRATION,
+    ISSUANCE,
+}
+
+impl From<ProjectStatusV1> for ProjectStatus {
+    fn from(old: ProjectStatusV1) -> Self {
+        match old {
+            ProjectStatusV1::PREPARING => ProjectStatus::PREPARING,
+            ProjectStatusV1::REGISTRATION => ProjectStatus::REGISTRATION,
+            ProjectStatusV1::ISSUANCE => ProjectStatus::ISSUANCE,
+        }
+    }
+}
+
+/// Carbon credits metadata in storage version 1
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq)]
+pub struct CarbonCreditsMetaV1 {
+    pub name: Vec<u8>,
+    pub symbol: Vec<u8>,
+    pub decimals: u8,
+}
+
+impl From<CarbonCreditsMetaV1> for CarbonCreditsMeta {
+    fn from(old: CarbonCreditsMetaV1) -> Self {
+        CarbonCreditsMeta::new(old.name, old.symbol, old.decimals)
+    }
+}
+
+/// Annual report in storage version 1
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq)]
+pub struct AnnualReportStructV1<AccountId, Moment, Balance> where Balance: Clone, AccountId: PartialEq {
+    pub file_id: FileId,
+    pub state: AnnualReportStateMask,
+    pub carbon_credits_meta: CarbonCreditsMetaV1,
+    #[codec(compact)]
+    pub create_time: Moment,
+    pub carbon_credits_count: Balance,
+    pub carbon_credits_released: bool,
+    pub required_signers: Vec<RequiredSigner<AccountId>>,
+}
+
+/// Project in storage version 1
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq)]
+pub struct ProjectStructV1<AccountId, Moment, Balance> where Balance: Clone, AccountId: PartialEq {
+    pub owner: AccountId,
+    pub id: ProjectId,
+    pub status: ProjectStatusV1,
+    pub state: ProjectStateMask,
+    pub file_id: Option<FileId>,
+    pub annual_reports: Vec<AnnualReportStructV1<AccountId, Moment, Balance>>,
+    pub required_signers: Vec<RequiredSigner<AccountId>>,
+    pub standard: StandardV1,
+}
+
+/// Carbon credits passport in storage version 1
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq)]
+pub struct CarbonCreditsPassportV1<AssetId> {
+    pub asset_id: AssetId,
+    pub project_id: ProjectId,
+    pub annual_report_index: u64,
+}
+
+/// Moves annual reports out of projects to AnnualReports storage,
+/// adds empty monitoring period to existing annual reports and passports,
+/// marks carbon credits of released reports as fully issued,
+/// fixes standard definitions of submitted projects,
+/// builds indexes of projects by owner, state and signer, assets of annual reports, counts projects by standard
+/// and carbon credits totals of projects.
+/// Migrated carbon credits have no vintage, they are totalled under UNKNOWN_VINTAGE
+pub fn migrate_to_v2<T: Config>() -> Weight {
+    let mut projects_count: u64 = 0;
+    let mut reports_count: u64 = 0;
+    let mut signers_count: u64 = 0;
+    ProjectById::<T>::translate::<ProjectStructV1<T::AccountId, <T as pallet_timestamp::Config>::Moment, T::Balance>, _>(
+        |project_id, old_project| {
+            let (mut project, annual_reports) = ProjectStruct::from_v1(old_project);
+            // Submitted projects keep the definition of their standard at the time of migration
+            if project.state != PROJECT_OWNER_SIGN_PENDING {
+                if let Some(standard) = Module::<T>::get_standard_definition(project.get_standard()) {
+                    project.set_standard_definition(standard);
+                }
+            }
+            ProjectsCountByStandard::mutate(project.get_standard(), |count| *count += 1);
+            ProjectsByOwner::<T>::insert(&project.owner, project_id, ());
+            ProjectsByState::insert(project.state, project_id, ());
+            project.get_required_signers().iter()
+                .chain(annual_reports.iter().flat_map(|report| report.get_required_signers().iter()))
+                .for_each(|(signer, _)| {
+                    signers_count += 1;
+                    ProjectsBySigner::<T>::mutate(signer, project_id, |assignments| *assignments += 1);
+                });
+            annual_reports.into_iter()
+                .enumerate()
+                .for_each(|(index, report)| AnnualReports::<T>::insert(project_id, index as u64, report));
+            projects_count += 1;
+            reports_count += project.annual_reports_count;
+            Some(project)
+        }
+    );
+    let mut passports_count: u64 = 0;
+    CarbonCreditPassportRegistry::<T>::translate::<CarbonCreditsPassportV1<<T as pallet_assets::Config>::AssetId>, _>(
+        |_, old_passport| {
+            passports_count += 1;
+            // Annual report index in passport starts from 1
+            let carbon_credits_count = AnnualReports::<T>::get(old_passport.project_id, old_passport.annual_report_index.saturating_sub(1))
+                .map(|report| report.carbon_credits_count())
+                .unwrap_or_default();
+            AnnualReportAssets::<T>::insert(old_passport.project_id, old_passport.annual_report_index.saturating_sub(1), old_passport.asset_id);
+            let passport = CarbonCreditsPassport::from_v1(old_passport, carbon_credits_count);
+            Module::<T>::update_carbon_credits_totals(passport.get_project_id(), passport.get_vintage(),
+                |totals| totals.issue(carbon_credits_count, T::Balance::zero())
+            );
+            Some(passport)
+        }
+    );
+    let mut burn_certificates_count: u64 = 0;
+    BurnCertificates::<T>::iter()
+        .flat_map(|(_, certificates)| certificates)
+        .for_each(|certificate| {
+            burn_certificates_count += 1;
+            if let Some(passport) = CarbonCreditPassportRegistry::<T>::get(certificate.asset_id) {
+                BurnedCarbonCredits::<T>::mutate(certificate.asset_id, |burned| *burned += certificate.burn_amount);
+                Module::<T>::update_carbon_credits_totals(passport.get_project_id(), passport.get_vintage(),
+                    |totals| totals.retire(certificate.burn_amount)
+                );
+            }
+        });
+    T::DbWeight::get().reads_writes(
+        projects_count * 5 + signers_count + passports_count * 7 + burn_certificates_count * 8, 
+        projects_count * 4 + reports_count + signers_count + passports_count * 6 + burn_certificates_count * 5
+    )
+}
@@ -3,14 +3,25 @@ use frame_support::{
     sp_runtime::RuntimeDebug,
     dispatch::Vec,
 };
-use crate::standard::Standard;
-use crate::annual_report::*;
+#[cfg(feature = "std")]
+use serde::{Serialize, Deserialize};
+use crate::standard::{Standard, StandardStruct};
+use crate::annual_report::AnnualReportStructT;
+use crate::migration::ProjectStructV1;
 use pallet_evercity_filesign::file::FileId;
 use frame_support::sp_std::{
     cmp::{
         PartialEq}, 
 };
 use crate::required_signers::RequiredSigner;
+use pallet_evercity_accounts::accounts::{
+    RoleMask,
+    CC_PROJECT_OWNER_ROLE_MASK,
+    CC_AUDITOR_ROLE_MASK,
+    CC_STANDARD_ROLE_MASK,
+    CC_REGISTRY_ROLE_MASK,
+    CC_INVESTOR_ROLE_MASK,
+};
 
 pub type ProjectStateMask = u16;
 pub const PROJECT_OWNER_SIGN_PENDING: ProjectStateMask = 1;
@@ -20,22 +31,46 @@ pub const INVESTOR_SIGN_PENDING: ProjectStateMask = 8;
 pub const REGISTRY_SIGN_PENDING: ProjectStateMask = 16;
 pub const REGISTERED: ProjectStateMask = 32;
 
+/// State of the project, when signature of the role is pending
+pub fn project_state_by_role(role: RoleMask) -> Option<ProjectStateMask> {
+    match role {
+        CC_PROJECT_OWNER_ROLE_MASK => Some(PROJECT_OWNER_SIGN_PENDING),
+        CC_AUDITOR_ROLE_MASK => Some(AUDITOR_SIGN_PENDING),
+        CC_STANDARD_ROLE_MASK => Some(STANDARD_SIGN_PENDING),
+        CC_INVESTOR_ROLE_MASK => Some(INVESTOR_SIGN_PENDING),
+        CC_REGISTRY_ROLE_MASK => Some(REGISTRY_SIGN_PENDING),
+        _ => None,
+    }
+}
+
 pub type ProjectId = u32;
 
 /// Main struct for projects
 #[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq)]
-pub struct ProjectStruct<AccountId, Moment, Balance> where AccountId: PartialEq + Clone, Moment: pallet_timestamp::Config, Balance: Clone {
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct ProjectStruct<AccountId> where AccountId: PartialEq + Clone {
     pub owner: AccountId,
     pub id: ProjectId,
     pub status: ProjectStatus,
     pub state: ProjectStateMask,
     pub file_id: Option<FileId>,
-    pub annual_reports: Vec<AnnualReportStruct<AccountId, Moment, Balance>>,
+    /// Annual reports are stored separately, indexed from 0 to annual_reports_count, deleted reports leave their indexes empty
+    pub annual_reports_count: u64,
+    /// Annual reports, that are not deleted, limited by MAX_ANNUAL_REPORTS
+    pub live_annual_reports_count: u64,
     required_signers: Vec<RequiredSigner<AccountId>>,
     standard: Standard,
+    investor_sign_required: bool,
+    /// Rejections are stored separately, indexed from 0 to rejections_count
+    pub rejections_count: u32,
+    /// Reversals are stored separately, indexed from 0 to reversals_count
+    pub reversals_count: u32,
+    /// Definition of the standard with investor stage, fixed when the owner submits the project.
+    /// Registered project keeps it for signing of annual reports, crediting period renewals and releases
+    standard_definition: Option<StandardStruct>,
 }
 
-impl<AccountId, Moment, Balance> ProjectStruct<AccountId, Moment, Balance> where AccountId: PartialEq + Clone, Moment: pallet_timestamp::Config, Balance: Clone {
+impl<AccountId> ProjectStruct<AccountId> where AccountId: PartialEq + Clone {
     /// constructor for project
     pub fn new(owner: AccountId, id: u32, standard: Standard, file_id: Option<FileId>) -> Self {
         ProjectStruct{
@@ -45,8 +80,13 @@ impl<AccountId, Moment, Balance> ProjectStruct<AccountId, Moment, Balance> where
             standard,
             status: ProjectStatus::default(), 
             state: PROJECT_OWNER_SIGN_PENDING,
-            annual_reports: Vec::new(),
+            annual_reports_count: 0,
+            live_annual_reports_count: 0,
             required_signers: Vec::new(),
+            investor_sign_required: false,
+            rejections_count: 0,
+            reversals_count: 0,
+            standard_definition: None,
         }
     }
 
@@ -62,6 +102,17 @@ impl<AccountId, Moment, Balance> ProjectStruct<AccountId, Moment, Balance> where
         }
     }
 
+    pub fn is_investor_sign_required(&self) -> bool {
+        self.investor_sign_required
+    }
+
+    // Investor stage can be changed only before signing starts
+    pub fn set_investor_sign_required(&mut self, investor_sign_required: bool) {
+        if self.state == PROJECT_OWNER_SIGN_PENDING {
+            self.investor_sign_required = investor_sign_required;
+        }
+    }
+
     pub fn assign_required_signer(&mut self, signer: RequiredSigner<AccountId>) {
         if !self.required_signers.iter().any(|(acc, role)| *acc == signer.0 && *role == signer.1) {
             self.required_signers.push(signer);
@@ -83,12 +134,65 @@ impl<AccountId, Moment, Balance> ProjectStruct<AccountId, Moment, Balance> where
         self.required_signers.iter().any(|(acc, role)| *acc == signer.0 && *role == signer.1)
     }
 
+    /// Account is required signer with any role
+    pub fn has_required_signer(&self, account: &AccountId) -> bool {
+        self.required_signers.iter().any(|(acc, _)| acc == account)
+    }
+
+    pub fn get_required_signers(&self) -> &[RequiredSigner<AccountId>] {
+        &self.required_signers
+    }
+
     pub fn is_ready_for_signing(&self) -> bool {
         self.file_id.is_some()
     }
+
+    /// Returns project to the owner for corrections, returning index of the new rejection
+    pub fn reject(&mut self) -> u32 {
+        self.state = PROJECT_OWNER_SIGN_PENDING;
+        self.status = ProjectStatus::PREPARING;
+        self.standard_definition = None;
+        self.rejections_count += 1;
+        self.rejections_count - 1
+    }
+
+    /// None, if the project is not submitted for signing
+    pub fn get_standard_definition(&self) -> Option<&StandardStruct> {
+        self.standard_definition.as_ref()
+    }
+
+    pub fn set_standard_definition(&mut self, standard_definition: StandardStruct) {
+        self.standard_definition = Some(standard_definition);
+    }
+
+    /// Converts project from storage version 1, returning annual reports, that were embedded into it
+    pub(crate) fn from_v1<Moment, Balance>(old: ProjectStructV1<AccountId, Moment, Balance>) -> (Self, Vec<AnnualReportStructT<AccountId, Moment, Balance>>) 
+        where Balance: Clone + Default, Moment: Default
+    {
+        let annual_reports: Vec<_> = old.annual_reports.into_iter()
+            .map(AnnualReportStructT::from)
+            .collect();
+        let project = ProjectStruct{
+            owner: old.owner,
+            id: old.id,
+            status: old.status.into(),
+            state: old.state,
+            file_id: old.file_id,
+            annual_reports_count: annual_reports.len() as u64,
+            live_annual_reports_count: annual_reports.len() as u64,
+            required_signers: old.required_signers,
+            standard: old.standard.into(),
+            investor_sign_required: false,
+            rejections_count: 0,
+            reversals_count: 0,
+            standard_definition: None,
+        };
+        (project, annual_reports)
+    }
 }
 
 #[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[allow(non_camel_case_types)]
 pub enum ProjectStatus {
     PREPARING,
@@ -0,0 +1,58 @@
+use frame_support::{
+    codec::{Decode, Encode},
+    sp_runtime::RuntimeDebug,
+    dispatch::Vec,
+};
+
+pub const MAX_METHODOLOGY_LEN: usize = 64;
+pub const MAX_SECTOR_LEN: usize = 64;
+/// Coordinates are stored in millionths of a degree
+pub const MAX_LATITUDE: i32 = 90_000_000;
+pub const MAX_LONGITUDE: i32 = 180_000_000;
+
+/// Queryable attributes of the project, that are described in detail in PDD
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq)]
+pub struct ProjectMetadata {
+    /// ISO 3166-1 alpha-2 country code, e.g. b"BR"
+    pub country_code: [u8; 2],
+    pub latitude: i32,
+    pub longitude: i32,
+    /// Methodology id of the standard, e.g. b"AMS-I.D."
+    pub methodology: Vec<u8>,
+    /// Sector or project type, e.g. b"Renewable Energy"
+    pub sector: Vec<u8>,
+    /// Estimated emission reductions in tCO2e per year
+    pub estimated_annual_reductions: u64,
+    pub crediting_period_years: u8,
+}
+
+impl ProjectMetadata {
+    pub fn new(
+        country_code: [u8; 2],
+        latitude: i32,
+        longitude: i32,
+        methodology: Vec<u8>,
+        sector: Vec<u8>,
+        estimated_annual_reductions: u64,
+        crediting_period_years: u8,
+    ) -> Self {
+        ProjectMetadata {
+            country_code,
+            latitude,
+            longitude,
+            methodology,
+            sector,
+            estimated_annual_reductions,
+            crediting_period_years,
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.country_code.iter().all(|c| c.is_ascii_uppercase()) &&
+        (-MAX_LATITUDE..=MAX_LATITUDE).contains(&self.latitude) &&
+        (-MAX_LONGITUDE..=MAX_LONGITUDE).contains(&self.longitude) &&
+        !self.methodology.is_empty() && self.methodology.len() <= MAX_METHODOLOGY_LEN &&
+        !self.sector.is_empty() && self.sector.len() <= MAX_SECTOR_LEN &&
+        self.crediting_period_years != 0
+    }
+}
@@ -0,0 +1,24 @@
+use frame_support::{
+    codec::{Decode, Encode},
+    sp_runtime::RuntimeDebug,
+};
+use pallet_evercity_accounts::accounts::RoleMask;
+use pallet_evercity_filesign::file::H256;
+
+/// Record about rejection of a document by one of its signers
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq)]
+pub struct RejectionStruct<AccountId> {
+    pub rejected_by: AccountId,
+    pub role: RoleMask,
+    pub reason_hash: H256,
+}
+
+impl<AccountId> RejectionStruct<AccountId> {
+    pub fn new(rejected_by: AccountId, role: RoleMask, reason_hash: H256) -> Self {
+        RejectionStruct {
+            rejected_by,
+            role,
+            reason_hash,
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,27 @@
+use frame_support::{
+    codec::{Decode, Encode},
+    sp_runtime::RuntimeDebug,
+};
+use pallet_evercity_filesign::file::FileId;
+
+/// Record about verified loss of emission reductions of the project after issuance, e.g. fire or deforestation
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq)]
+pub struct ReversalStruct<AccountId, Balance> {
+    pub reported_by: AccountId,
+    /// File in filesign pallet with evidence of the loss
+    pub evidence_file_id: FileId,
+    pub amount: Balance,
+    /// Part of the reversal, that was not covered neither by buffer pool nor by unretired carbon credits
+    pub uncovered_amount: Balance,
+}
+
+impl<AccountId, Balance> ReversalStruct<AccountId, Balance> {
+    pub fn new(reported_by: AccountId, evidence_file_id: FileId, amount: Balance, uncovered_amount: Balance) -> Self {
+        ReversalStruct {
+            reported_by,
+            evidence_file_id,
+            amount,
+            uncovered_amount,
+        }
+    }
+}
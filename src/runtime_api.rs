@@ -0,0 +1,61 @@
+use frame_support::{
+    codec::{Codec, Decode, Encode},
+    sp_runtime::RuntimeDebug,
+    dispatch::Vec,
+};
+#[cfg(feature = "std")]
+use serde::{Serialize, Deserialize};
+use crate::project::{ProjectStruct, ProjectId, ProjectStateMask};
+use crate::carbon_credits_passport::CarbonCreditsPassport;
+use crate::burn_certificate::{CarbonCreditsBurnCertificate, RetirementCertificate};
+use crate::carbon_credits_totals::CarbonCreditsTotals;
+use crate::standard::Standard;
+use crate::serial_range::CarbonCreditsSerialRanges;
+use pallet_evercity_filesign::file::FileId;
+
+/// Carbon credits asset with the project and the annual report, it was released from
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct CarbonCreditsAssetInfo<AccountId, AssetId, Moment, Balance> where AccountId: PartialEq + Clone {
+    pub passport: CarbonCreditsPassport<AssetId, Moment, Balance>,
+    pub project: ProjectStruct<AccountId>,
+    /// Annual report index in the project, starting from 0
+    pub annual_report_index: u64,
+    pub annual_report_file_id: FileId,
+    /// Carbon credits, verified in the annual report
+    pub carbon_credits_count: Balance,
+    pub vintage: u16,
+    /// Carbon credits of the asset, burned by all holders
+    pub burned_carbon_credits: Balance,
+}
+
+sp_api::decl_runtime_apis! {
+    /// Read only queries of carbon credits, projects and certificates
+    pub trait CarbonCreditsApi<AccountId, AssetId, Balance, Moment, BlockNumber> where
+        AccountId: Codec + PartialEq + Clone,
+        AssetId: Codec,
+        Balance: Codec,
+        Moment: Codec,
+        BlockNumber: Codec,
+    {
+        fn carbon_credits_asset_info(asset_id: AssetId) -> Option<CarbonCreditsAssetInfo<AccountId, AssetId, Moment, Balance>>;
+        fn projects_by_owner(owner: AccountId) -> Vec<ProjectStruct<AccountId>>;
+        /// Carbon credits assets of the project by annual report index
+        fn project_assets(project_id: ProjectId) -> Vec<(u64, AssetId)>;
+        fn project_carbon_credits_totals(project_id: ProjectId) -> CarbonCreditsTotals<Balance>;
+        /// Totals of the whole carbon credits ledger
+        fn carbon_credits_total() -> CarbonCreditsTotals<Balance>;
+        fn standard_carbon_credits_totals(standard: Standard) -> CarbonCreditsTotals<Balance>;
+        /// Carbon credits of the vintage, vintage 0 totals carbon credits migrated from storage version 1
+        fn vintage_carbon_credits_totals(vintage: u16) -> CarbonCreditsTotals<Balance>;
+        fn projects_by_state(state: ProjectStateMask) -> Vec<ProjectId>;
+        /// Work queue of the signer: projects, waiting for its signature
+        fn projects_pending_signature(signer: AccountId) -> Vec<ProjectId>;
+        /// Work queue of the signer: annual reports as project id and report index, waiting for its signature
+        fn annual_reports_pending_signature(signer: AccountId) -> Vec<(ProjectId, u64)>;
+        fn certificates_by_account(account: AccountId) -> Vec<CarbonCreditsBurnCertificate<AssetId, Balance>>;
+        fn retirement_certificates_by_account(account: AccountId) -> Vec<RetirementCertificate<AccountId, AssetId, Balance, Moment, BlockNumber>>;
+        /// Serial numbers of carbon credits of the asset, held by the account
+        fn carbon_credits_serial_ranges(asset_id: AssetId, account: AccountId) -> Option<CarbonCreditsSerialRanges<Balance>>;
+    }
+}
@@ -0,0 +1,88 @@
+use frame_support::{
+    codec::{Decode, Encode},
+    sp_runtime::{RuntimeDebug, traits::AtLeast32BitUnsigned},
+    dispatch::Vec,
+};
+#[cfg(feature = "std")]
+use serde::{Serialize, Deserialize};
+use crate::project::ProjectId;
+
+/// Inclusive range of carbon credits serial numbers inside an asset.
+/// Serial numbers of each annual report start from 1, so together with project id,
+/// annual report index and vintage from the passport they identify every tonne,
+/// e.g. EVC-{project_id}-{annual_report_index}-{vintage}-{start}-{end}
+#[derive(Encode, Decode, Clone, Copy, Default, RuntimeDebug, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct SerialRange<Balance> {
+    pub start: Balance,
+    pub end: Balance,
+}
+
+impl<Balance: AtLeast32BitUnsigned + Copy> SerialRange<Balance> {
+    pub fn new(start: Balance, end: Balance) -> Self {
+        SerialRange {
+            start,
+            end,
+        }
+    }
+
+    /// Range of the given amount of serial numbers, following the given number
+    pub fn following(last: Balance, amount: Balance) -> Self {
+        SerialRange::new(last + Balance::one(), last + amount)
+    }
+
+    pub fn count(&self) -> Balance {
+        self.end - self.start + Balance::one()
+    }
+}
+
+/// Serial ranges of the account in the asset together with project id,
+/// annual report index and vintage, that complete the serial numbers
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct CarbonCreditsSerialRanges<Balance> {
+    pub project_id: ProjectId,
+    /// Annual report index in the project, starting from 0
+    pub annual_report_index: u64,
+    pub vintage: u16,
+    pub ranges: Vec<SerialRange<Balance>>,
+}
+
+/// Removes the given amount of serial numbers from the start of the ranges, splitting the range if needed.
+/// Returns less, if ranges dont have enough serial numbers, e.g. for assets released before serial numbers
+pub fn take_serial_ranges<Balance: AtLeast32BitUnsigned + Copy>(
+    ranges: &mut Vec<SerialRange<Balance>>,
+    amount: Balance,
+) -> Vec<SerialRange<Balance>> {
+    let mut taken = Vec::new();
+    let mut remaining = amount;
+    while !remaining.is_zero() && !ranges.is_empty() {
+        let range = ranges[0];
+        if range.count() <= remaining {
+            remaining -= range.count();
+            taken.push(ranges.remove(0));
+        } else {
+            taken.push(SerialRange::following(range.start - Balance::one(), remaining));
+            ranges[0].start = range.start + remaining;
+            remaining = Balance::zero();
+        }
+    }
+    taken
+}
+
+/// Adds serial ranges keeping them sorted and merging adjacent ones
+pub fn add_serial_ranges<Balance: AtLeast32BitUnsigned + Copy>(
+    ranges: &mut Vec<SerialRange<Balance>>,
+    new_ranges: Vec<SerialRange<Balance>>,
+) {
+    ranges.extend(new_ranges);
+    ranges.sort_by(|a, b| a.start.cmp(&b.start));
+    let mut merged: Vec<SerialRange<Balance>> = Vec::with_capacity(ranges.len());
+    for range in ranges.drain(..) {
+        match merged.last_mut() {
+            Some(last) if last.end + Balance::one() == range.start => last.end = range.end,
+            _ => merged.push(range),
+        }
+    }
+    *ranges = merged;
+}
@@ -1,16 +1,158 @@
 use frame_support::{
     codec::{Decode, Encode},
     sp_runtime::RuntimeDebug,
+    dispatch::Vec,
 };
+#[cfg(feature = "std")]
+use serde::{Serialize, Deserialize};
+use pallet_evercity_accounts::accounts::{
+    RoleMask,
+    CC_PROJECT_OWNER_ROLE_MASK,
+    CC_AUDITOR_ROLE_MASK,
+    CC_STANDARD_ROLE_MASK,
+    CC_REGISTRY_ROLE_MASK,
+    CC_INVESTOR_ROLE_MASK,
+};
+
+pub type StandardId = u32;
+
+/// Roles, that can sign project documentation and annual reports after project owner
+pub const STANDARD_SIGNING_ROLES: [RoleMask; 4] = [
+    CC_AUDITOR_ROLE_MASK,
+    CC_STANDARD_ROLE_MASK,
+    CC_INVESTOR_ROLE_MASK,
+    CC_REGISTRY_ROLE_MASK,
+];
+
+/// Crediting periods are commonly renewable twice, e.g. 7 years renewable twice
+pub const DEFAULT_MAX_CREDITING_PERIOD_RENEWALS: u8 = 2;
+/// Buffer pool can not take the whole issuance
+pub const MAX_BUFFER_PERCENT: u8 = 99;
+/// Roles, that can release carbon credits
+pub const ISSUER_ROLES: [RoleMask; 2] = [CC_PROJECT_OWNER_ROLE_MASK, CC_REGISTRY_ROLE_MASK];
 
 #[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[allow(non_camel_case_types)]
 pub enum Standard {
     GOLD_STANDARD,
+    VERRA_VCS,
+    CUSTOM(StandardId),
 }
 
 impl Default for Standard {
     fn default() -> Standard {
         Standard::GOLD_STANDARD
     }
+}
+
+impl Standard {
+    /// Definitions of built in standards, custom standards are defined only in pallet storage
+    pub fn default_definition(&self) -> Option<StandardStruct> {
+        match self {
+            // Project Owner submits PDD (changing status to Registration) => 
+            // => Auditor Approves PDD => Standard Certifies PDD => Registry Registers PDD (changing status to Issuance)
+            // Project Owner sends report for verification =>  Auditor provides and submits verification report => 
+            // Standard Approves carbon credit issuance => Registry issues carbon credits
+            Standard::GOLD_STANDARD => Some(StandardStruct::new(
+                sp_std::vec![CC_PROJECT_OWNER_ROLE_MASK, CC_AUDITOR_ROLE_MASK, CC_STANDARD_ROLE_MASK, CC_REGISTRY_ROLE_MASK],
+                sp_std::vec![CC_PROJECT_OWNER_ROLE_MASK, CC_AUDITOR_ROLE_MASK, CC_STANDARD_ROLE_MASK, CC_REGISTRY_ROLE_MASK],
+            )),
+            // Project Owner submits PDD => VVB validates PDD (auditor role) => Verra reviews the registration request
+            // (standard role) => Registry registers PDD. The project flow is deliberately the same as Gold Standard:
+            // both programs require validation and standard review before registration.
+            // Project Owner sends monitoring report => VVB verifies it => Registry issues carbon credits,
+            // Verra doesnt co-sign monitoring reports, unlike Gold Standard
+            Standard::VERRA_VCS => Some(StandardStruct::new(
+                sp_std::vec![CC_PROJECT_OWNER_ROLE_MASK, CC_AUDITOR_ROLE_MASK, CC_STANDARD_ROLE_MASK, CC_REGISTRY_ROLE_MASK],
+                sp_std::vec![CC_PROJECT_OWNER_ROLE_MASK, CC_AUDITOR_ROLE_MASK, CC_REGISTRY_ROLE_MASK],
+            )),
+            Standard::CUSTOM(_) => None,
+        }
+    }
+}
+
+/// Standard definition, that determines the order of signatures
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct StandardStruct {
+    pub project_signing_order: Vec<RoleMask>,
+    pub report_signing_order: Vec<RoleMask>,
+    /// New annual reports can be created before previous ones are issued
+    pub allow_concurrent_reports: bool,
+    /// How many times crediting period of the project can be renewed, 0 for fixed crediting period
+    pub max_crediting_period_renewals: u8,
+    /// Share of every issuance in percents, that goes to non-permanence buffer pool
+    pub buffer_percent: u8,
+    /// Role, that releases carbon credits of the annual reports: project owner or registry, that signed the project
+    pub issuer_role: RoleMask,
+}
+
+impl StandardStruct {
+    pub fn new(project_signing_order: Vec<RoleMask>, report_signing_order: Vec<RoleMask>) -> Self {
+        StandardStruct {
+            project_signing_order,
+            report_signing_order,
+            allow_concurrent_reports: false,
+            max_crediting_period_renewals: DEFAULT_MAX_CREDITING_PERIOD_RENEWALS,
+            buffer_percent: 0,
+            issuer_role: CC_PROJECT_OWNER_ROLE_MASK,
+        }
+    }
+
+    /// Allows annual reports for overlapping periods to be verified in parallel
+    pub fn with_concurrent_reports(mut self) -> Self {
+        self.allow_concurrent_reports = true;
+        self
+    }
+
+    pub fn with_max_crediting_period_renewals(mut self, max_crediting_period_renewals: u8) -> Self {
+        self.max_crediting_period_renewals = max_crediting_period_renewals;
+        self
+    }
+
+    /// Withholds share of every issuance to the buffer pool, e.g. for AFOLU projects
+    pub fn with_buffer_percent(mut self, buffer_percent: u8) -> Self {
+        self.buffer_percent = buffer_percent;
+        self
+    }
+
+    /// Carbon credits are issued by the given role instead of project owner
+    pub fn with_issuer_role(mut self, issuer_role: RoleMask) -> Self {
+        self.issuer_role = issuer_role;
+        self
+    }
+
+    pub fn is_valid(&self) -> bool {
+        Self::is_valid_signing_order(&self.project_signing_order) && 
+        Self::is_valid_signing_order(&self.report_signing_order) &&
+        self.buffer_percent <= MAX_BUFFER_PERCENT &&
+        ISSUER_ROLES.contains(&self.issuer_role)
+    }
+
+    /// Adds investor stage to signing orders, that dont have it
+    pub fn with_investor_stage(mut self) -> Self {
+        Self::add_investor_stage(&mut self.project_signing_order);
+        Self::add_investor_stage(&mut self.report_signing_order);
+        self
+    }
+
+    /// Investor signs before registry, or the last if registry doesnt sign
+    fn add_investor_stage(signing_order: &mut Vec<RoleMask>) {
+        if signing_order.contains(&CC_INVESTOR_ROLE_MASK) {
+            return;
+        }
+        let position = signing_order.iter()
+            .position(|role| *role == CC_REGISTRY_ROLE_MASK)
+            .unwrap_or(signing_order.len());
+        signing_order.insert(position, CC_INVESTOR_ROLE_MASK);
+    }
+
+    /// Signing starts from project owner, then each of other roles signs at most once
+    fn is_valid_signing_order(signing_order: &[RoleMask]) -> bool {
+        signing_order.len() > 1 &&
+        signing_order[0] == CC_PROJECT_OWNER_ROLE_MASK &&
+        signing_order.iter().skip(1).all(|role| STANDARD_SIGNING_ROLES.contains(role)) &&
+        signing_order.iter().enumerate().all(|(i, role)| !signing_order[i + 1..].contains(role))
+    }
 }
\ No newline at end of file
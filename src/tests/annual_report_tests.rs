@@ -1,6 +1,6 @@
 use crate::Error;
 use crate::tests::mock::*;
-use frame_support::{assert_ok, assert_noop, dispatch::{
+use frame_support::{assert_ok, assert_noop, traits::Get, dispatch::{
     DispatchResult,
     Vec,
 }};
@@ -9,6 +9,7 @@ use crate::annual_report::*;
 use pallet_evercity_accounts::accounts::*;
 use crate::tests::helpers::*;
 use sp_std::vec;
+use pallet_evercity_filesign::file::H256;
 
 type RuntimeError = Error<TestRuntime>;
 
@@ -24,15 +25,15 @@ fn it_works_for_create_new_annual_report_with_file() {
 
         let create_report_result = CarbonCredits::create_annual_report_with_file(
             Origin::signed(owner), project_id, file_id, filehash, tag, TEST_CARBON_CREDITS_COUNT,
-            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL
+            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, get_test_monitoring_period(0)
         );
 
 
         let project_with_report = CarbonCredits::get_proj_by_id(project_id).unwrap();
 
-        assert_eq!(project.annual_reports.len() + 1, project_with_report.annual_reports.len());
-        assert_eq!(REPORT_PROJECT_OWNER_SIGN_PENDING, project_with_report.annual_reports.last().unwrap().state);
-        assert_ok!(create_report_result, ());
+        assert_eq!(project.annual_reports_count + 1, project_with_report.annual_reports_count);
+        assert_eq!(REPORT_PROJECT_OWNER_SIGN_PENDING, CarbonCredits::get_last_annual_report(project_with_report.id).unwrap().state);
+        assert_ok!(create_report_result);
     });
 }
 
@@ -49,12 +50,12 @@ fn it_fails_for_create_new_annual_report_with_file_not_project_owner() {
 
         let create_report_result = CarbonCredits::create_annual_report_with_file(
             Origin::signed(other_owner), project_id, file_id, filehash, tag, TEST_CARBON_CREDITS_COUNT,
-            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL
+            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, get_test_monitoring_period(0)
         );
 
         let project_with_report = CarbonCredits::get_proj_by_id(project_id).unwrap();
 
-        assert_eq!(project.annual_reports.len(), project_with_report.annual_reports.len());
+        assert_eq!(project.annual_reports_count, project_with_report.annual_reports_count);
         assert_noop!(create_report_result, RuntimeError::AccountNotOwner);
     });
 }
@@ -72,12 +73,12 @@ fn it_fails_for_create_new_annual_report_with_file_not_owner_role() {
 
         let create_report_result = CarbonCredits::create_annual_report_with_file(
             Origin::signed(not_owner), project_id, file_id, filehash, tag, TEST_CARBON_CREDITS_COUNT,
-            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL
+            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, get_test_monitoring_period(0)
         );
 
         let project_with_report = CarbonCredits::get_proj_by_id(project_id).unwrap();
 
-        assert_eq!(project.annual_reports.len(), project_with_report.annual_reports.len());
+        assert_eq!(project.annual_reports_count, project_with_report.annual_reports_count);
         assert_noop!(create_report_result, RuntimeError::AccountNotOwner);
     });
 }
@@ -93,18 +94,18 @@ fn it_fails_for_create_new_annual_report_with_file_bad_metadata() {
 
         let create_report_empty_name_result = CarbonCredits::create_annual_report_with_file(
             Origin::signed(owner), project_id, file_id, filehash, tag.clone(), TEST_CARBON_CREDITS_COUNT,
-            Vec::new() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL
+            Vec::new() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, get_test_monitoring_period(0)
         );
 
         let create_report_empty_symbol_result = CarbonCredits::create_annual_report_with_file(
             Origin::signed(owner), project_id, file_id, filehash, tag, TEST_CARBON_CREDITS_COUNT,
-            get_test_carbon_credits_name() , Vec::new(), TEST_CARBON_CREDITS_DECIMAL
+            get_test_carbon_credits_name() , Vec::new(), TEST_CARBON_CREDITS_DECIMAL, get_test_monitoring_period(0)
         );
 
 
         let project_with_report = CarbonCredits::get_proj_by_id(project_id).unwrap();
 
-        assert_eq!(project.annual_reports.len(), project_with_report.annual_reports.len());
+        assert_eq!(project.annual_reports_count, project_with_report.annual_reports_count);
         assert_noop!(create_report_empty_name_result, RuntimeError::BadMetadataParameters);
         assert_noop!(create_report_empty_symbol_result, RuntimeError::BadMetadataParameters);
     });
@@ -118,13 +119,13 @@ fn it_works_for_create_new_annual_report_gold_standard() {
 
         let create_report_result = CarbonCredits::create_annual_report(
             Origin::signed(owner), project_id, create_annual_report_file(owner), TEST_CARBON_CREDITS_COUNT,
-            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL
+            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, get_test_monitoring_period(0)
         );
         let project_with_report = CarbonCredits::get_proj_by_id(project_id).unwrap();
 
-        assert_eq!(project.annual_reports.len() + 1, project_with_report.annual_reports.len());
-        assert_eq!(REPORT_PROJECT_OWNER_SIGN_PENDING, project_with_report.annual_reports.last().unwrap().state);
-        assert_ok!(create_report_result, ());
+        assert_eq!(project.annual_reports_count + 1, project_with_report.annual_reports_count);
+        assert_eq!(REPORT_PROJECT_OWNER_SIGN_PENDING, CarbonCredits::get_last_annual_report(project_with_report.id).unwrap().state);
+        assert_ok!(create_report_result);
     });
 }
 
@@ -136,16 +137,16 @@ fn it_works_for_create_new_annual_report_multiple_annual_reports_gold_standard()
         let report_id = create_annual_report_file(owner);
         let _ = CarbonCredits::create_annual_report(
             Origin::signed(owner), project_id, report_id, TEST_CARBON_CREDITS_COUNT,
-            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL
+            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, get_test_monitoring_period(0)
         );
         let create_second_report_result = CarbonCredits::create_annual_report(
             Origin::signed(owner), project_id, report_id, TEST_CARBON_CREDITS_COUNT,
-            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL
+            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, get_test_monitoring_period(0)
         );
         let project_with_report = CarbonCredits::get_proj_by_id(project_id).unwrap();
 
-        assert_ne!(create_second_report_result, DispatchResult::Ok(()));
-        assert_eq!(project.annual_reports.len() + 1, project_with_report.annual_reports.len());
+        assert!(create_second_report_result.is_err());
+        assert_eq!(project.annual_reports_count + 1, project_with_report.annual_reports_count);
     });
 }
 
@@ -156,17 +157,17 @@ fn it_fails_for_create_new_annual_report_empty_name_gold_standard() {
         let results = vec![
             CarbonCredits::create_annual_report(
                 Origin::signed(owner), project_id, create_annual_report_file(owner), TEST_CARBON_CREDITS_COUNT,
-                Vec::new() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL
+                Vec::new() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, get_test_monitoring_period(0)
             ),
             CarbonCredits::create_annual_report(
                 Origin::signed(owner), project_id, create_annual_report_file(owner), TEST_CARBON_CREDITS_COUNT,
-                get_test_carbon_credits_name(), Vec::new(), TEST_CARBON_CREDITS_DECIMAL
+                get_test_carbon_credits_name(), Vec::new(), TEST_CARBON_CREDITS_DECIMAL, get_test_monitoring_period(0)
             )
         ];
 
         let project_with_report = CarbonCredits::get_proj_by_id(project_id).unwrap();
 
-        assert_eq!(project.annual_reports.len(), project_with_report.annual_reports.len());
+        assert_eq!(project.annual_reports_count, project_with_report.annual_reports_count);
 
         results.iter().for_each(|create_report_result| {
             assert_noop!(
@@ -185,11 +186,11 @@ fn it_fails_for_create_new_annual_report_no_file() {
 
         let create_report_result = CarbonCredits::create_annual_report(
             Origin::signed(owner), project_id, unexisting_file_id, TEST_CARBON_CREDITS_COUNT,
-            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL
+            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, get_test_monitoring_period(0)
         );
         let project_with_report = CarbonCredits::get_proj_by_id(project_id).unwrap();
 
-        assert_eq!(project.annual_reports.len(), project_with_report.annual_reports.len());
+        assert_eq!(project.annual_reports_count, project_with_report.annual_reports_count);
         assert_noop!(
             create_report_result,
             RuntimeError::AccountNotFileOwner
@@ -206,11 +207,11 @@ fn it_fails_for_create_new_annual_report_not_file_owner() {
 
         let create_report_result = CarbonCredits::create_annual_report(
             Origin::signed(owner), project_id, unexisting_file_id, TEST_CARBON_CREDITS_COUNT,
-            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL
+            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, get_test_monitoring_period(0)
         );
         let project_with_report = CarbonCredits::get_proj_by_id(project_id).unwrap();
 
-        assert_eq!(project.annual_reports.len(), project_with_report.annual_reports.len());
+        assert_eq!(project.annual_reports_count, project_with_report.annual_reports_count);
         assert_noop!(
             create_report_result,
             RuntimeError::AccountNotFileOwner
@@ -231,40 +232,40 @@ fn it_fails_for_create_new_annual_report_gold_standard_not_registered() {
         let mut report_results = Vec::with_capacity(4);
         let mut projects = Vec::with_capacity(4);
     
-        let _ = CarbonCredits::create_project(Origin::signed(owner), standard, create_project_documentation_file(owner));
+        let _ = CarbonCredits::create_project(Origin::signed(owner), standard, create_project_documentation_file(owner), get_test_project_metadata(), TEST_CREDITING_PERIOD_START, TEST_CREDITING_PERIOD_END);
         report_results.push(CarbonCredits::create_annual_report(
             Origin::signed(owner), project_id, report_id, TEST_CARBON_CREDITS_COUNT,
-            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL
+            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, get_test_monitoring_period(0)
         ));
         projects.push(CarbonCredits::get_proj_by_id(1).unwrap());
 
         let _ = CarbonCredits::sign_project(Origin::signed(owner), 1);
         report_results.push(CarbonCredits::create_annual_report(
             Origin::signed(owner), project_id, report_id, TEST_CARBON_CREDITS_COUNT,
-            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL
+            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, get_test_monitoring_period(0)
         ));
         projects.push(CarbonCredits::get_proj_by_id(1).unwrap());
 
         let _ = CarbonCredits::sign_project(Origin::signed(auditor), 1);
         report_results.push(CarbonCredits::create_annual_report(
             Origin::signed(owner), project_id, report_id, TEST_CARBON_CREDITS_COUNT,
-            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL
+            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, get_test_monitoring_period(0)
         ));
         projects.push(CarbonCredits::get_proj_by_id(1).unwrap());
 
         let _ = CarbonCredits::sign_project(Origin::signed(standard_acc), 1);
         report_results.push(CarbonCredits::create_annual_report(
             Origin::signed(owner), project_id, report_id, TEST_CARBON_CREDITS_COUNT,
-            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL
+            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, get_test_monitoring_period(0)
         ));
         projects.push(CarbonCredits::get_proj_by_id(1).unwrap());
 
         // assertion after all steps
         report_results.iter().for_each(|res|{
-            assert_ne!(*res, DispatchResult::Ok(()));
+            assert!(res.is_err());
         });
         projects.iter().for_each(|proj|{
-            assert_eq!(0, proj.annual_reports.len());
+            assert_eq!(0, proj.annual_reports_count);
         });
     });
 }
@@ -281,12 +282,12 @@ fn it_fails_for_create_new_annual_report_not_an_owner_role_gold_standard() {
             .for_each(|x| {
                 let create_report_result = CarbonCredits::create_annual_report(
                     Origin::signed(x), project_id, report_id, TEST_CARBON_CREDITS_COUNT,
-                    get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL
+                    get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, get_test_monitoring_period(0)
                 );
                 let project_with_report = CarbonCredits::get_proj_by_id(project_id).unwrap();
 
-                assert_eq!(project.annual_reports.len(), project_with_report.annual_reports.len());
-                assert_ne!(create_report_result, DispatchResult::Ok(()));
+                assert_eq!(project.annual_reports_count, project_with_report.annual_reports_count);
+                assert!(create_report_result.is_err());
                 assert_noop!(
                     create_report_result,
                     RuntimeError::AccountNotOwner
@@ -308,13 +309,13 @@ fn it_fails_for_create_new_annual_report_not_an_owner_of_the_project_gold_standa
 
         let create_report_result = CarbonCredits::create_annual_report(
             Origin::signed(new_owner_id), project_id, report_id, TEST_CARBON_CREDITS_COUNT,
-            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL
+            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, get_test_monitoring_period(0)
         );
         let project_with_report = CarbonCredits::get_proj_by_id(project_id).unwrap();
 
         assert!(is_owner);
-        assert_eq!(project.annual_reports.len(), project_with_report.annual_reports.len());
-        assert_ne!(create_report_result, DispatchResult::Ok(()));
+        assert_eq!(project.annual_reports_count, project_with_report.annual_reports_count);
+        assert!(create_report_result.is_err());
         assert_noop!(
             create_report_result,
             RuntimeError::AccountNotOwner
@@ -331,7 +332,7 @@ fn it_works_annual_report_assign_signer() {
         let report_id = create_annual_report_file(owner);
         let _ = CarbonCredits::create_annual_report(
             Origin::signed(owner), project_id, report_id, TEST_CARBON_CREDITS_COUNT,
-            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL
+            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, get_test_monitoring_period(0)
         ); 
 
         let assign_results = vec![
@@ -347,10 +348,10 @@ fn it_works_annual_report_assign_signer() {
                 assert_ok!(*result, ());
             }
         );
-        assert!(project_with_report.annual_reports.last().unwrap().is_required_signer((ROLES[1].0, ROLES[1].1)));
-        assert!(project_with_report.annual_reports.last().unwrap().is_required_signer((ROLES[2].0, ROLES[2].1)));
-        assert!(project_with_report.annual_reports.last().unwrap().is_required_signer((ROLES[3].0, ROLES[3].1)));
-        assert!(project_with_report.annual_reports.last().unwrap().is_required_signer((ROLES[5].0, ROLES[5].1)));
+        assert!(CarbonCredits::get_last_annual_report(project_with_report.id).unwrap().is_required_signer((ROLES[1].0, ROLES[1].1)));
+        assert!(CarbonCredits::get_last_annual_report(project_with_report.id).unwrap().is_required_signer((ROLES[2].0, ROLES[2].1)));
+        assert!(CarbonCredits::get_last_annual_report(project_with_report.id).unwrap().is_required_signer((ROLES[3].0, ROLES[3].1)));
+        assert!(CarbonCredits::get_last_annual_report(project_with_report.id).unwrap().is_required_signer((ROLES[5].0, ROLES[5].1)));
     });
 }
 
@@ -362,7 +363,7 @@ fn it_works_annual_report_remove_signer() {
         let report_id = create_annual_report_file(owner);
         let _ = CarbonCredits::create_annual_report(
             Origin::signed(owner), project_id, report_id, TEST_CARBON_CREDITS_COUNT,
-            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL
+            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, get_test_monitoring_period(0)
         ); 
 
         let _ = CarbonCredits::assign_last_annual_report_signer(Origin::signed(owner), ROLES[1].0, ROLES[1].1, project_id);
@@ -374,12 +375,12 @@ fn it_works_annual_report_remove_signer() {
         let project_with_report = CarbonCredits::get_proj_by_id(project_id).unwrap();
 
         assert_ok!(delete_result, ());
-        assert!(!project_with_report.annual_reports.last().unwrap().is_required_signer((ROLES[5].0, ROLES[5].1)));
+        assert!(!CarbonCredits::get_last_annual_report(project_with_report.id).unwrap().is_required_signer((ROLES[5].0, ROLES[5].1)));
 
         // Assert that others are not deleted:
-        assert!(project_with_report.annual_reports.last().unwrap().is_required_signer((ROLES[1].0, ROLES[1].1)));
-        assert!(project_with_report.annual_reports.last().unwrap().is_required_signer((ROLES[2].0, ROLES[2].1)));
-        assert!(project_with_report.annual_reports.last().unwrap().is_required_signer((ROLES[3].0, ROLES[3].1)));
+        assert!(CarbonCredits::get_last_annual_report(project_with_report.id).unwrap().is_required_signer((ROLES[1].0, ROLES[1].1)));
+        assert!(CarbonCredits::get_last_annual_report(project_with_report.id).unwrap().is_required_signer((ROLES[2].0, ROLES[2].1)));
+        assert!(CarbonCredits::get_last_annual_report(project_with_report.id).unwrap().is_required_signer((ROLES[3].0, ROLES[3].1)));
     });
 }
 
@@ -391,7 +392,7 @@ fn it_fails_annual_report_remove_unexisting_signer() {
         let report_id = create_annual_report_file(owner);
         let _ = CarbonCredits::create_annual_report(
             Origin::signed(owner), project_id, report_id, TEST_CARBON_CREDITS_COUNT,
-            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL
+            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, get_test_monitoring_period(0)
         ); 
 
         let _ = CarbonCredits::assign_last_annual_report_signer(Origin::signed(owner), ROLES[1].0, ROLES[1].1, project_id);
@@ -403,9 +404,9 @@ fn it_fails_annual_report_remove_unexisting_signer() {
 
         assert_noop!(delete_result, RuntimeError::AccountNotGivenRoleSigner);
 
-        assert!(project_with_report.annual_reports.last().unwrap().is_required_signer((ROLES[1].0, ROLES[1].1)));
-        assert!(project_with_report.annual_reports.last().unwrap().is_required_signer((ROLES[2].0, ROLES[2].1)));
-        assert!(project_with_report.annual_reports.last().unwrap().is_required_signer((ROLES[3].0, ROLES[3].1)));
+        assert!(CarbonCredits::get_last_annual_report(project_with_report.id).unwrap().is_required_signer((ROLES[1].0, ROLES[1].1)));
+        assert!(CarbonCredits::get_last_annual_report(project_with_report.id).unwrap().is_required_signer((ROLES[2].0, ROLES[2].1)));
+        assert!(CarbonCredits::get_last_annual_report(project_with_report.id).unwrap().is_required_signer((ROLES[3].0, ROLES[3].1)));
     });
 }
 
@@ -418,7 +419,7 @@ fn it_fails_annual_report_remove_signed_signer() {
         let report_id = create_annual_report_file(owner);
         let _ = CarbonCredits::create_annual_report(
             Origin::signed(owner), project_id, report_id, TEST_CARBON_CREDITS_COUNT,
-            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL
+            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, get_test_monitoring_period(0)
         ); 
 
         let _ = CarbonCredits::assign_last_annual_report_signer(Origin::signed(owner), ROLES[1].0, ROLES[1].1, project_id);
@@ -434,9 +435,9 @@ fn it_fails_annual_report_remove_signed_signer() {
 
         assert_noop!(delete_result, RuntimeError::AccountAlreadySigned);
 
-        assert!(project_with_report.annual_reports.last().unwrap().is_required_signer((ROLES[1].0, ROLES[1].1)));
-        assert!(project_with_report.annual_reports.last().unwrap().is_required_signer((ROLES[2].0, ROLES[2].1)));
-        assert!(project_with_report.annual_reports.last().unwrap().is_required_signer((ROLES[3].0, ROLES[3].1)));
+        assert!(CarbonCredits::get_last_annual_report(project_with_report.id).unwrap().is_required_signer((ROLES[1].0, ROLES[1].1)));
+        assert!(CarbonCredits::get_last_annual_report(project_with_report.id).unwrap().is_required_signer((ROLES[2].0, ROLES[2].1)));
+        assert!(CarbonCredits::get_last_annual_report(project_with_report.id).unwrap().is_required_signer((ROLES[3].0, ROLES[3].1)));
     });
 }
 
@@ -454,7 +455,7 @@ fn it_works_for_full_cycle_sign_annual_report_gold_standard() {
 
         let _ = CarbonCredits::create_annual_report(
             Origin::signed(owner), project_id, report_id, TEST_CARBON_CREDITS_COUNT,
-            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL
+            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, get_test_monitoring_period(0)
         );
         crate::tests::helpers::assign_annual_report_mock_users_required_signers_gold_standard(project_id);
 
@@ -481,11 +482,133 @@ fn it_works_for_full_cycle_sign_annual_report_gold_standard() {
 
                 assert!(EvercityFilesign::address_has_signed_the_file(report_id, &acc));
                 assert_ok!(result, ());
-                assert_eq!(state, project.annual_reports.last().unwrap().state);
+                assert_eq!(state, CarbonCredits::get_last_annual_report(project.id).unwrap().state);
             })
     });
 }
 
+// Verra VCS: Project Owner sends monitoring report for verification => Validation/verification body verifies it =>
+// Registry issues carbon credits
+#[test]
+fn it_works_for_full_cycle_sign_annual_report_verra() {
+    new_test_ext().execute_with(|| {
+        let (_, project_id, owner) = get_registerd_project_and_owner_verra();
+        let auditor = ROLES[2].0;
+        let registry = ROLES[5].0;
+        let report_id = create_annual_report_file(owner);
+
+        let _ = CarbonCredits::create_annual_report(
+            Origin::signed(owner), project_id, report_id, TEST_CARBON_CREDITS_COUNT,
+            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, get_test_monitoring_period(0)
+        );
+        crate::tests::helpers::assign_annual_report_mock_users_required_signers_verra(project_id);
+
+        let tuple_vec = vec![
+            (owner, REPORT_AUDITOR_SIGN_PENDING),
+            (auditor, REPORT_REGISTRY_SIGN_PENDING),
+            (registry, REPORT_ISSUED)
+        ];
+
+        tuple_vec.iter()
+            .for_each(|(acc, state)| {
+                let result = CarbonCredits::sign_last_annual_report(Origin::signed(*acc), project_id);
+                let project = CarbonCredits::get_proj_by_id(project_id).unwrap();
+
+                assert!(EvercityFilesign::address_has_signed_the_file(report_id, acc));
+                assert_ok!(result, ());
+                assert_eq!(*state, CarbonCredits::get_last_annual_report(project.id).unwrap().state);
+            })
+    });
+}
+
+#[test]
+fn it_fails_sign_annual_report_standard_role_verra() {
+    new_test_ext().execute_with(|| {
+        let (_, project_id, owner) = get_registerd_project_and_owner_verra();
+        let auditor = ROLES[2].0;
+        let standard_acc = ROLES[3].0;
+        let report_id = create_annual_report_file(owner);
+
+        let _ = CarbonCredits::create_annual_report(
+            Origin::signed(owner), project_id, report_id, TEST_CARBON_CREDITS_COUNT,
+            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, get_test_monitoring_period(0)
+        );
+        crate::tests::helpers::assign_annual_report_mock_users_required_signers_verra(project_id);
+        let _ = CarbonCredits::assign_last_annual_report_signer(Origin::signed(owner), standard_acc, ROLES[3].1, project_id);
+
+        let _ = CarbonCredits::sign_last_annual_report(Origin::signed(owner), project_id);
+        let _ = CarbonCredits::sign_last_annual_report(Origin::signed(auditor), project_id);
+        let standard_sign_result = CarbonCredits::sign_last_annual_report(Origin::signed(standard_acc), project_id);
+        let project = CarbonCredits::get_proj_by_id(project_id).unwrap();
+
+        assert_noop!(standard_sign_result, RuntimeError::AccountNotRegistry);
+        assert_eq!(REPORT_REGISTRY_SIGN_PENDING, CarbonCredits::get_last_annual_report(project.id).unwrap().state);
+    });
+}
+
+// Investor approves annual report before registry issues carbon credits
+#[test]
+fn it_works_for_full_cycle_sign_annual_report_with_investor_gold_standard() {
+    new_test_ext_with_event().execute_with(|| {
+        let (_, project_id, owner) = get_registerd_project_with_investor_and_owner_gold_standard();
+        let auditor = ROLES[2].0;
+        let standard_acc = ROLES[3].0;
+        let investor = ROLES[4].0;
+        let registry = ROLES[5].0;
+        let report_id = create_annual_report_file(owner);
+
+        let _ = CarbonCredits::create_annual_report(
+            Origin::signed(owner), project_id, report_id, TEST_CARBON_CREDITS_COUNT,
+            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, get_test_monitoring_period(0)
+        );
+        crate::tests::helpers::assign_annual_report_mock_users_required_signers_with_investor(project_id);
+
+        let tuple_vec = vec![
+            (owner, REPORT_AUDITOR_SIGN_PENDING),
+            (auditor, REPORT_STANDARD_SIGN_PENDING),
+            (standard_acc, REPORT_INVESTOR_SIGN_PENDING),
+            (investor, REPORT_REGISTRY_SIGN_PENDING),
+            (registry, REPORT_ISSUED)
+        ];
+
+        tuple_vec.iter()
+            .for_each(|(acc, state)| {
+                let result = CarbonCredits::sign_last_annual_report(Origin::signed(*acc), project_id);
+                let project = CarbonCredits::get_proj_by_id(project_id).unwrap();
+
+                assert_ok!(result, ());
+                assert_eq!(*state, CarbonCredits::get_last_annual_report(project.id).unwrap().state);
+                if *acc == investor {
+                    let check_event = Event::pallet_carbon_credits(crate::RawEvent::AnnualReportSignedByInvestor(investor, project_id));
+                    assert_eq!(check_event, last_event().unwrap());
+                }
+            })
+    });
+}
+
+#[test]
+fn it_fails_sign_annual_report_not_an_investor_gold_standard() {
+    new_test_ext().execute_with(|| {
+        let (_, project_id, owner) = get_registerd_project_with_investor_and_owner_gold_standard();
+        let auditor = ROLES[2].0;
+        let standard_acc = ROLES[3].0;
+        let registry = ROLES[5].0;
+        let report_id = create_annual_report_file(owner);
+
+        let _ = CarbonCredits::create_annual_report(
+            Origin::signed(owner), project_id, report_id, TEST_CARBON_CREDITS_COUNT,
+            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, get_test_monitoring_period(0)
+        );
+        crate::tests::helpers::assign_annual_report_mock_users_required_signers_with_investor(project_id);
+        let _ = CarbonCredits::sign_last_annual_report(Origin::signed(owner), project_id);
+        let _ = CarbonCredits::sign_last_annual_report(Origin::signed(auditor), project_id);
+        let _ = CarbonCredits::sign_last_annual_report(Origin::signed(standard_acc), project_id);
+
+        let registry_sign_result = CarbonCredits::sign_last_annual_report(Origin::signed(registry), project_id);
+        assert_noop!(registry_sign_result, RuntimeError::AccountNotInvestor);
+    });
+}
+
 #[test]
 fn it_fails_sign_annual_report_not_an_owner_of_project_gold_standard() {
     new_test_ext().execute_with(|| {
@@ -493,7 +616,7 @@ fn it_fails_sign_annual_report_not_an_owner_of_project_gold_standard() {
         let report_id = create_annual_report_file(owner);
         let _ = CarbonCredits::create_annual_report(
             Origin::signed(owner), project_id, report_id, TEST_CARBON_CREDITS_COUNT,
-            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL 
+            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, get_test_monitoring_period(0)
         );
         crate::tests::helpers::assign_annual_report_mock_users_required_signers_gold_standard(project_id);
         
@@ -514,7 +637,7 @@ fn it_fails_sign_annual_report_not_an_owner_role_gold_standard() {
         let report_id = create_annual_report_file(owner);
         let _ = CarbonCredits::create_annual_report(
             Origin::signed(owner), project_id, report_id, TEST_CARBON_CREDITS_COUNT,
-            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL
+            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, get_test_monitoring_period(0)
         );
 
         ROLES.iter()
@@ -535,7 +658,7 @@ fn it_fails_sign_annual_report_owner_not_in_signers_gold_standard() {
         let report_id = create_annual_report_file(owner);
         let _ = CarbonCredits::create_annual_report(
             Origin::signed(owner), project_id, report_id, TEST_CARBON_CREDITS_COUNT,
-            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL
+            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, get_test_monitoring_period(0)
         );
         let owner_sign_result = CarbonCredits::sign_last_annual_report(Origin::signed(owner), 1);
         assert_noop!(
@@ -554,7 +677,7 @@ fn it_fails_sign_annual_report_auditor_not_in_signers_gold_standard() {
 
         let _ = CarbonCredits::create_annual_report(
             Origin::signed(owner), project_id, report_id, TEST_CARBON_CREDITS_COUNT,
-            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL
+            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, get_test_monitoring_period(0)
         );
         let _ = CarbonCredits::assign_last_annual_report_signer(Origin::signed(owner), owner, ROLES[1].1, project_id);
         let _owner_sign_result = CarbonCredits::sign_last_annual_report(Origin::signed(owner), 1);
@@ -579,7 +702,7 @@ fn it_fails_sign_annual_report_standard_not_in_signers_gold_standard() {
         
         let _ = CarbonCredits::create_annual_report(
             Origin::signed(owner), project_id, report_id, TEST_CARBON_CREDITS_COUNT,
-            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL
+            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, get_test_monitoring_period(0)
         );
         let _ = CarbonCredits::assign_last_annual_report_signer(Origin::signed(owner), owner, ROLES[1].1, project_id);
         let _ = CarbonCredits::assign_last_annual_report_signer(Origin::signed(owner), auditor, ROLES[2].1, project_id);
@@ -608,7 +731,7 @@ fn it_fails_sign_annual_report_registry_not_in_signers_gold_standard() {
         
         let _ = CarbonCredits::create_annual_report(
             Origin::signed(owner), project_id, report_id, TEST_CARBON_CREDITS_COUNT,
-            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL
+            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, get_test_monitoring_period(0)
         );
         let _ = CarbonCredits::assign_last_annual_report_signer(Origin::signed(owner), owner, ROLES[1].1, project_id);
         let _ = CarbonCredits::assign_last_annual_report_signer(Origin::signed(owner), auditor, ROLES[2].1, project_id);
@@ -634,7 +757,7 @@ fn it_fails_sign_annual_report_not_an_auditor_gold_standard() {
 
         let _ = CarbonCredits::create_annual_report(
             Origin::signed(owner), project_id, report_id, TEST_CARBON_CREDITS_COUNT,
-            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL
+            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, get_test_monitoring_period(0)
         );
         crate::tests::helpers::assign_annual_report_mock_users_required_signers_gold_standard(project_id);
         let _ = CarbonCredits::sign_last_annual_report(Origin::signed(owner), project_id);
@@ -667,7 +790,7 @@ fn it_fails_sign_annual_report_not_a_standard_role_gold_standard() {
 
         let _ = CarbonCredits::create_annual_report(
             Origin::signed(owner), project_id, report_id, TEST_CARBON_CREDITS_COUNT,
-            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL
+            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, get_test_monitoring_period(0)
         );
         crate::tests::helpers::assign_annual_report_mock_users_required_signers_gold_standard(project_id);
         let _ = CarbonCredits::sign_last_annual_report(Origin::signed(owner), project_id);
@@ -707,7 +830,7 @@ fn it_fails_sign_annual_report_not_an_registry_role_gold_standard() {
 
         let _ = CarbonCredits::create_annual_report(
             Origin::signed(owner), project_id, report_id, TEST_CARBON_CREDITS_COUNT,
-            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL
+            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, get_test_monitoring_period(0)
         );
         crate::tests::helpers::assign_annual_report_mock_users_required_signers_gold_standard(project_id);
         let _ = CarbonCredits::sign_last_annual_report(Origin::signed(owner), project_id);
@@ -743,7 +866,7 @@ fn it_fails_sign_annual_report_already_issued_gold_standard() {
 
         let _ = CarbonCredits::create_annual_report(
             Origin::signed(owner), project_id, report_id, TEST_CARBON_CREDITS_COUNT,
-            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL
+            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, get_test_monitoring_period(0)
         );
 
         let _ = CarbonCredits::assign_last_annual_report_signer(Origin::signed(owner), owner, ROLES[1].1, project_id);
@@ -779,14 +902,14 @@ fn it_works_change_report_carbon_credits_count() {
 
         let _ = CarbonCredits::create_annual_report(
             Origin::signed(owner), project_id, report_id, TEST_CARBON_CREDITS_COUNT,
-            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL
+            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, get_test_monitoring_period(0)
         );
         let new_carbon_credits_count = 555666;
         let change_count_result = CarbonCredits::change_report_carbon_credits_count(Origin::signed(owner), project_id, new_carbon_credits_count);
 
         let project_with_report = CarbonCredits::get_proj_by_id(project_id).unwrap();
 
-        assert_eq!(project_with_report.annual_reports.last().unwrap().carbon_credits_count(), new_carbon_credits_count);
+        assert_eq!(CarbonCredits::get_last_annual_report(project_with_report.id).unwrap().carbon_credits_count(), new_carbon_credits_count);
         assert_ok!(change_count_result, ());
     });
 }
@@ -802,13 +925,13 @@ fn it_fails_change_report_carbon_credits_count_last_annual_report_not_owner() {
 
         let _ = CarbonCredits::create_annual_report(
             Origin::signed(owner), project_id, report_id, TEST_CARBON_CREDITS_COUNT,
-            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL
+            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, get_test_monitoring_period(0)
         );
         let new_carbon_credits_count = 555666;
         let change_count_result = CarbonCredits::change_report_carbon_credits_count(Origin::signed(new_owner_id), project_id, new_carbon_credits_count);
         let project_with_report = CarbonCredits::get_proj_by_id(project_id).unwrap();
 
-        assert_eq!(project_with_report.annual_reports.last().unwrap().carbon_credits_count(), TEST_CARBON_CREDITS_COUNT);
+        assert_eq!(CarbonCredits::get_last_annual_report(project_with_report.id).unwrap().carbon_credits_count(), TEST_CARBON_CREDITS_COUNT);
         assert_noop!(change_count_result, RuntimeError::AccountNotOwner);
     });
 }
@@ -825,7 +948,7 @@ fn it_fails_change_report_carbon_credits_count_last_annual_report_not_correct_st
 
         let _ = CarbonCredits::create_annual_report(
             Origin::signed(owner), project_id, report_id, TEST_CARBON_CREDITS_COUNT,
-            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL
+            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, get_test_monitoring_period(0)
         );
         let _ = CarbonCredits::assign_last_annual_report_signer(Origin::signed(owner), owner, ROLES[1].1, project_id);
         let _ = CarbonCredits::assign_last_annual_report_signer(Origin::signed(owner), auditor, ROLES[2].1, project_id);
@@ -846,7 +969,7 @@ fn it_fails_change_report_carbon_credits_count_last_annual_report_not_correct_st
         
         let project_with_report = CarbonCredits::get_proj_by_id(project_id).unwrap();
 
-        assert_eq!(project_with_report.annual_reports.last().unwrap().carbon_credits_count(), TEST_CARBON_CREDITS_COUNT);
+        assert_eq!(CarbonCredits::get_last_annual_report(project_with_report.id).unwrap().carbon_credits_count(), TEST_CARBON_CREDITS_COUNT);
         change_count_results.iter().for_each(|res|{
             assert_noop!(*res, RuntimeError::InvalidState);
         });
@@ -866,7 +989,7 @@ fn it_works_delete_last_annual_report() {
 
         let _ = CarbonCredits::create_annual_report(
             Origin::signed(owner), project_id, report_id, TEST_CARBON_CREDITS_COUNT,
-            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL
+            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, get_test_monitoring_period(0)
         );
         let _ = CarbonCredits::assign_last_annual_report_signer(Origin::signed(owner), owner, ROLES[1].1, project_id);
         let _ = CarbonCredits::assign_last_annual_report_signer(Origin::signed(owner), auditor, ROLES[2].1, project_id);
@@ -875,8 +998,11 @@ fn it_works_delete_last_annual_report() {
 
         let _ = CarbonCredits::sign_last_annual_report(Origin::signed(owner), project_id);
         let result = CarbonCredits::delete_last_annual_report(Origin::signed(owner), project_id);
+        let project = CarbonCredits::get_proj_by_id(project_id).unwrap();
 
         assert_ok!(result, ());
+        assert_eq!(1, project.annual_reports_count);
+        assert!(CarbonCredits::get_annual_report(project_id, 0).is_none());
     });
 }
 
@@ -891,7 +1017,7 @@ fn it_fails_delete_issued_annual_report() {
 
         let _ = CarbonCredits::create_annual_report(
             Origin::signed(owner), project_id, report_id, TEST_CARBON_CREDITS_COUNT,
-            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL
+            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, get_test_monitoring_period(0)
         );
 
         let _ = CarbonCredits::assign_last_annual_report_signer(Origin::signed(owner), owner, ROLES[1].1, project_id);
@@ -920,7 +1046,7 @@ fn it_fails_delete_last_annual_report_not_project_owner() {
 
         let _ = CarbonCredits::create_annual_report(
             Origin::signed(owner), project_id, report_id, TEST_CARBON_CREDITS_COUNT,
-            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL
+            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, get_test_monitoring_period(0)
         );
         let result = CarbonCredits::delete_last_annual_report(Origin::signed(new_owner_id), project_id);
 
@@ -935,10 +1061,10 @@ fn it_works_for_create_new_annual_report_deposit_event_gold_standard() {
         let report_id = create_annual_report_file(owner);
         let _ = CarbonCredits::create_annual_report(
             Origin::signed(owner), project_id, report_id, TEST_CARBON_CREDITS_COUNT,
-            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL
+            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, get_test_monitoring_period(0)
         );
         let last_event = last_event().unwrap();
-        let check_event = Event::pallet_carbon_credits(crate::RawEvent::AnnualReportCreated(owner, project_id));
+        let check_event = Event::pallet_carbon_credits(crate::RawEvent::AnnualReportCreated(owner, project_id, get_test_monitoring_period(0)));
 
         assert_eq!(check_event, last_event);
     });
@@ -955,7 +1081,7 @@ fn it_works_sign_annual_report_deposit_events_gold_standard() {
 
         let _ = CarbonCredits::create_annual_report(
             Origin::signed(owner), project_id, report_id, TEST_CARBON_CREDITS_COUNT,
-            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL
+            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, get_test_monitoring_period(0)
         );
         crate::tests::helpers::assign_annual_report_mock_users_required_signers_gold_standard(project_id);
 
@@ -974,4 +1100,450 @@ fn it_works_sign_annual_report_deposit_events_gold_standard() {
                 assert_eq!(*check_event, last_event);
             });
     });
-}
\ No newline at end of file
+}
+
+#[test]
+fn it_works_sign_annual_report_deposit_events_verra() {
+    new_test_ext_with_event().execute_with(|| {
+        let (_, project_id, owner) = get_registerd_project_and_owner_verra();
+        let auditor = ROLES[2].0;
+        let registry = ROLES[5].0;
+        let report_id = create_annual_report_file(owner);
+
+        let _ = CarbonCredits::create_annual_report(
+            Origin::signed(owner), project_id, report_id, TEST_CARBON_CREDITS_COUNT,
+            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, get_test_monitoring_period(0)
+        );
+        crate::tests::helpers::assign_annual_report_mock_users_required_signers_verra(project_id);
+
+        let tuple_vec = vec![
+            (owner, Event::pallet_carbon_credits(crate::RawEvent::AnnualReportSubmited(owner, 1))),
+            (auditor, Event::pallet_carbon_credits(crate::RawEvent::AnnualReportSignedByAuditor(auditor, 1))),
+            (registry, Event::pallet_carbon_credits(crate::RawEvent::AnnualReportSignedByRegistry(registry, 1))),
+        ];
+
+        tuple_vec.iter()
+            .for_each(|(acc, check_event)|{
+                let _ = CarbonCredits::sign_last_annual_report(Origin::signed(*acc), project_id);
+                let last_event = last_event().unwrap();
+
+                assert_eq!(*check_event, last_event);
+            });
+    });
+}
+#[test]
+fn it_works_reject_last_annual_report_by_auditor() {
+    new_test_ext_with_event().execute_with(|| {
+        let (_, project_id, owner) = get_registerd_project_and_owner_gold_standard();
+        let auditor = ROLES[2].0;
+        let reason_hash = H256::from([0x42; 32]);
+
+        let _ = CarbonCredits::create_annual_report(
+            Origin::signed(owner), project_id, create_annual_report_file(owner), TEST_CARBON_CREDITS_COUNT,
+            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, get_test_monitoring_period(0)
+        );
+        crate::tests::helpers::assign_annual_report_mock_users_required_signers_gold_standard(project_id);
+        let _ = CarbonCredits::sign_last_annual_report(Origin::signed(owner), project_id);
+
+        let reject_result = CarbonCredits::reject_last_annual_report(Origin::signed(auditor), project_id, reason_hash);
+        let project = CarbonCredits::get_proj_by_id(project_id).unwrap();
+        let report = CarbonCredits::get_last_annual_report(project.id).unwrap();
+        let rejections = CarbonCredits::get_annual_report_rejections(project_id, 0);
+        let check_event = Event::pallet_carbon_credits(crate::RawEvent::AnnualReportRejected(auditor, project_id, CC_AUDITOR_ROLE_MASK, reason_hash));
+
+        assert_ok!(reject_result, ());
+        assert_eq!(REPORT_PROJECT_OWNER_SIGN_PENDING, report.state);
+        assert_eq!(1, report.rejections_count);
+        assert_eq!(1, rejections.len());
+        assert_eq!(auditor, rejections[0].rejected_by);
+        assert_eq!(CC_AUDITOR_ROLE_MASK, rejections[0].role);
+        assert_eq!(reason_hash, rejections[0].reason_hash);
+        assert_eq!(check_event, last_event().unwrap());
+    });
+}
+
+#[test]
+fn it_works_change_report_carbon_credits_count_after_rejection() {
+    new_test_ext().execute_with(|| {
+        let (_, project_id, owner) = get_registerd_project_and_owner_gold_standard();
+        let auditor = ROLES[2].0;
+        let standard_acc = ROLES[3].0;
+        let new_carbon_credits_count = 100_000;
+
+        let _ = CarbonCredits::create_annual_report(
+            Origin::signed(owner), project_id, create_annual_report_file(owner), TEST_CARBON_CREDITS_COUNT,
+            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, get_test_monitoring_period(0)
+        );
+        crate::tests::helpers::assign_annual_report_mock_users_required_signers_gold_standard(project_id);
+        let _ = CarbonCredits::sign_last_annual_report(Origin::signed(owner), project_id);
+        let _ = CarbonCredits::sign_last_annual_report(Origin::signed(auditor), project_id);
+        let _ = CarbonCredits::reject_last_annual_report(Origin::signed(standard_acc), project_id, H256::from([0x42; 32]));
+
+        let change_count_result = CarbonCredits::change_report_carbon_credits_count(Origin::signed(owner), project_id, new_carbon_credits_count);
+        let project = CarbonCredits::get_proj_by_id(project_id).unwrap();
+
+        assert_ok!(change_count_result, ());
+        assert_eq!(new_carbon_credits_count, CarbonCredits::get_last_annual_report(project.id).unwrap().carbon_credits_count());
+    });
+}
+
+#[test]
+fn it_fails_reject_last_annual_report_not_pending_signer_role() {
+    new_test_ext().execute_with(|| {
+        let (_, project_id, owner) = get_registerd_project_and_owner_gold_standard();
+        let standard_acc = ROLES[3].0;
+
+        let _ = CarbonCredits::create_annual_report(
+            Origin::signed(owner), project_id, create_annual_report_file(owner), TEST_CARBON_CREDITS_COUNT,
+            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, get_test_monitoring_period(0)
+        );
+        crate::tests::helpers::assign_annual_report_mock_users_required_signers_gold_standard(project_id);
+        let _ = CarbonCredits::sign_last_annual_report(Origin::signed(owner), project_id);
+
+        let reject_result = CarbonCredits::reject_last_annual_report(Origin::signed(standard_acc), project_id, H256::from([0x42; 32]));
+        let project = CarbonCredits::get_proj_by_id(project_id).unwrap();
+
+        assert_noop!(reject_result, RuntimeError::AccountNotAuditor);
+        assert_eq!(REPORT_AUDITOR_SIGN_PENDING, CarbonCredits::get_last_annual_report(project.id).unwrap().state);
+    });
+}
+
+#[test]
+fn it_fails_reject_last_annual_report_not_submitted_or_issued() {
+    new_test_ext().execute_with(|| {
+        let (_, project_id, owner) = full_sign_annual_report_gold_standard();
+        let registry = ROLES[5].0;
+
+        let reject_issued_result = CarbonCredits::reject_last_annual_report(Origin::signed(registry), project_id, H256::from([0x42; 32]));
+        let _ = CarbonCredits::create_annual_report(
+            Origin::signed(owner), project_id, create_annual_report_file(owner), TEST_CARBON_CREDITS_COUNT,
+            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, get_test_monitoring_period(1)
+        );
+        let reject_not_submitted_result = CarbonCredits::reject_last_annual_report(Origin::signed(owner), project_id, H256::from([0x42; 32]));
+
+        assert_noop!(reject_issued_result, RuntimeError::InvalidState);
+        assert_noop!(reject_not_submitted_result, RuntimeError::InvalidState);
+    });
+}
+
+#[test]
+fn it_works_create_concurrent_annual_reports_concurrent_standard() {
+    new_test_ext().execute_with(|| {
+        let (project_id, _) = get_registerd_project_with_two_concurrent_annual_reports();
+        let project = CarbonCredits::get_proj_by_id(project_id).unwrap();
+
+        assert_eq!(2, project.annual_reports_count);
+        assert_eq!([9; 16], CarbonCredits::get_annual_report(project.id, 0).unwrap().file_id);
+        assert_eq!([10; 16], CarbonCredits::get_annual_report(project.id, 1).unwrap().file_id);
+    });
+}
+
+#[test]
+fn it_works_sign_annual_report_by_index() {
+    new_test_ext().execute_with(|| {
+        let (project_id, _) = get_registerd_project_with_two_concurrent_annual_reports();
+        let owner = ROLES[1].0;
+        let auditor = ROLES[2].0;
+
+        let owner_sign_result = CarbonCredits::sign_annual_report(Origin::signed(owner), project_id, 0);
+        let auditor_sign_result = CarbonCredits::sign_annual_report(Origin::signed(auditor), project_id, 0);
+        let project = CarbonCredits::get_proj_by_id(project_id).unwrap();
+
+        assert_ok!(owner_sign_result, ());
+        assert_ok!(auditor_sign_result, ());
+        assert_eq!(REPORT_STANDARD_SIGN_PENDING, CarbonCredits::get_annual_report(project.id, 0).unwrap().state);
+        assert_eq!(REPORT_PROJECT_OWNER_SIGN_PENDING, CarbonCredits::get_annual_report(project.id, 1).unwrap().state);
+        assert!(EvercityFilesign::address_has_signed_the_file([9; 16], &auditor));
+        assert!(!EvercityFilesign::address_has_signed_the_file([10; 16], &owner));
+    });
+}
+
+#[test]
+fn it_works_full_sign_concurrent_annual_reports_in_any_order() {
+    new_test_ext().execute_with(|| {
+        let (project_id, _) = get_registerd_project_with_two_concurrent_annual_reports();
+
+        full_sign_annual_report_by_index(project_id, 1);
+        let reports_second_issued = CarbonCredits::get_annual_reports(project_id);
+        full_sign_annual_report_by_index(project_id, 0);
+        let reports = CarbonCredits::get_annual_reports(project_id);
+
+        assert_eq!(REPORT_PROJECT_OWNER_SIGN_PENDING, reports_second_issued[0].state);
+        assert_eq!(REPORT_ISSUED, reports_second_issued[1].state);
+        assert!(reports.iter().all(|x| x.state == REPORT_ISSUED));
+    });
+}
+
+#[test]
+fn it_fails_sign_annual_report_unexisting_index() {
+    new_test_ext().execute_with(|| {
+        let (project_id, owner) = get_registerd_project_with_two_concurrent_annual_reports();
+
+        let sign_result = CarbonCredits::sign_annual_report(Origin::signed(owner), project_id, 2);
+        assert_noop!(sign_result, RuntimeError::AnnualReportNotExist);
+    });
+}
+
+#[test]
+fn it_works_change_annual_report_carbon_credits_count_by_index() {
+    new_test_ext().execute_with(|| {
+        let (project_id, owner) = get_registerd_project_with_two_concurrent_annual_reports();
+        let new_carbon_credits_count = 100_000;
+
+        let change_count_result = CarbonCredits::change_annual_report_carbon_credits_count(Origin::signed(owner), project_id, 0, new_carbon_credits_count);
+        let project = CarbonCredits::get_proj_by_id(project_id).unwrap();
+
+        assert_ok!(change_count_result, ());
+        assert_eq!(new_carbon_credits_count, CarbonCredits::get_annual_report(project.id, 0).unwrap().carbon_credits_count());
+        assert_eq!(TEST_CARBON_CREDITS_COUNT, CarbonCredits::get_annual_report(project.id, 1).unwrap().carbon_credits_count());
+    });
+}
+
+#[test]
+fn it_works_remove_annual_report_signer_by_index() {
+    new_test_ext().execute_with(|| {
+        let (project_id, owner) = get_registerd_project_with_two_concurrent_annual_reports();
+        let auditor = ROLES[2].0;
+
+        let remove_result = CarbonCredits::remove_annual_report_signer(Origin::signed(owner), auditor, CC_AUDITOR_ROLE_MASK, project_id, 0);
+        let project = CarbonCredits::get_proj_by_id(project_id).unwrap();
+
+        assert_ok!(remove_result, ());
+        assert!(!CarbonCredits::get_annual_report(project.id, 0).unwrap().is_required_signer((auditor, CC_AUDITOR_ROLE_MASK)));
+        assert!(CarbonCredits::get_annual_report(project.id, 1).unwrap().is_required_signer((auditor, CC_AUDITOR_ROLE_MASK)));
+    });
+}
+
+#[test]
+fn it_works_reject_annual_report_by_index() {
+    new_test_ext().execute_with(|| {
+        let (project_id, owner) = get_registerd_project_with_two_concurrent_annual_reports();
+        let auditor = ROLES[2].0;
+        let _ = CarbonCredits::sign_annual_report(Origin::signed(owner), project_id, 0);
+        let _ = CarbonCredits::sign_annual_report(Origin::signed(owner), project_id, 1);
+
+        let reject_result = CarbonCredits::reject_annual_report(Origin::signed(auditor), project_id, 0, H256::from([0x42; 32]));
+        let project = CarbonCredits::get_proj_by_id(project_id).unwrap();
+
+        assert_ok!(reject_result, ());
+        assert_eq!(REPORT_PROJECT_OWNER_SIGN_PENDING, CarbonCredits::get_annual_report(project.id, 0).unwrap().state);
+        assert_eq!(REPORT_AUDITOR_SIGN_PENDING, CarbonCredits::get_annual_report(project.id, 1).unwrap().state);
+    });
+}
+
+#[test]
+fn it_works_delete_annual_report_by_index() {
+    new_test_ext().execute_with(|| {
+        let (project_id, owner) = get_registerd_project_with_two_concurrent_annual_reports();
+        let _ = CarbonCredits::sign_annual_report(Origin::signed(owner), project_id, 0);
+        let _ = CarbonCredits::reject_annual_report(Origin::signed(ROLES[2].0), project_id, 0, H256::from([0x42; 32]));
+        assert_eq!(1, CarbonCredits::get_annual_report_rejections(project_id, 0).len());
+
+        let delete_result = CarbonCredits::delete_annual_report(Origin::signed(owner), project_id, 0);
+        let project = CarbonCredits::get_proj_by_id(project_id).unwrap();
+
+        assert_ok!(delete_result, ());
+        assert_eq!(2, project.annual_reports_count);
+        assert!(CarbonCredits::get_annual_report(project.id, 0).is_none());
+        assert!(CarbonCredits::get_annual_report_rejections(project.id, 0).is_empty());
+        assert_eq!([10; 16], CarbonCredits::get_annual_report(project.id, 1).unwrap().file_id);
+    });
+}
+
+#[test]
+fn it_works_delete_annual_report_by_index_later_report_released() {
+    new_test_ext().execute_with(|| {
+        let (project_id, owner) = get_registerd_project_with_two_concurrent_annual_reports();
+        full_sign_annual_report_by_index(project_id, 1);
+        let _ = CarbonCredits::release_annual_report_carbon_credits(Origin::signed(owner), project_id, 1, 1, owner, 1);
+
+        let delete_result = CarbonCredits::delete_annual_report(Origin::signed(owner), project_id, 0);
+        let asset_id = CarbonCredits::annual_report_asset(project_id, 1).unwrap();
+        let passport = CarbonCredits::get_passport_by_assetid(asset_id).unwrap();
+
+        assert_ok!(delete_result, ());
+        // Passport still references the released report
+        assert_eq!(2, passport.get_annual_report_index());
+        assert!(CarbonCredits::get_annual_report(project_id, 1).unwrap().has_carbon_credits_issued());
+        assert_eq!(vec![(1, asset_id)], CarbonCredits::get_project_assets(project_id));
+    });
+}
+
+#[test]
+fn it_fails_sign_deleted_annual_report() {
+    new_test_ext().execute_with(|| {
+        let (project_id, owner) = get_registerd_project_with_two_concurrent_annual_reports();
+        let _ = CarbonCredits::delete_last_annual_report(Origin::signed(owner), project_id);
+
+        let sign_result = CarbonCredits::sign_last_annual_report(Origin::signed(owner), project_id);
+
+        assert_noop!(sign_result, RuntimeError::AnnualReportNotExist);
+        assert_ok!(CarbonCredits::sign_annual_report(Origin::signed(owner), project_id, 0));
+    });
+}
+
+#[test]
+fn it_fails_create_annual_report_limit_exceeded() {
+    new_test_ext().execute_with(|| {
+        let (_, project_id, owner) = get_registerd_project_and_owner_gold_standard();
+        crate::ProjectById::<TestRuntime>::mutate(project_id, |project| {
+            project.as_mut().unwrap().live_annual_reports_count = crate::MAX_ANNUAL_REPORTS;
+        });
+
+        let create_result = CarbonCredits::create_annual_report(
+            Origin::signed(owner), project_id, create_annual_report_file(owner), TEST_CARBON_CREDITS_COUNT,
+            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, get_test_monitoring_period(0)
+        );
+
+        assert_noop!(create_result, RuntimeError::AnnualReportsLimitExceeded);
+    });
+}
+
+#[test]
+fn it_works_create_annual_report_after_delete_at_limit() {
+    new_test_ext().execute_with(|| {
+        let (_, project_id, owner) = get_registerd_project_and_owner_gold_standard();
+        let _ = CarbonCredits::create_annual_report(
+            Origin::signed(owner), project_id, create_annual_report_file(owner), TEST_CARBON_CREDITS_COUNT,
+            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, get_test_monitoring_period(0)
+        );
+        crate::ProjectById::<TestRuntime>::mutate(project_id, |project| {
+            project.as_mut().unwrap().live_annual_reports_count = crate::MAX_ANNUAL_REPORTS;
+        });
+
+        // Deleted reports dont count towards the limit
+        let delete_result = CarbonCredits::delete_last_annual_report(Origin::signed(owner), project_id);
+        let create_result = CarbonCredits::create_annual_report(
+            Origin::signed(owner), project_id, create_annual_report_file_with_id(owner, [10; 16]), TEST_CARBON_CREDITS_COUNT,
+            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, get_test_monitoring_period(0)
+        );
+        let project = CarbonCredits::get_proj_by_id(project_id).unwrap();
+
+        assert_ok!(delete_result, ());
+        assert_ok!(create_result);
+        assert_eq!(2, project.annual_reports_count);
+        assert_eq!(crate::MAX_ANNUAL_REPORTS, project.live_annual_reports_count);
+    });
+}
+
+#[test]
+fn it_works_create_annual_report_refunds_unread_reports_weight() {
+    new_test_ext().execute_with(|| {
+        let (project_id, owner) = get_registerd_project_with_two_concurrent_annual_reports();
+        let _ = CarbonCredits::delete_last_annual_report(Origin::signed(owner), project_id);
+
+        let create_result = CarbonCredits::create_annual_report(
+            Origin::signed(owner), project_id, create_annual_report_file_with_id(owner, [11; 16]), TEST_CARBON_CREDITS_COUNT,
+            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, get_test_monitoring_period(2)
+        );
+        let db_weight = <TestRuntime as frame_system::Config>::DbWeight::get();
+
+        // Only the live report is read to check overlapping monitoring periods
+        assert_eq!(Some(10_000 + db_weight.reads_writes(4 + 1, 2)), create_result.unwrap().actual_weight);
+    });
+}
+
+#[test]
+fn it_works_create_annual_report_with_monitoring_period() {
+    new_test_ext().execute_with(|| {
+        let (_, project_id, owner) = get_registerd_project_and_owner_gold_standard();
+        let period = MonitoringPeriod::new(TEST_MONITORING_PERIOD_START, TEST_MONITORING_PERIOD_START + 1000, TEST_VINTAGE);
+        let create_report_result = CarbonCredits::create_annual_report(
+            Origin::signed(owner), project_id, create_annual_report_file(owner), TEST_CARBON_CREDITS_COUNT,
+            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, period.clone()
+        );
+
+        assert_ok!(create_report_result);
+        assert_eq!(period, CarbonCredits::get_annual_report(project_id, 0).unwrap().monitoring_period);
+    });
+}
+
+#[test]
+fn it_fails_create_annual_report_invalid_monitoring_period() {
+    new_test_ext().execute_with(|| {
+        let (_, project_id, owner) = get_registerd_project_and_owner_gold_standard();
+        let periods = vec![
+            // ends before start
+            MonitoringPeriod::new(TEST_MONITORING_PERIOD_START, TEST_MONITORING_PERIOD_START - 1, TEST_VINTAGE),
+            // empty
+            MonitoringPeriod::new(TEST_MONITORING_PERIOD_START, TEST_MONITORING_PERIOD_START, TEST_VINTAGE),
+            // no vintage
+            MonitoringPeriod::new(TEST_MONITORING_PERIOD_START, TEST_MONITORING_PERIOD_START + 1, 0),
+        ];
+
+        periods.into_iter().for_each(|period| {
+            let create_report_result = CarbonCredits::create_annual_report(
+                Origin::signed(owner), project_id, create_annual_report_file(owner), TEST_CARBON_CREDITS_COUNT,
+                get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, period
+            );
+            assert_noop!(create_report_result, RuntimeError::InvalidMonitoringPeriod);
+        });
+    });
+}
+
+#[test]
+fn it_fails_create_annual_report_overlapping_monitoring_period() {
+    new_test_ext().execute_with(|| {
+        let (project_id, owner) = get_registerd_project_with_two_concurrent_annual_reports();
+        let second_period = get_test_monitoring_period(1);
+        let periods = vec![
+            get_test_monitoring_period(0),
+            MonitoringPeriod::new(second_period.start - 1, second_period.start + 1, TEST_VINTAGE + 1),
+            MonitoringPeriod::new(second_period.end - 1, second_period.end + 1, TEST_VINTAGE + 2),
+        ];
+
+        periods.into_iter().for_each(|period| {
+            let create_report_result = CarbonCredits::create_annual_report(
+                Origin::signed(owner), project_id, create_annual_report_file_with_id(owner, [11; 16]), TEST_CARBON_CREDITS_COUNT,
+                get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, period
+            );
+            assert_noop!(create_report_result, RuntimeError::MonitoringPeriodOverlaps);
+        });
+
+        let adjacent_period_result = CarbonCredits::create_annual_report(
+            Origin::signed(owner), project_id, create_annual_report_file_with_id(owner, [11; 16]), TEST_CARBON_CREDITS_COUNT,
+            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, get_test_monitoring_period(2)
+        );
+        assert_ok!(adjacent_period_result);
+        assert_eq!(3, CarbonCredits::get_proj_by_id(project_id).unwrap().annual_reports_count);
+    });
+}
+
+#[test]
+fn it_fails_create_annual_report_outside_crediting_period() {
+    new_test_ext().execute_with(|| {
+        let (_, project_id, owner) = get_registerd_project_and_owner_gold_standard();
+        let periods = vec![
+            MonitoringPeriod::new(TEST_CREDITING_PERIOD_START - 1, TEST_CREDITING_PERIOD_START + 1, TEST_VINTAGE),
+            MonitoringPeriod::new(TEST_CREDITING_PERIOD_END - 1, TEST_CREDITING_PERIOD_END + 1, TEST_VINTAGE),
+        ];
+
+        periods.into_iter().for_each(|period| {
+            let create_report_result = CarbonCredits::create_annual_report(
+                Origin::signed(owner), project_id, create_annual_report_file(owner), TEST_CARBON_CREDITS_COUNT,
+                get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, period
+            );
+            assert_noop!(create_report_result, RuntimeError::MonitoringPeriodOutsideCreditingPeriod);
+        });
+    });
+}
+
+#[test]
+fn it_works_get_annual_reports_pending_signature() {
+    new_test_ext().execute_with(|| {
+        let (_, project_id, owner) = get_registerd_project_and_owner_gold_standard();
+        let auditor = ROLES[2].0;
+        let _ = CarbonCredits::create_annual_report(
+            Origin::signed(owner), project_id, create_annual_report_file(owner), TEST_CARBON_CREDITS_COUNT, 
+            get_test_carbon_credits_name(), get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, get_test_monitoring_period(0)
+        );
+        assign_annual_report_mock_users_required_signers_gold_standard(project_id);
+
+        assert_eq!(vec![(project_id, 0)], CarbonCredits::get_annual_reports_pending_signature(owner));
+        assert!(CarbonCredits::get_annual_reports_pending_signature(auditor).is_empty());
+
+        let _ = CarbonCredits::sign_last_annual_report(Origin::signed(owner), project_id);
+        assert!(CarbonCredits::get_annual_reports_pending_signature(owner).is_empty());
+        assert_eq!(vec![(project_id, 0)], CarbonCredits::get_annual_reports_pending_signature(auditor));
+    });
+}
@@ -1,7 +1,12 @@
 use crate::Error;
 use crate::tests::mock::*;
-use frame_support::{assert_ok, assert_noop};
+use frame_support::{assert_ok, assert_noop, dispatch::DispatchError};
 use pallet_evercity_accounts::accounts::*;
+use crate::standard::Standard;
+use crate::reversal::ReversalStruct;
+use crate::holder_freeze::HolderFreezeStruct;
+use crate::serial_range::SerialRange;
+use pallet_evercity_filesign::file::{FileId, H256};
 use crate::tests::helpers::*;
 
 type RuntimeError = Error<TestRuntime>;
@@ -22,8 +27,26 @@ fn it_works_for_relase_new_cc_gold_standard() {
         assert_eq!(passport.get_project_id(), project_id);
         assert_eq!(TEST_CARBON_CREDITS_COUNT, balance);
         assert_eq!(*passport.get_asset_id_ref(), asset_id);
-        assert_eq!(passport.get_annual_report_index(), project.annual_reports.len() as u64);
-        assert!(project.annual_reports.last().unwrap().is_carbon_credits_released());
+        assert_eq!(passport.get_annual_report_index(), project.annual_reports_count);
+        assert_eq!(&get_test_monitoring_period(0), passport.get_monitoring_period());
+        assert_eq!(TEST_VINTAGE, passport.get_vintage());
+        assert!(CarbonCredits::get_last_annual_report(project.id).unwrap().is_carbon_credits_released());
+    });
+}
+
+#[test]
+fn it_works_for_relase_new_cc_verra() {
+    new_test_ext().execute_with(|| {
+        let (_, project_id, owner) = full_sign_annual_report_verra();
+        let asset_id = 1;
+        let release_call = CarbonCredits::release_carbon_credits(Origin::signed(owner), project_id, asset_id, owner, 1);
+        let passport = CarbonCredits::get_passport_by_assetid(asset_id).unwrap();
+        let project = CarbonCredits::get_proj_by_id(project_id).unwrap();
+
+        assert_ok!(release_call, ());
+        assert_eq!(passport.get_project_id(), project_id);
+        assert_eq!(TEST_CARBON_CREDITS_COUNT, Assets::balance(asset_id, owner));
+        assert!(CarbonCredits::get_last_annual_report(project.id).unwrap().is_carbon_credits_released());
     });
 }
 
@@ -39,7 +62,7 @@ fn it_fails_for_relase_cc_not_owner_role() {
         let project = CarbonCredits::get_proj_by_id(project_id).unwrap();
         
         assert!(passport.is_none());
-        assert!(!project.annual_reports.last().unwrap().is_carbon_credits_released());
+        assert!(!CarbonCredits::get_last_annual_report(project.id).unwrap().is_carbon_credits_released());
         assert_noop!(release_call, RuntimeError::AccountNotOwner);
     });
 }
@@ -53,7 +76,7 @@ fn it_fails_for_relase_cc_not_owner_account() {
         let release_call = CarbonCredits::release_carbon_credits(Origin::signed(new_owner_id), project_id, asset_id, new_owner_id, 1);
 
         let project = CarbonCredits::get_proj_by_id(project_id).unwrap();
-        let last_annual_report = project.annual_reports.last().unwrap();
+        let last_annual_report = CarbonCredits::get_last_annual_report(project.id).unwrap();
         let passport = CarbonCredits::get_passport_by_assetid(asset_id);
         
         assert!(passport.is_none());
@@ -75,6 +98,679 @@ fn it_fails_for_release_cc_no_annual_reports() {
     });
 }
 
+#[test]
+fn it_works_for_release_cc_to_other_holder() {
+    new_test_ext().execute_with(|| {
+        let (_, project_id, owner) = full_sign_annual_report_gold_standard();
+        let holder = ROLES[4].0;
+        let asset_id = 1;
+
+        assert_ok!(CarbonCredits::release_carbon_credits(Origin::signed(owner), project_id, asset_id, holder, 1));
+        assert_eq!(TEST_CARBON_CREDITS_COUNT, Assets::balance(asset_id, holder));
+        assert_eq!(0, Assets::balance(asset_id, owner));
+    });
+}
+
+#[test]
+fn it_fails_for_release_cc_asset_exists() {
+    new_test_ext().execute_with(|| {
+        let (_, project_id, owner) = full_sign_annual_report_gold_standard();
+        let asset_id = 1;
+        assert_ok!(Assets::create(Origin::signed(owner), asset_id, owner, 0, 1));
+
+        assert_noop!(
+            CarbonCredits::release_carbon_credits(Origin::signed(owner), project_id, asset_id, owner, 1),
+            RuntimeError::ErrorCreatingAsset
+        );
+        assert!(CarbonCredits::get_passport_by_assetid(asset_id).is_none());
+        assert!(!CarbonCredits::get_last_annual_report(project_id).unwrap().is_carbon_credits_released());
+    });
+}
+
+#[test]
+fn it_fails_for_release_cc_set_metadata_failed() {
+    new_test_ext().execute_with(|| {
+        let (_, project_id, owner) = full_sign_annual_report_gold_standard();
+        let asset_id = 1;
+        // Name longer than StringLimit of assets pallet
+        crate::AnnualReports::<TestRuntime>::mutate(project_id, 0, |report| {
+            report.as_mut().unwrap().set_metadata(vec![b'C'; 51], get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL)
+        });
+
+        assert_noop!(
+            CarbonCredits::release_carbon_credits(Origin::signed(owner), project_id, asset_id, owner, 1),
+            RuntimeError::SetMetadataFailed
+        );
+        let report = CarbonCredits::get_last_annual_report(project_id).unwrap();
+        assert!(CarbonCredits::get_passport_by_assetid(asset_id).is_none());
+        assert!(!report.is_carbon_credits_released());
+        assert_eq!(vec![b'C'; 51], report.carbon_credits_meta.name);
+        // Asset creation is rolled back, asset id is free
+        assert_ok!(Assets::create(Origin::signed(owner), asset_id, owner, 0, 1));
+    });
+}
+
+#[test]
+fn it_fails_for_release_cc_mint_failed() {
+    new_test_ext().execute_with(|| {
+        let (_, project_id, owner) = full_sign_annual_report_gold_standard();
+        let asset_id = 1;
+        let min_balance = TEST_CARBON_CREDITS_COUNT + 1;
+
+        assert_noop!(
+            CarbonCredits::release_carbon_credits(Origin::signed(owner), project_id, asset_id, owner, min_balance),
+            RuntimeError::ErrorMintingAsset
+        );
+        let report = CarbonCredits::get_last_annual_report(project_id).unwrap();
+        assert!(CarbonCredits::get_passport_by_assetid(asset_id).is_none());
+        assert!(!report.is_carbon_credits_released());
+        assert_eq!(get_test_carbon_credits_name(), report.carbon_credits_meta.name);
+        assert_eq!(0, Assets::balance(asset_id, owner));
+
+        // Release can be repeated with correct parameters
+        assert_ok!(CarbonCredits::release_carbon_credits(Origin::signed(owner), project_id, asset_id, owner, 1));
+        assert_eq!(TEST_CARBON_CREDITS_COUNT, Assets::balance(asset_id, owner));
+    });
+}
+
+#[test]
+fn it_works_for_release_cc_in_tranches() {
+    new_test_ext().execute_with(|| {
+        let (_, project_id, owner) = full_sign_annual_report_gold_standard();
+        let holder = ROLES[4].0;
+        let asset_id = 1;
+        let first_tranche = 10000;
+
+        assert_ok!(CarbonCredits::release_carbon_credits_tranche(Origin::signed(owner), project_id, 0, asset_id, owner, 1, first_tranche));
+        let report = CarbonCredits::get_annual_report(project_id, 0).unwrap();
+        let passport = CarbonCredits::get_passport_by_assetid(asset_id).unwrap();
+
+        assert_eq!(first_tranche, Assets::balance(asset_id, owner));
+        assert!(!report.is_carbon_credits_released());
+        assert_eq!(first_tranche, report.carbon_credits_issued());
+        assert_eq!(TEST_CARBON_CREDITS_COUNT - first_tranche, report.remaining_carbon_credits());
+        assert_eq!(TEST_CARBON_CREDITS_COUNT, passport.get_carbon_credits_count());
+        assert_eq!(first_tranche, passport.get_carbon_credits_issued());
+
+        // Remaining carbon credits are released to the same asset
+        assert_ok!(CarbonCredits::release_annual_report_carbon_credits(Origin::signed(owner), project_id, 0, asset_id, holder, 1));
+        let report = CarbonCredits::get_annual_report(project_id, 0).unwrap();
+        let passport = CarbonCredits::get_passport_by_assetid(asset_id).unwrap();
+
+        assert_eq!(TEST_CARBON_CREDITS_COUNT - first_tranche, Assets::balance(asset_id, holder));
+        assert!(report.is_carbon_credits_released());
+        assert_eq!(0, report.remaining_carbon_credits());
+        assert_eq!(0, passport.get_remaining_carbon_credits());
+        assert_noop!(
+            CarbonCredits::release_carbon_credits_tranche(Origin::signed(owner), project_id, 0, asset_id, owner, 1, 1),
+            RuntimeError::CCAlreadyCreated
+        );
+    });
+}
+
+#[test]
+fn it_fails_for_release_cc_tranche_exceeds_remaining() {
+    new_test_ext().execute_with(|| {
+        let (_, project_id, owner) = full_sign_annual_report_gold_standard();
+        let asset_id = 1;
+
+        assert_noop!(
+            CarbonCredits::release_carbon_credits_tranche(Origin::signed(owner), project_id, 0, asset_id, owner, 1, 0),
+            RuntimeError::InvalidCarbonCreditsAmount
+        );
+        assert_ok!(CarbonCredits::release_carbon_credits_tranche(Origin::signed(owner), project_id, 0, asset_id, owner, 1, 10000));
+        assert_noop!(
+            CarbonCredits::release_carbon_credits_tranche(Origin::signed(owner), project_id, 0, asset_id, owner, 1, TEST_CARBON_CREDITS_COUNT),
+            RuntimeError::InvalidCarbonCreditsAmount
+        );
+    });
+}
+
+#[test]
+fn it_fails_for_release_cc_tranche_other_asset() {
+    new_test_ext().execute_with(|| {
+        let (_, project_id, owner) = full_sign_annual_report_gold_standard();
+        let asset_id = 1;
+
+        assert_ok!(CarbonCredits::release_carbon_credits_tranche(Origin::signed(owner), project_id, 0, asset_id, owner, 1, 10000));
+        assert_noop!(
+            CarbonCredits::release_carbon_credits_tranche(Origin::signed(owner), project_id, 0, asset_id + 1, owner, 1, 1000),
+            RuntimeError::PassportNotExist
+        );
+    });
+}
+
+/// Gold standard withholding 10 percents of every issuance to the buffer pool
+fn set_gold_standard_buffer_percent() {
+    let definition = Standard::GOLD_STANDARD.default_definition().unwrap().with_buffer_percent(10);
+    let _ = CarbonCredits::set_standard_definition(Origin::root(), Standard::GOLD_STANDARD, definition);
+}
+
+#[test]
+fn it_works_for_release_cc_with_buffer_pool() {
+    new_test_ext().execute_with(|| {
+        set_gold_standard_buffer_percent();
+        let (_, project_id, owner) = full_sign_annual_report_gold_standard();
+        let buffer_pool = CarbonCredits::buffer_pool_account_id();
+        let asset_id = 1;
+
+        assert_ok!(CarbonCredits::release_carbon_credits(Origin::signed(owner), project_id, asset_id, owner, 1));
+        let passport = CarbonCredits::get_passport_by_assetid(asset_id).unwrap();
+
+        assert_eq!(13500, Assets::balance(asset_id, owner));
+        assert_eq!(1500, Assets::balance(asset_id, buffer_pool));
+        assert_eq!(1500, passport.get_buffer_carbon_credits());
+        assert_eq!(TEST_CARBON_CREDITS_COUNT, passport.get_carbon_credits_issued());
+    });
+}
+
+#[test]
+fn it_works_for_release_cc_serial_ranges() {
+    new_test_ext().execute_with(|| {
+        set_gold_standard_buffer_percent();
+        let (_, project_id, owner) = full_sign_annual_report_gold_standard();
+        let holder = ROLES[4].0;
+        let buffer_pool = CarbonCredits::buffer_pool_account_id();
+        let asset_id = 1;
+
+        // 10000 in the first tranche: 9000 to the owner, 1000 to the buffer pool
+        assert_ok!(CarbonCredits::release_carbon_credits_tranche(Origin::signed(owner), project_id, 0, asset_id, owner, 1, 10000));
+        // 5000 in the second tranche: 4500 to the holder, 500 to the buffer pool
+        assert_ok!(CarbonCredits::release_carbon_credits(Origin::signed(owner), project_id, asset_id, holder, 1));
+
+        assert_eq!(vec![SerialRange::new(1, 9000)], CarbonCredits::serial_ranges(asset_id, owner));
+        assert_eq!(vec![SerialRange::new(10001, 14500)], CarbonCredits::serial_ranges(asset_id, holder));
+        assert_eq!(
+            vec![SerialRange::new(9001, 10000), SerialRange::new(14501, 15000)],
+            CarbonCredits::serial_ranges(asset_id, buffer_pool)
+        );
+    });
+}
+
+#[test]
+fn it_works_for_release_cc_tranches_with_buffer_percent_fixed_on_submission() {
+    new_test_ext().execute_with(|| {
+        set_gold_standard_buffer_percent();
+        let (_, project_id, owner) = full_sign_annual_report_gold_standard();
+        let buffer_pool = CarbonCredits::buffer_pool_account_id();
+        let asset_id = 1;
+
+        assert_ok!(CarbonCredits::release_carbon_credits_tranche(Origin::signed(owner), project_id, 0, asset_id, owner, 1, 10000));
+        // Changed definition doesnt affect the registered project
+        let definition = Standard::GOLD_STANDARD.default_definition().unwrap().with_buffer_percent(50);
+        assert_ok!(CarbonCredits::set_standard_definition(Origin::root(), Standard::GOLD_STANDARD, definition));
+        assert_ok!(CarbonCredits::release_carbon_credits(Origin::signed(owner), project_id, asset_id, owner, 1));
+        let passport = CarbonCredits::get_passport_by_assetid(asset_id).unwrap();
+
+        assert_eq!(13500, Assets::balance(asset_id, owner));
+        assert_eq!(1500, Assets::balance(asset_id, buffer_pool));
+        assert_eq!(1500, passport.get_buffer_carbon_credits());
+    });
+}
+
+#[test]
+fn it_works_cancel_buffer_cc_serial_ranges() {
+    new_test_ext().execute_with(|| {
+        set_gold_standard_buffer_percent();
+        let (_, project_id, owner) = full_sign_annual_report_gold_standard();
+        let buffer_pool = CarbonCredits::buffer_pool_account_id();
+        let asset_id = 1;
+        let _ = CarbonCredits::release_carbon_credits(Origin::signed(owner), project_id, asset_id, owner, 1);
+
+        assert_ok!(CarbonCredits::cancel_buffer_carbon_credits(Origin::root(), asset_id, 1000));
+        assert_eq!(vec![SerialRange::new(14501, 15000)], CarbonCredits::serial_ranges(asset_id, buffer_pool));
+
+        assert_ok!(CarbonCredits::cancel_buffer_carbon_credits(Origin::root(), asset_id, 500));
+        assert!(CarbonCredits::serial_ranges(asset_id, buffer_pool).is_empty());
+    });
+}
+
+#[test]
+fn it_works_release_cc_project_assets_and_totals() {
+    new_test_ext().execute_with(|| {
+        set_gold_standard_buffer_percent();
+        let (_, project_id, owner) = full_sign_annual_report_gold_standard();
+        let asset_id = 1;
+
+        assert_ok!(CarbonCredits::release_carbon_credits_tranche(Origin::signed(owner), project_id, 0, asset_id, owner, 1, 10000));
+        assert_ok!(CarbonCredits::release_carbon_credits(Origin::signed(owner), project_id, asset_id, owner, 1));
+        assert_ok!(CarbonCredits::burn_carbon_credits(Origin::signed(owner), asset_id, 100));
+        assert_ok!(CarbonCredits::cancel_buffer_carbon_credits(Origin::root(), asset_id, 500));
+        let totals = CarbonCredits::get_project_carbon_credits_totals(project_id);
+
+        assert_eq!(Some(asset_id), CarbonCredits::annual_report_asset(project_id, 0));
+        assert_eq!(vec![(0, asset_id)], CarbonCredits::get_project_assets(project_id));
+        assert_eq!(TEST_CARBON_CREDITS_COUNT, totals.issued);
+        assert_eq!(1500, totals.buffered);
+        assert_eq!(100, totals.retired);
+        assert_eq!(500, totals.cancelled);
+        assert_eq!(TEST_CARBON_CREDITS_COUNT - 600, totals.outstanding());
+    });
+}
+
+#[test]
+fn it_works_release_cc_ledger_totals() {
+    new_test_ext().execute_with(|| {
+        set_gold_standard_buffer_percent();
+        let (_, project_id, owner) = full_sign_annual_report_gold_standard();
+        let asset_id = 1;
+
+        assert_ok!(CarbonCredits::release_carbon_credits(Origin::signed(owner), project_id, asset_id, owner, 1));
+        assert_ok!(CarbonCredits::burn_carbon_credits(Origin::signed(owner), asset_id, 100));
+        assert_ok!(CarbonCredits::cancel_buffer_carbon_credits(Origin::root(), asset_id, 500));
+        let vintage = CarbonCredits::get_passport_by_assetid(asset_id).unwrap().get_vintage();
+        let totals = CarbonCredits::get_carbon_credits_total();
+
+        assert_eq!(TEST_CARBON_CREDITS_COUNT, totals.issued);
+        assert_eq!(1500, totals.buffered);
+        assert_eq!(100, totals.retired);
+        assert_eq!(500, totals.cancelled);
+        assert_eq!(totals, CarbonCredits::get_project_carbon_credits_totals(project_id));
+        assert_eq!(totals, CarbonCredits::get_standard_carbon_credits_totals(Standard::GOLD_STANDARD));
+        assert_eq!(totals, CarbonCredits::get_vintage_carbon_credits_totals(vintage));
+        assert_eq!(Default::default(), CarbonCredits::get_standard_carbon_credits_totals(Standard::VERRA_VCS));
+        assert_eq!(Default::default(), CarbonCredits::get_vintage_carbon_credits_totals(vintage + 1));
+    });
+}
+
+#[test]
+fn it_works_cancel_buffer_cc() {
+    new_test_ext_with_event().execute_with(|| {
+        set_gold_standard_buffer_percent();
+        let (_, project_id, owner) = full_sign_annual_report_gold_standard();
+        let buffer_pool = CarbonCredits::buffer_pool_account_id();
+        let asset_id = 1;
+        let _ = CarbonCredits::release_carbon_credits(Origin::signed(owner), project_id, asset_id, owner, 1);
+
+        let cancel_result = CarbonCredits::cancel_buffer_carbon_credits(Origin::root(), asset_id, 500);
+        let check_event = Event::pallet_carbon_credits(crate::RawEvent::BufferCarbonCreditsCancelled(asset_id, 500));
+
+        assert_ok!(cancel_result, ());
+        assert_eq!(1000, Assets::balance(asset_id, buffer_pool));
+        assert_eq!(1000, CarbonCredits::get_passport_by_assetid(asset_id).unwrap().get_buffer_carbon_credits());
+        assert_eq!(13500, Assets::balance(asset_id, owner));
+        assert_eq!(check_event, last_event().unwrap());
+    });
+}
+
+#[test]
+fn it_fails_cancel_buffer_cc_not_root() {
+    new_test_ext().execute_with(|| {
+        set_gold_standard_buffer_percent();
+        let (_, project_id, owner) = full_sign_annual_report_gold_standard();
+        let asset_id = 1;
+        let _ = CarbonCredits::release_carbon_credits(Origin::signed(owner), project_id, asset_id, owner, 1);
+
+        assert_noop!(
+            CarbonCredits::cancel_buffer_carbon_credits(Origin::signed(ROLES[0].0), asset_id, 500),
+            DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn it_fails_cancel_buffer_cc_insufficient() {
+    new_test_ext().execute_with(|| {
+        let (_, project_id, owner) = full_sign_annual_report_gold_standard();
+        let asset_id = 1;
+        let _ = CarbonCredits::release_carbon_credits(Origin::signed(owner), project_id, asset_id, owner, 1);
+
+        // Gold standard doesnt withhold buffer by default
+        assert_noop!(
+            CarbonCredits::cancel_buffer_carbon_credits(Origin::root(), asset_id, 1),
+            RuntimeError::InsufficientBufferCarbonCredits
+        );
+        assert_noop!(
+            CarbonCredits::cancel_buffer_carbon_credits(Origin::root(), asset_id + 1, 1),
+            RuntimeError::PassportNotExist
+        );
+    });
+}
+
+fn create_reversal_evidence_file(account: u64) -> FileId {
+    let file_id = [20; 16];
+    let _ = EvercityFilesign::create_new_file(Origin::signed(account), b"reversal_evidence".to_vec(), H256::from([0x20; 32]), Some(file_id));
+    file_id
+}
+
+#[test]
+fn it_works_report_reversal_covered_by_buffer() {
+    new_test_ext_with_event().execute_with(|| {
+        set_gold_standard_buffer_percent();
+        let (_, project_id, owner) = full_sign_annual_report_gold_standard();
+        let registry = ROLES[5].0;
+        let asset_id = 1;
+        let _ = CarbonCredits::release_carbon_credits(Origin::signed(owner), project_id, asset_id, owner, 1);
+        let evidence_file_id = create_reversal_evidence_file(registry);
+
+        let reversal_result = CarbonCredits::report_reversal(Origin::signed(registry), project_id, 1000, evidence_file_id);
+        let passport = CarbonCredits::get_passport_by_assetid(asset_id).unwrap();
+        let check_event = Event::pallet_carbon_credits(crate::RawEvent::ReversalReported(registry, project_id, 1000));
+
+        assert_ok!(reversal_result, ());
+        assert_eq!(500, Assets::balance(asset_id, CarbonCredits::buffer_pool_account_id()));
+        assert_eq!(13500, Assets::balance(asset_id, owner));
+        assert_eq!(500, passport.get_buffer_carbon_credits());
+        assert_eq!(1000, passport.get_reversed_carbon_credits());
+        assert_eq!(vec![ReversalStruct::new(registry, evidence_file_id, 1000, 0)], CarbonCredits::get_reversals(project_id));
+        assert_eq!(check_event, last_event().unwrap());
+    });
+}
+
+#[test]
+fn it_works_report_reversal_burns_owner_cc() {
+    new_test_ext().execute_with(|| {
+        set_gold_standard_buffer_percent();
+        let (_, project_id, owner) = full_sign_annual_report_gold_standard();
+        let registry = ROLES[5].0;
+        let asset_id = 1;
+        let _ = CarbonCredits::release_carbon_credits(Origin::signed(owner), project_id, asset_id, owner, 1);
+        let evidence_file_id = create_reversal_evidence_file(registry);
+
+        assert_ok!(CarbonCredits::report_reversal(Origin::signed(registry), project_id, 2000, evidence_file_id));
+        let passport = CarbonCredits::get_passport_by_assetid(asset_id).unwrap();
+
+        assert_eq!(0, Assets::balance(asset_id, CarbonCredits::buffer_pool_account_id()));
+        assert_eq!(13000, Assets::balance(asset_id, owner));
+        assert_eq!(0, passport.get_buffer_carbon_credits());
+        assert_eq!(2000, passport.get_reversed_carbon_credits());
+        assert_eq!(2000, CarbonCredits::get_project_carbon_credits_totals(project_id).cancelled);
+        assert_eq!(2000, CarbonCredits::get_vintage_carbon_credits_totals(passport.get_vintage()).cancelled);
+        assert_eq!(2000, CarbonCredits::get_carbon_credits_total().cancelled);
+    });
+}
+
+#[test]
+fn it_works_report_reversal_burns_cc_of_all_holders() {
+    new_test_ext_with_event().execute_with(|| {
+        let (_, project_id, owner) = full_sign_annual_report_gold_standard();
+        let registry = ROLES[5].0;
+        let investor = ROLES[4].0;
+        let asset_id = 1;
+        let _ = CarbonCredits::release_carbon_credits(Origin::signed(owner), project_id, asset_id, owner, 1);
+        let _ = CarbonCredits::transfer_carbon_credits(Origin::signed(owner), asset_id, investor, 14000);
+        let evidence_file_id = create_reversal_evidence_file(registry);
+
+        assert_ok!(CarbonCredits::report_reversal(Origin::signed(registry), project_id, 2000, evidence_file_id));
+
+        // Asset admin burns carbon credits of every holder, until the loss is covered
+        assert_eq!(13000, Assets::balance(asset_id, owner) + Assets::balance(asset_id, investor));
+        assert_eq!(2000, CarbonCredits::get_passport_by_assetid(asset_id).unwrap().get_reversed_carbon_credits());
+        assert_eq!(0, CarbonCredits::get_reversals(project_id)[0].uncovered_amount);
+    });
+}
+
+#[test]
+fn it_works_report_reversal_uncovered() {
+    new_test_ext_with_event().execute_with(|| {
+        let (_, project_id, owner) = full_sign_annual_report_gold_standard();
+        let registry = ROLES[5].0;
+        let investor = ROLES[4].0;
+        let asset_id = 1;
+        let _ = CarbonCredits::release_carbon_credits(Origin::signed(owner), project_id, asset_id, owner, 1);
+        let _ = CarbonCredits::transfer_carbon_credits(Origin::signed(owner), asset_id, investor, 14000);
+        let evidence_file_id = create_reversal_evidence_file(registry);
+
+        assert_ok!(CarbonCredits::report_reversal(Origin::signed(registry), project_id, 20000, evidence_file_id));
+        let events: Vec<_> = System::events().into_iter().map(|record| record.event).collect();
+
+        assert_eq!(0, Assets::balance(asset_id, owner));
+        assert_eq!(0, Assets::balance(asset_id, investor));
+        assert!(CarbonCredits::serial_ranges(asset_id, owner).is_empty());
+        assert!(CarbonCredits::serial_ranges(asset_id, investor).is_empty());
+        assert!(events.contains(&Event::pallet_carbon_credits(crate::RawEvent::HolderCarbonCreditsReversed(asset_id, owner, 1000))));
+        assert!(events.contains(&Event::pallet_carbon_credits(crate::RawEvent::HolderCarbonCreditsReversed(asset_id, investor, 14000))));
+        assert_eq!(TEST_CARBON_CREDITS_COUNT, CarbonCredits::get_passport_by_assetid(asset_id).unwrap().get_reversed_carbon_credits());
+        assert_eq!(20000 - TEST_CARBON_CREDITS_COUNT, CarbonCredits::get_reversals(project_id)[0].uncovered_amount);
+    });
+}
+
+#[test]
+fn it_works_report_reversals_by_index() {
+    new_test_ext().execute_with(|| {
+        let (_, project_id, owner) = full_sign_annual_report_gold_standard();
+        let registry = ROLES[5].0;
+        let asset_id = 1;
+        let _ = CarbonCredits::release_carbon_credits(Origin::signed(owner), project_id, asset_id, owner, 1);
+        let evidence_file_id = create_reversal_evidence_file(registry);
+
+        assert_ok!(CarbonCredits::report_reversal(Origin::signed(registry), project_id, 1000, evidence_file_id));
+        assert_ok!(CarbonCredits::report_reversal(Origin::signed(registry), project_id, 2000, evidence_file_id));
+
+        assert_eq!(2, CarbonCredits::get_proj_by_id(project_id).unwrap().reversals_count);
+        assert_eq!(Some(ReversalStruct::new(registry, evidence_file_id, 2000, 0)), CarbonCredits::project_reversal_by_index(project_id, 1));
+        assert_eq!(vec![1000, 2000], CarbonCredits::get_reversals(project_id).iter().map(|reversal| reversal.amount).collect::<Vec<_>>());
+    });
+}
+
+#[test]
+fn it_fails_report_reversal_not_registry() {
+    new_test_ext().execute_with(|| {
+        let (_, project_id, owner) = full_sign_annual_report_gold_standard();
+        let auditor = ROLES[2].0;
+        let registry = ROLES[5].0;
+        let asset_id = 1;
+        let _ = CarbonCredits::release_carbon_credits(Origin::signed(owner), project_id, asset_id, owner, 1);
+        let auditor_file_id = create_reversal_evidence_file(auditor);
+
+        assert_noop!(
+            CarbonCredits::report_reversal(Origin::signed(auditor), project_id, 1000, auditor_file_id),
+            RuntimeError::AccountNotRegistry
+        );
+        // Evidence file belongs to other account
+        assert_noop!(
+            CarbonCredits::report_reversal(Origin::signed(registry), project_id, 1000, auditor_file_id),
+            RuntimeError::AccountNotFileOwner
+        );
+        assert!(CarbonCredits::get_reversals(project_id).is_empty());
+    });
+}
+
+#[test]
+fn it_works_freeze_cc() {
+    new_test_ext_with_event().execute_with(|| {
+        let (_, project_id, owner) = full_sign_annual_report_gold_standard();
+        let registry = ROLES[5].0;
+        let investor = ROLES[4].0;
+        let asset_id = 1;
+        let _ = CarbonCredits::release_carbon_credits(Origin::signed(owner), project_id, asset_id, owner, 1);
+
+        let freeze_result = CarbonCredits::freeze_carbon_credits(Origin::signed(registry), asset_id);
+        let check_event = Event::pallet_carbon_credits(crate::RawEvent::CarbonCreditsFrozen(registry, asset_id));
+
+        assert_ok!(freeze_result, ());
+        assert!(CarbonCredits::get_passport_by_assetid(asset_id).unwrap().is_frozen());
+        assert_eq!(check_event, last_event().unwrap());
+        assert_noop!(
+            CarbonCredits::transfer_carbon_credits(Origin::signed(owner), asset_id, investor, 100),
+            RuntimeError::CarbonCreditsAssetFrozen
+        );
+        assert_noop!(
+            CarbonCredits::burn_carbon_credits(Origin::signed(owner), asset_id, 100),
+            RuntimeError::CarbonCreditsAssetFrozen
+        );
+        assert_noop!(
+            CarbonCredits::retire_carbon_credits(Origin::signed(owner), asset_id, 100, b"Beneficiary".to_vec(), Vec::new()),
+            RuntimeError::CarbonCreditsAssetFrozen
+        );
+        assert_noop!(
+            CarbonCredits::freeze_carbon_credits(Origin::signed(registry), asset_id),
+            RuntimeError::CarbonCreditsAssetFreezeUnchanged
+        );
+    });
+}
+
+#[test]
+fn it_works_thaw_cc() {
+    new_test_ext_with_event().execute_with(|| {
+        let (_, project_id, owner) = full_sign_annual_report_gold_standard();
+        let registry = ROLES[5].0;
+        let investor = ROLES[4].0;
+        let asset_id = 1;
+        let _ = CarbonCredits::release_carbon_credits(Origin::signed(owner), project_id, asset_id, owner, 1);
+        let _ = CarbonCredits::freeze_carbon_credits(Origin::signed(registry), asset_id);
+
+        let thaw_result = CarbonCredits::thaw_carbon_credits(Origin::signed(registry), asset_id);
+        let check_event = Event::pallet_carbon_credits(crate::RawEvent::CarbonCreditsThawed(registry, asset_id));
+
+        assert_ok!(thaw_result, ());
+        assert!(!CarbonCredits::get_passport_by_assetid(asset_id).unwrap().is_frozen());
+        assert_eq!(check_event, last_event().unwrap());
+        assert_ok!(CarbonCredits::transfer_carbon_credits(Origin::signed(owner), asset_id, investor, 100));
+        assert_noop!(
+            CarbonCredits::thaw_carbon_credits(Origin::signed(registry), asset_id),
+            RuntimeError::CarbonCreditsAssetFreezeUnchanged
+        );
+    });
+}
+
+#[test]
+fn it_fails_freeze_cc_not_registry() {
+    new_test_ext().execute_with(|| {
+        let (_, project_id, owner) = full_sign_annual_report_gold_standard();
+        let asset_id = 1;
+        let _ = CarbonCredits::release_carbon_credits(Origin::signed(owner), project_id, asset_id, owner, 1);
+
+        assert_noop!(
+            CarbonCredits::freeze_carbon_credits(Origin::signed(owner), asset_id),
+            RuntimeError::AccountNotRegistry
+        );
+        assert_noop!(
+            CarbonCredits::freeze_carbon_credits(Origin::signed(ROLES[5].0), asset_id + 1),
+            RuntimeError::PassportNotExist
+        );
+    });
+}
+
+#[test]
+fn it_works_freeze_cc_holder() {
+    new_test_ext_with_event().execute_with(|| {
+        let (_, project_id, owner) = full_sign_annual_report_gold_standard();
+        let registry = ROLES[5].0;
+        let investor = ROLES[4].0;
+        let asset_id = 1;
+        let reason_hash = H256::from([0x30; 32]);
+        let _ = CarbonCredits::release_carbon_credits(Origin::signed(owner), project_id, asset_id, owner, 1);
+        let _ = CarbonCredits::transfer_carbon_credits(Origin::signed(owner), asset_id, investor, 1000);
+
+        let freeze_result = CarbonCredits::freeze_carbon_credits_holder(Origin::signed(registry), asset_id, investor, reason_hash);
+        let check_event = Event::pallet_carbon_credits(crate::RawEvent::CarbonCreditsHolderFrozen(registry, asset_id, investor));
+
+        assert_ok!(freeze_result, ());
+        assert_eq!(Some(HolderFreezeStruct::new(registry, reason_hash)), CarbonCredits::holder_freeze(asset_id, investor));
+        assert_eq!(check_event, last_event().unwrap());
+        assert_noop!(
+            CarbonCredits::transfer_carbon_credits(Origin::signed(investor), asset_id, owner, 100),
+            RuntimeError::CarbonCreditsHolderFrozen
+        );
+        assert_noop!(
+            CarbonCredits::burn_carbon_credits(Origin::signed(investor), asset_id, 100),
+            RuntimeError::CarbonCreditsHolderFrozen
+        );
+        // Other holders are not affected
+        assert_ok!(CarbonCredits::transfer_carbon_credits(Origin::signed(owner), asset_id, investor, 100));
+        assert_ok!(CarbonCredits::burn_carbon_credits(Origin::signed(owner), asset_id, 100));
+        assert_noop!(
+            CarbonCredits::freeze_carbon_credits_holder(Origin::signed(registry), asset_id, investor, reason_hash),
+            RuntimeError::CarbonCreditsHolderFreezeUnchanged
+        );
+    });
+}
+
+#[test]
+fn it_works_thaw_cc_holder() {
+    new_test_ext_with_event().execute_with(|| {
+        let (_, project_id, owner) = full_sign_annual_report_gold_standard();
+        let registry = ROLES[5].0;
+        let asset_id = 1;
+        let _ = CarbonCredits::release_carbon_credits(Origin::signed(owner), project_id, asset_id, owner, 1);
+        let _ = CarbonCredits::freeze_carbon_credits_holder(Origin::signed(registry), asset_id, owner, H256::from([0x30; 32]));
+
+        let thaw_result = CarbonCredits::thaw_carbon_credits_holder(Origin::signed(registry), asset_id, owner);
+        let check_event = Event::pallet_carbon_credits(crate::RawEvent::CarbonCreditsHolderThawed(registry, asset_id, owner));
+
+        assert_ok!(thaw_result, ());
+        assert!(CarbonCredits::holder_freeze(asset_id, owner).is_none());
+        assert_eq!(check_event, last_event().unwrap());
+        assert_ok!(CarbonCredits::burn_carbon_credits(Origin::signed(owner), asset_id, 100));
+        assert_noop!(
+            CarbonCredits::thaw_carbon_credits_holder(Origin::signed(registry), asset_id, owner),
+            RuntimeError::CarbonCreditsHolderFreezeUnchanged
+        );
+    });
+}
+
+#[test]
+fn it_fails_freeze_cc_holder_not_registry() {
+    new_test_ext().execute_with(|| {
+        let (_, project_id, owner) = full_sign_annual_report_gold_standard();
+        let asset_id = 1;
+        let _ = CarbonCredits::release_carbon_credits(Origin::signed(owner), project_id, asset_id, owner, 1);
+
+        assert_noop!(
+            CarbonCredits::freeze_carbon_credits_holder(Origin::signed(ROLES[2].0), asset_id, owner, H256::from([0x30; 32])),
+            RuntimeError::AccountNotRegistry
+        );
+    });
+}
+
+#[test]
+fn it_works_for_release_cc_by_registry() {
+    new_test_ext_with_event().execute_with(|| {
+        let definition = Standard::GOLD_STANDARD.default_definition().unwrap().with_issuer_role(CC_REGISTRY_ROLE_MASK);
+        let _ = CarbonCredits::set_standard_definition(Origin::root(), Standard::GOLD_STANDARD, definition);
+        let (_, project_id, owner) = full_sign_annual_report_gold_standard();
+        let registry = ROLES[5].0;
+        let asset_id = 1;
+
+        let release_result = CarbonCredits::release_carbon_credits(Origin::signed(registry), project_id, asset_id, owner, 1);
+        let passport = CarbonCredits::get_passport_by_assetid(asset_id).unwrap();
+        let check_event = Event::pallet_carbon_credits(crate::RawEvent::CarbonCreditsMinted(owner, project_id, asset_id, TEST_VINTAGE));
+
+        assert_ok!(release_result, ());
+        assert_eq!(TEST_CARBON_CREDITS_COUNT, Assets::balance(asset_id, owner));
+        assert_eq!(0, Assets::balance(asset_id, registry));
+        assert_eq!(project_id, passport.get_project_id());
+        assert_eq!(TEST_CARBON_CREDITS_COUNT, passport.get_carbon_credits_issued());
+        assert_eq!(check_event, last_event().unwrap());
+    });
+}
+
+#[test]
+fn it_fails_for_release_cc_by_owner_when_registry_issues() {
+    new_test_ext().execute_with(|| {
+        let definition = Standard::GOLD_STANDARD.default_definition().unwrap().with_issuer_role(CC_REGISTRY_ROLE_MASK);
+        let _ = CarbonCredits::set_standard_definition(Origin::root(), Standard::GOLD_STANDARD, definition);
+        let (_, project_id, owner) = full_sign_annual_report_gold_standard();
+        let asset_id = 1;
+
+        assert_noop!(
+            CarbonCredits::release_carbon_credits(Origin::signed(owner), project_id, asset_id, owner, 1),
+            RuntimeError::AccountNotRegistry
+        );
+    });
+}
+
+#[test]
+fn it_works_for_release_cc_by_issuer_role_fixed_on_submission() {
+    new_test_ext().execute_with(|| {
+        let (_, project_id, owner) = full_sign_annual_report_gold_standard();
+        let registry = ROLES[5].0;
+        let asset_id = 1;
+
+        // Changed definition doesnt affect the registered project
+        let definition = Standard::GOLD_STANDARD.default_definition().unwrap().with_issuer_role(CC_REGISTRY_ROLE_MASK);
+        let _ = CarbonCredits::set_standard_definition(Origin::root(), Standard::GOLD_STANDARD, definition);
+
+        assert_noop!(
+            CarbonCredits::release_carbon_credits(Origin::signed(registry), project_id, asset_id, owner, 1),
+            RuntimeError::AccountNotOwner
+        );
+        assert_ok!(CarbonCredits::release_carbon_credits(Origin::signed(owner), project_id, asset_id, owner, 1));
+        assert_eq!(TEST_CARBON_CREDITS_COUNT, Assets::balance(asset_id, owner));
+    });
+}
+
 // cc transfer tests
 #[test]
 fn it_works_for_ransfer_cc() {
@@ -94,6 +790,94 @@ fn it_works_for_ransfer_cc() {
     });
 }
 
+#[test]
+fn it_works_transfer_cc_serial_ranges() {
+    new_test_ext().execute_with(|| {
+        let (_, project_id, owner) = full_sign_annual_report_gold_standard();
+        let asset_id = 1;
+        let _ = CarbonCredits::release_carbon_credits(Origin::signed(owner), project_id, asset_id, owner, 1);
+        let investor = ROLES[4].0;
+
+        assert_ok!(CarbonCredits::transfer_carbon_credits(Origin::signed(owner), asset_id, investor, 100));
+        assert_ok!(CarbonCredits::transfer_carbon_credits(Origin::signed(owner), asset_id, investor, 50));
+        assert_eq!(vec![SerialRange::new(151, TEST_CARBON_CREDITS_COUNT)], CarbonCredits::serial_ranges(asset_id, owner));
+        assert_eq!(vec![SerialRange::new(1, 150)], CarbonCredits::serial_ranges(asset_id, investor));
+
+        // Ranges, returned to the owner, are merged with the remaining ones
+        assert_ok!(CarbonCredits::transfer_carbon_credits(Origin::signed(investor), asset_id, owner, 20));
+        assert_eq!(
+            vec![SerialRange::new(1, 20), SerialRange::new(151, TEST_CARBON_CREDITS_COUNT)],
+            CarbonCredits::serial_ranges(asset_id, owner)
+        );
+        assert_eq!(vec![SerialRange::new(21, 150)], CarbonCredits::serial_ranges(asset_id, investor));
+    });
+}
+
+#[test]
+fn it_works_batch_transfer_cc() {
+    new_test_ext_with_event().execute_with(|| {
+        let (project_id, owner) = get_registerd_project_with_two_concurrent_annual_reports();
+        let investor = ROLES[4].0;
+        let standard_acc = ROLES[3].0;
+        full_sign_annual_report_by_index(project_id, 0);
+        full_sign_annual_report_by_index(project_id, 1);
+        let _ = CarbonCredits::release_annual_report_carbon_credits(Origin::signed(owner), project_id, 0, 1, owner, 1);
+        let _ = CarbonCredits::release_annual_report_carbon_credits(Origin::signed(owner), project_id, 1, 2, owner, 1);
+
+        let transfers = vec![(1, investor, 100), (1, standard_acc, 200), (2, investor, 300)];
+        let transfer_result = CarbonCredits::batch_transfer_carbon_credits(Origin::signed(owner), transfers);
+        let check_event = Event::pallet_carbon_credits(crate::RawEvent::CarbonCreditsTransfered(owner, investor, 2));
+
+        assert_ok!(transfer_result, ());
+        assert_eq!(100, Assets::balance(1, investor));
+        assert_eq!(200, Assets::balance(1, standard_acc));
+        assert_eq!(300, Assets::balance(2, investor));
+        assert_eq!(TEST_CARBON_CREDITS_COUNT - 300, Assets::balance(1, owner));
+        assert_eq!(check_event, last_event().unwrap());
+    });
+}
+
+#[test]
+fn it_fails_batch_transfer_cc_rolls_back() {
+    new_test_ext().execute_with(|| {
+        let (_, project_id, owner) = full_sign_annual_report_gold_standard();
+        let investor = ROLES[4].0;
+        let asset_id = 1;
+        let _ = CarbonCredits::release_carbon_credits(Origin::signed(owner), project_id, asset_id, owner, 1);
+
+        // The last transfer exceeds balance
+        assert_noop!(
+            CarbonCredits::batch_transfer_carbon_credits(Origin::signed(owner), vec![(asset_id, investor, 100), (asset_id, investor, TEST_CARBON_CREDITS_COUNT)]),
+            RuntimeError::TransferFailed
+        );
+        // Asset without passport
+        assert_noop!(
+            CarbonCredits::batch_transfer_carbon_credits(Origin::signed(owner), vec![(asset_id, investor, 100), (asset_id + 1, investor, 100)]),
+            RuntimeError::PassportNotExist
+        );
+        assert_eq!(0, Assets::balance(asset_id, investor));
+    });
+}
+
+#[test]
+fn it_fails_batch_transfer_cc_bad_length() {
+    new_test_ext().execute_with(|| {
+        let (_, project_id, owner) = full_sign_annual_report_gold_standard();
+        let investor = ROLES[4].0;
+        let asset_id = 1;
+        let _ = CarbonCredits::release_carbon_credits(Origin::signed(owner), project_id, asset_id, owner, 1);
+
+        assert_noop!(
+            CarbonCredits::batch_transfer_carbon_credits(Origin::signed(owner), Vec::new()),
+            RuntimeError::InvalidTransferBatch
+        );
+        assert_noop!(
+            CarbonCredits::batch_transfer_carbon_credits(Origin::signed(owner), vec![(asset_id, investor, 1); crate::MAX_BATCH_TRANSFERS + 1]),
+            RuntimeError::InvalidTransferBatch
+        );
+    });
+}
+
 // // CC burn tests:
 #[test]
 fn it_works_for_burn_cc() {
@@ -167,4 +951,238 @@ fn it_fails_for_burn_cc_not_enough() {
         assert_noop!(burn_result, RuntimeError::InsufficientCarbonCredits);
         assert_eq!(Assets::balance(asset_id, owner), TEST_CARBON_CREDITS_COUNT);
     });
-}
\ No newline at end of file
+}
+#[test]
+fn it_works_release_annual_report_carbon_credits_by_index() {
+    new_test_ext().execute_with(|| {
+        let (project_id, owner) = get_registerd_project_with_two_concurrent_annual_reports();
+        let asset_id = 1;
+        full_sign_annual_report_by_index(project_id, 0);
+
+        let release_call = CarbonCredits::release_annual_report_carbon_credits(Origin::signed(owner), project_id, 0, asset_id, owner, 1);
+        let passport = CarbonCredits::get_passport_by_assetid(asset_id).unwrap();
+        let project = CarbonCredits::get_proj_by_id(project_id).unwrap();
+
+        assert_ok!(release_call, ());
+        assert_eq!(TEST_CARBON_CREDITS_COUNT, Assets::balance(asset_id, owner));
+        assert_eq!(1, passport.get_annual_report_index());
+        assert!(CarbonCredits::get_annual_report(project.id, 0).unwrap().is_carbon_credits_released());
+        assert!(!CarbonCredits::get_annual_report(project.id, 1).unwrap().is_carbon_credits_released());
+    });
+}
+
+#[test]
+fn it_fails_release_annual_report_carbon_credits_not_issued() {
+    new_test_ext().execute_with(|| {
+        let (project_id, owner) = get_registerd_project_with_two_concurrent_annual_reports();
+        let asset_id = 1;
+        full_sign_annual_report_by_index(project_id, 0);
+
+        let release_call = CarbonCredits::release_annual_report_carbon_credits(Origin::signed(owner), project_id, 1, asset_id, owner, 1);
+        let project = CarbonCredits::get_proj_by_id(project_id).unwrap();
+
+        assert_noop!(release_call, RuntimeError::AnnualReportNotReady);
+        assert!(CarbonCredits::get_passport_by_assetid(asset_id).is_none());
+        assert!(!CarbonCredits::get_annual_report(project.id, 1).unwrap().is_carbon_credits_released());
+    });
+}
+
+#[test]
+fn it_works_release_cc_deposit_event_with_vintage() {
+    new_test_ext_with_event().execute_with(|| {
+        let (_, project_id, owner) = full_sign_annual_report_gold_standard();
+        let asset_id = 1;
+        let _ = CarbonCredits::release_carbon_credits(Origin::signed(owner), project_id, asset_id, owner, 1);
+        let check_event = Event::pallet_carbon_credits(crate::RawEvent::CarbonCreditsMinted(owner, project_id, asset_id, TEST_VINTAGE));
+
+        assert_eq!(check_event, last_event().unwrap());
+    });
+}
+
+#[test]
+fn it_fails_release_cc_monitoring_period_not_ended() {
+    new_test_ext().execute_with(|| {
+        let (project_id, owner) = get_registerd_project_with_two_concurrent_annual_reports();
+        let asset_id = 1;
+        full_sign_annual_report_by_index(project_id, 1);
+        // Move time back to the middle of the second monitoring period
+        Timestamp::set_timestamp(get_test_monitoring_period(1).start + 1);
+
+        let release_call = CarbonCredits::release_annual_report_carbon_credits(Origin::signed(owner), project_id, 1, asset_id, owner, 1);
+
+        assert_noop!(release_call, RuntimeError::MonitoringPeriodNotEnded);
+        assert!(CarbonCredits::get_passport_by_assetid(asset_id).is_none());
+        assert!(!CarbonCredits::get_annual_report(project_id, 1).unwrap().is_carbon_credits_released());
+    });
+}
+
+#[test]
+fn it_works_retire_cc_with_certificate() {
+    new_test_ext_with_event().execute_with(|| {
+        let (_, project_id, owner) = full_sign_annual_report_gold_standard();
+        let asset_id = 1;
+        let burn_amount = 20;
+        let beneficiary_name = b"Green Corp".to_vec();
+        let reason = b"Offsetting 2021 business travel".to_vec();
+        let _ = CarbonCredits::release_carbon_credits(Origin::signed(owner), project_id, asset_id, owner, 1);
+
+        let retire_result = CarbonCredits::retire_carbon_credits(Origin::signed(owner), asset_id, burn_amount, beneficiary_name.clone(), reason.clone());
+        let certificate = CarbonCredits::get_retirement_certificate(1).unwrap();
+        let check_event = Event::pallet_carbon_credits(crate::RawEvent::RetirementCertificateIssued(owner, owner, 1));
+
+        assert_ok!(retire_result, ());
+        assert_eq!(Assets::balance(asset_id, owner), TEST_CARBON_CREDITS_COUNT - burn_amount);
+        assert_eq!(owner, certificate.retired_by);
+        assert_eq!(owner, certificate.beneficiary);
+        assert_eq!(beneficiary_name, certificate.beneficiary_name);
+        assert_eq!(reason, certificate.reason);
+        assert_eq!(asset_id, certificate.asset_id);
+        assert_eq!(burn_amount, certificate.amount);
+        assert_eq!(vec![SerialRange::new(1, burn_amount)], certificate.serial_ranges);
+        assert_eq!(project_id, certificate.project_id);
+        assert_eq!(0, certificate.annual_report_index);
+        assert_eq!(TEST_GENESIS_TIMESTAMP, certificate.timestamp);
+        assert_eq!(System::block_number(), certificate.block_number);
+        assert_eq!(vec![certificate], CarbonCredits::get_retirement_certificates_by_account(owner));
+        assert_eq!(check_event, last_event().unwrap());
+    });
+}
+
+#[test]
+fn it_works_burn_cc_separate_certificates() {
+    new_test_ext().execute_with(|| {
+        let (_, project_id, owner) = full_sign_annual_report_gold_standard();
+        let asset_id = 1;
+        let _ = CarbonCredits::release_carbon_credits(Origin::signed(owner), project_id, asset_id, owner, 1);
+
+        let _ = CarbonCredits::burn_carbon_credits(Origin::signed(owner), asset_id, 20);
+        let _ = CarbonCredits::burn_carbon_credits(Origin::signed(owner), asset_id, 15);
+        let certificates = CarbonCredits::get_retirement_certificates_by_account(owner);
+
+        assert_eq!(2, certificates.len());
+        assert_eq!((1, 20), (certificates[0].id, certificates[0].amount));
+        assert_eq!((2, 15), (certificates[1].id, certificates[1].amount));
+        assert_eq!(vec![SerialRange::new(21, 35)], certificates[1].serial_ranges);
+        assert_eq!(vec![SerialRange::new(36, TEST_CARBON_CREDITS_COUNT)], CarbonCredits::serial_ranges(asset_id, owner));
+        assert_eq!(35, CarbonCredits::get_certificates_by_account(owner)[0].burn_amount);
+    });
+}
+
+#[test]
+fn it_works_burn_cc_certificate_with_burned_dust() {
+    new_test_ext().execute_with(|| {
+        let (_, project_id, owner) = full_sign_annual_report_gold_standard();
+        let asset_id = 1;
+        let min_balance = 10;
+        let _ = CarbonCredits::release_carbon_credits(Origin::signed(owner), project_id, asset_id, owner, min_balance);
+
+        // Remaining 5 carbon credits are below min balance
+        assert_ok!(CarbonCredits::burn_carbon_credits(Origin::signed(owner), asset_id, TEST_CARBON_CREDITS_COUNT - 5));
+        let burned = TEST_CARBON_CREDITS_COUNT - Assets::balance(asset_id, owner);
+        let certificate = CarbonCredits::get_retirement_certificate(1).unwrap();
+
+        assert_eq!(burned, certificate.amount);
+        assert_eq!(vec![SerialRange::new(1, burned)], certificate.serial_ranges);
+        assert_eq!(burned, CarbonCredits::get_project_carbon_credits_totals(project_id).retired);
+    });
+}
+
+#[test]
+fn it_works_get_cc_serial_ranges() {
+    new_test_ext().execute_with(|| {
+        let (_, project_id, owner) = full_sign_annual_report_gold_standard();
+        let asset_id = 1;
+        let investor = ROLES[4].0;
+        let _ = CarbonCredits::release_carbon_credits(Origin::signed(owner), project_id, asset_id, owner, 1);
+        assert_ok!(CarbonCredits::transfer_carbon_credits(Origin::signed(owner), asset_id, investor, 100));
+
+        let serial_ranges = CarbonCredits::get_carbon_credits_serial_ranges(asset_id, investor).unwrap();
+        let passport = CarbonCredits::get_passport_by_assetid(asset_id).unwrap();
+
+        assert_eq!(project_id, serial_ranges.project_id);
+        assert_eq!(0, serial_ranges.annual_report_index);
+        assert_eq!(passport.get_vintage(), serial_ranges.vintage);
+        assert_eq!(vec![SerialRange::new(1, 100)], serial_ranges.ranges);
+        assert!(CarbonCredits::get_carbon_credits_serial_ranges(asset_id + 1, investor).is_none());
+    });
+}
+
+#[test]
+fn it_works_get_cc_asset_info() {
+    new_test_ext().execute_with(|| {
+        let (_, project_id, owner) = full_sign_annual_report_gold_standard();
+        let asset_id = 1;
+        let _ = CarbonCredits::release_carbon_credits(Origin::signed(owner), project_id, asset_id, owner, 1);
+        let _ = CarbonCredits::burn_carbon_credits(Origin::signed(owner), asset_id, 20);
+        let _ = CarbonCredits::burn_carbon_credits(Origin::signed(owner), asset_id, 15);
+
+        let info = CarbonCredits::get_carbon_credits_asset_info(asset_id).unwrap();
+        let report = CarbonCredits::get_annual_report(project_id, 0).unwrap();
+
+        assert_eq!(CarbonCredits::get_passport_by_assetid(asset_id).unwrap(), info.passport);
+        assert_eq!(CarbonCredits::get_proj_by_id(project_id).unwrap(), info.project);
+        assert_eq!(0, info.annual_report_index);
+        assert_eq!(report.file_id, info.annual_report_file_id);
+        assert_eq!(TEST_CARBON_CREDITS_COUNT, info.carbon_credits_count);
+        assert_eq!(report.monitoring_period.vintage, info.vintage);
+        assert_eq!(35, info.burned_carbon_credits);
+        assert!(CarbonCredits::get_carbon_credits_asset_info(asset_id + 1).is_none());
+    });
+}
+
+#[test]
+fn it_fails_retire_cc_too_long_reason() {
+    new_test_ext().execute_with(|| {
+        let (_, project_id, owner) = full_sign_annual_report_gold_standard();
+        let asset_id = 1;
+        let _ = CarbonCredits::release_carbon_credits(Origin::signed(owner), project_id, asset_id, owner, 1);
+
+        let retire_result = CarbonCredits::retire_carbon_credits(Origin::signed(owner), asset_id, 20, b"Green Corp".to_vec(), vec![0x41; 257]);
+
+        assert_noop!(retire_result, RuntimeError::BadRetirementParameters);
+        assert!(CarbonCredits::get_retirement_certificate(1).is_none());
+    });
+}
+
+#[test]
+fn it_works_burn_cc_for_beneficiary() {
+    new_test_ext_with_event().execute_with(|| {
+        let (_, project_id, owner) = full_sign_annual_report_gold_standard();
+        let beneficiary = ROLES[4].0;
+        let asset_id = 1;
+        let burn_amount = 20;
+        let _ = CarbonCredits::release_carbon_credits(Origin::signed(owner), project_id, asset_id, owner, 1);
+
+        let burn_result = CarbonCredits::burn_carbon_credits_for(Origin::signed(owner), asset_id, burn_amount, beneficiary);
+        let certificate = CarbonCredits::get_retirement_certificate(1).unwrap();
+        let check_event = Event::pallet_carbon_credits(crate::RawEvent::RetirementCertificateIssued(owner, beneficiary, 1));
+
+        assert_ok!(burn_result, ());
+        assert_eq!(Assets::balance(asset_id, owner), TEST_CARBON_CREDITS_COUNT - burn_amount);
+        assert_eq!(0, Assets::balance(asset_id, beneficiary));
+        assert_eq!(owner, certificate.retired_by);
+        assert_eq!(beneficiary, certificate.beneficiary);
+        assert_eq!(vec![certificate], CarbonCredits::get_retirement_certificates_by_account(beneficiary));
+        assert!(CarbonCredits::get_retirement_certificates_by_account(owner).is_empty());
+        assert_eq!(burn_amount, CarbonCredits::get_certificates_by_account(beneficiary)[0].burn_amount);
+        assert!(CarbonCredits::get_certificates_by_account(owner).is_empty());
+        assert_eq!(check_event, last_event().unwrap());
+    });
+}
+
+#[test]
+fn it_fails_burn_cc_for_beneficiary_not_enough() {
+    new_test_ext().execute_with(|| {
+        let (_, project_id, owner) = full_sign_annual_report_gold_standard();
+        let beneficiary = ROLES[4].0;
+        let asset_id = 1;
+        let _ = CarbonCredits::release_carbon_credits(Origin::signed(owner), project_id, asset_id, owner, 1);
+        let _ = CarbonCredits::transfer_carbon_credits(Origin::signed(owner), asset_id, beneficiary, 100);
+
+        // Beneficiary holds carbon credits, but the caller burns only own ones
+        let burn_result = CarbonCredits::burn_carbon_credits_for(Origin::signed(owner), asset_id, TEST_CARBON_CREDITS_COUNT, beneficiary);
+
+        assert_noop!(burn_result, RuntimeError::InsufficientCarbonCredits);
+        assert!(CarbonCredits::get_retirement_certificates_by_account(beneficiary).is_empty());
+    });
+}
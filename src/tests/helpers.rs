@@ -2,11 +2,18 @@ use crate::tests::mock::*;
 use pallet_evercity_filesign::file::{H256, FileId};
 use crate::standard::Standard;
 use crate::project::{ProjectId, ProjectStruct};
+use crate::project_metadata::ProjectMetadata;
 use crate::annual_report::*;
 use sp_std::vec;
 
 pub const TEST_CARBON_CREDITS_COUNT: u64 = 15000;
 pub const TEST_CARBON_CREDITS_DECIMAL: u8 = 0;
+// 2019-01-01, milliseconds since the unix epoch
+pub const TEST_MONITORING_PERIOD_START: u64 = 1_546_300_800_000;
+pub const TEST_MONITORING_PERIOD_DURATION: u64 = 365 * 24 * 60 * 60 * 1000;
+pub const TEST_VINTAGE: u16 = 2019;
+pub const TEST_CREDITING_PERIOD_START: u64 = TEST_MONITORING_PERIOD_START;
+pub const TEST_CREDITING_PERIOD_END: u64 = TEST_CREDITING_PERIOD_START + 7 * TEST_MONITORING_PERIOD_DURATION;
 
 
 pub(crate) fn create_user_with_owner_role() -> u64 {
@@ -23,6 +30,16 @@ pub(crate) fn get_test_carbon_credits_symbol() -> Vec<u8> {
     "CT".to_owned().as_bytes().to_vec()
 }
 
+/// Yearly monitoring period, the following one for every next index
+pub(crate) fn get_test_monitoring_period(index: u64) -> MonitoringPeriod<u64> {
+    let start = TEST_MONITORING_PERIOD_START + index * TEST_MONITORING_PERIOD_DURATION;
+    MonitoringPeriod::new(start, start + TEST_MONITORING_PERIOD_DURATION, TEST_VINTAGE + index as u16)
+}
+
+pub(crate) fn get_test_project_metadata() -> ProjectMetadata {
+    ProjectMetadata::new(*b"BR", -23_550_520, -46_633_309, b"AMS-I.D.".to_vec(), b"Renewable Energy".to_vec(), 50_000, 7)
+}
+
 pub(crate) fn create_project_documentation_file(account: u64) -> Option<FileId> {
     let filehash = H256::from([0x66; 32]);
     let file_id = Some([6; 16]);
@@ -38,18 +55,22 @@ pub(crate) fn create_annual_report_file(account: u64) -> FileId {
 }
 
 /// Return tuple -> (project, project_id, project_owner)
-pub(crate) fn get_registerd_project_and_owner_gold_standard() -> (ProjectStruct<u64, TestRuntime, Balance>, ProjectId, u64) {
-    get_project_and_owner_and_custom_signers(assign_project_mock_users_required_signers_gold_standard)
+pub(crate) fn get_registerd_project_and_owner_gold_standard() -> (ProjectStruct<u64>, ProjectId, u64) {
+    get_project_and_owner_and_custom_signers(Standard::GOLD_STANDARD, assign_project_mock_users_required_signers_gold_standard)
+}
+
+/// Return tuple -> (project, project_id, project_owner)
+pub(crate) fn get_registerd_project_and_owner_verra() -> (ProjectStruct<u64>, ProjectId, u64) {
+    get_project_and_owner_and_custom_signers(Standard::VERRA_VCS, assign_project_mock_users_required_signers_gold_standard)
 }
 
-pub(crate) fn get_project_and_owner_and_custom_signers<F>(sign_func: F) -> (ProjectStruct<u64, TestRuntime, Balance>, ProjectId, u64) where F: Fn(ProjectId) {
+pub(crate) fn get_project_and_owner_and_custom_signers<F>(standard: Standard, sign_func: F) -> (ProjectStruct<u64>, ProjectId, u64) where F: Fn(ProjectId) {
     let owner = ROLES[1].0;
     let auditor = ROLES[2].0;
     let standard_acc = ROLES[3].0;
     let registry = ROLES[5].0;
-    let standard = Standard::GOLD_STANDARD;
 
-    let _ = CarbonCredits::create_project(Origin::signed(owner), standard, create_project_documentation_file(owner));
+    let _ = CarbonCredits::create_project(Origin::signed(owner), standard, create_project_documentation_file(owner), get_test_project_metadata(), TEST_CREDITING_PERIOD_START, TEST_CREDITING_PERIOD_END);
     sign_func(1);
 
     let _ = CarbonCredits::sign_project(Origin::signed(owner), 1);
@@ -62,18 +83,18 @@ pub(crate) fn get_project_and_owner_and_custom_signers<F>(sign_func: F) -> (Proj
 }
 
 /// Return tuple -> (project, project_id, project_owner)
-pub(crate) fn full_sign_annual_report_gold_standard() -> (ProjectStruct<u64, TestRuntime, Balance>, ProjectId, u64) {
+pub(crate) fn full_sign_annual_report_gold_standard() -> (ProjectStruct<u64>, ProjectId, u64) {
     get_annual_report_and_owner_custom_signers(assign_annual_report_mock_users_required_signers_gold_standard)
 }
 
-pub(crate) fn get_annual_report_and_owner_custom_signers<F>(sign_func: F) -> (ProjectStruct<u64, TestRuntime, Balance>, ProjectId, u64) where F: Fn(ProjectId) {
+pub(crate) fn get_annual_report_and_owner_custom_signers<F>(sign_func: F) -> (ProjectStruct<u64>, ProjectId, u64) where F: Fn(ProjectId) {
     let (project, proj_id, owner) = get_registerd_project_and_owner_gold_standard();
     let auditor = ROLES[2].0;
     let standard_acc = ROLES[3].0;
     let registry = ROLES[5].0;
 
     let _ = CarbonCredits::create_annual_report(
-        Origin::signed(owner), proj_id, create_annual_report_file(owner), TEST_CARBON_CREDITS_COUNT, get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL
+        Origin::signed(owner), proj_id, create_annual_report_file(owner), TEST_CARBON_CREDITS_COUNT, get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, get_test_monitoring_period(0)
     );
     sign_func(proj_id);
 
@@ -102,6 +123,25 @@ pub(crate) fn get_annual_report_and_owner_custom_signers<F>(sign_func: F) -> (Pr
     (project, 1, owner)
 }
 
+/// Return tuple -> (project, project_id, project_owner)
+pub(crate) fn full_sign_annual_report_verra() -> (ProjectStruct<u64>, ProjectId, u64) {
+    let (project, proj_id, owner) = get_registerd_project_and_owner_verra();
+    let auditor = ROLES[2].0;
+    let registry = ROLES[5].0;
+
+    let _ = CarbonCredits::create_annual_report(
+        Origin::signed(owner), proj_id, create_annual_report_file(owner), TEST_CARBON_CREDITS_COUNT, get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, get_test_monitoring_period(0)
+    );
+    assign_annual_report_mock_users_required_signers_verra(proj_id);
+
+    vec![owner, auditor, registry].iter()
+        .for_each(|acc| {
+            let _ = CarbonCredits::sign_last_annual_report(Origin::signed(*acc), proj_id);
+        });
+
+    (project, proj_id, owner)
+}
+
 pub(crate) fn assign_project_mock_users_required_signers_gold_standard(project_id: ProjectId) {
     let owner = ROLES[1].0;
     let _ = CarbonCredits::assign_project_signer(Origin::signed(owner), ROLES[1].0, ROLES[1].1, project_id);
@@ -116,4 +156,79 @@ pub(crate) fn assign_annual_report_mock_users_required_signers_gold_standard(pro
     let _ = CarbonCredits::assign_last_annual_report_signer(Origin::signed(owner), ROLES[2].0, ROLES[2].1, project_id);
     let _ = CarbonCredits::assign_last_annual_report_signer(Origin::signed(owner), ROLES[3].0, ROLES[3].1, project_id);
     let _ = CarbonCredits::assign_last_annual_report_signer(Origin::signed(owner), ROLES[5].0, ROLES[5].1, project_id);
-}
\ No newline at end of file
+}
+
+pub(crate) fn assign_annual_report_mock_users_required_signers_verra(project_id: ProjectId) {
+    let owner = ROLES[1].0;
+    let _ = CarbonCredits::assign_last_annual_report_signer(Origin::signed(owner), ROLES[1].0, ROLES[1].1, project_id);
+    let _ = CarbonCredits::assign_last_annual_report_signer(Origin::signed(owner), ROLES[2].0, ROLES[2].1, project_id);
+    let _ = CarbonCredits::assign_last_annual_report_signer(Origin::signed(owner), ROLES[5].0, ROLES[5].1, project_id);
+}
+
+pub(crate) fn assign_project_mock_users_required_signers_with_investor(project_id: ProjectId) {
+    let owner = ROLES[1].0;
+    assign_project_mock_users_required_signers_gold_standard(project_id);
+    let _ = CarbonCredits::assign_project_signer(Origin::signed(owner), ROLES[4].0, ROLES[4].1, project_id);
+}
+
+pub(crate) fn assign_annual_report_mock_users_required_signers_with_investor(project_id: ProjectId) {
+    let owner = ROLES[1].0;
+    assign_annual_report_mock_users_required_signers_gold_standard(project_id);
+    let _ = CarbonCredits::assign_last_annual_report_signer(Origin::signed(owner), ROLES[4].0, ROLES[4].1, project_id);
+}
+
+/// Return tuple -> (project, project_id, project_owner)
+pub(crate) fn get_registerd_project_with_investor_and_owner_gold_standard() -> (ProjectStruct<u64>, ProjectId, u64) {
+    let owner = ROLES[1].0;
+    let _ = CarbonCredits::create_project(Origin::signed(owner), Standard::GOLD_STANDARD, create_project_documentation_file(owner), get_test_project_metadata(), TEST_CREDITING_PERIOD_START, TEST_CREDITING_PERIOD_END);
+    let _ = CarbonCredits::set_project_investor_sign_required(Origin::signed(owner), 1, true);
+    assign_project_mock_users_required_signers_with_investor(1);
+
+    vec![ROLES[1].0, ROLES[2].0, ROLES[3].0, ROLES[4].0, ROLES[5].0].iter()
+        .for_each(|acc| {
+            let _ = CarbonCredits::sign_project(Origin::signed(*acc), 1);
+        });
+    let project = CarbonCredits::get_proj_by_id(1).unwrap();
+
+    (project, 1, owner)
+}
+pub(crate) fn create_annual_report_file_with_id(account: u64, file_id: FileId) -> FileId {
+    let filehash = H256::from([0x89; 32]);
+    let _ = EvercityFilesign::create_new_file(Origin::signed(account), "my_concurrent_annual_report".to_owned().as_bytes().to_vec(), filehash, Some(file_id));
+    file_id
+}
+
+pub(crate) fn assign_annual_report_mock_users_required_signers_by_index(project_id: ProjectId, report_index: u64) {
+    let owner = ROLES[1].0;
+    vec![ROLES[1], ROLES[2], ROLES[3], ROLES[5]].iter()
+        .for_each(|(acc, role)| {
+            let _ = CarbonCredits::assign_annual_report_signer(Origin::signed(owner), *acc, *role, project_id, report_index);
+        });
+}
+
+pub(crate) fn full_sign_annual_report_by_index(project_id: ProjectId, report_index: u64) {
+    vec![ROLES[1].0, ROLES[2].0, ROLES[3].0, ROLES[5].0].iter()
+        .for_each(|acc| {
+            let _ = CarbonCredits::sign_annual_report(Origin::signed(*acc), project_id, report_index);
+        });
+}
+
+/// Gold standard, that allows concurrent annual reports, with two annual reports created in the project
+/// Return tuple -> (project_id, project_owner)
+pub(crate) fn get_registerd_project_with_two_concurrent_annual_reports() -> (ProjectId, u64) {
+    let definition = Standard::GOLD_STANDARD.default_definition().unwrap().with_concurrent_reports();
+    let _ = CarbonCredits::set_standard_definition(Origin::root(), Standard::GOLD_STANDARD, definition);
+    let (_, project_id, owner) = get_registerd_project_and_owner_gold_standard();
+
+    vec![create_annual_report_file(owner), create_annual_report_file_with_id(owner, [10; 16])].iter()
+        .enumerate()
+        .for_each(|(report_index, file_id)| {
+            let _ = CarbonCredits::create_annual_report(
+                Origin::signed(owner), project_id, *file_id, TEST_CARBON_CREDITS_COUNT,
+                get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, get_test_monitoring_period(report_index as u64)
+            );
+            assign_annual_report_mock_users_required_signers_by_index(project_id, report_index as u64);
+        });
+
+    (project_id, owner)
+}
@@ -0,0 +1,159 @@
+use crate::tests::mock::*;
+use frame_support::{
+    codec::{Compact, Encode},
+    storage::{unhashed, StorageValue, StorageMap},
+    traits::OnRuntimeUpgrade,
+};
+use crate::standard::Standard;
+use crate::project::*;
+use crate::annual_report::*;
+use crate::migration::{Releases, ProjectStructV1, AnnualReportStructV1, CarbonCreditsPassportV1, ProjectStatusV1, StandardV1, CarbonCreditsMetaV1};
+use crate::tests::helpers::*;
+use sp_std::vec;
+
+fn get_test_annual_report_v1(file_id: [u8; 16], state: AnnualReportStateMask) -> AnnualReportStructV1<u64, u64, Balance> {
+    AnnualReportStructV1 {
+        file_id,
+        state,
+        carbon_credits_meta: CarbonCreditsMetaV1 {
+            name: get_test_carbon_credits_name(),
+            symbol: get_test_carbon_credits_symbol(),
+            decimals: TEST_CARBON_CREDITS_DECIMAL,
+        },
+        create_time: 0,
+        carbon_credits_count: TEST_CARBON_CREDITS_COUNT,
+        carbon_credits_released: state == REPORT_ISSUED,
+        required_signers: vec![(ROLES[1].0, ROLES[1].1)],
+    }
+}
+
+#[test]
+fn it_works_migrate_annual_reports_to_separate_storage() {
+    new_test_ext().execute_with(|| {
+        let owner = ROLES[1].0;
+        let project_v1 = ProjectStructV1 {
+            owner,
+            id: 1,
+            status: ProjectStatusV1::ISSUANCE,
+            state: REGISTERED,
+            file_id: Some([6; 16]),
+            annual_reports: vec![
+                get_test_annual_report_v1([9; 16], REPORT_ISSUED),
+                get_test_annual_report_v1([10; 16], REPORT_AUDITOR_SIGN_PENDING),
+            ],
+            required_signers: vec![(owner, ROLES[1].1)],
+            standard: StandardV1::GOLD_STANDARD,
+        };
+        let passport_v1 = CarbonCreditsPassportV1 {
+            asset_id: 1_u32,
+            project_id: 1,
+            // Passport of the first released report
+            annual_report_index: 1,
+        };
+        unhashed::put(&crate::ProjectById::<TestRuntime>::hashed_key_for(1), &project_v1);
+        unhashed::put(&crate::CarbonCreditPassportRegistry::<TestRuntime>::hashed_key_for(1), &passport_v1);
+        crate::StorageVersion::put(Releases::V1);
+
+        let _ = CarbonCredits::on_runtime_upgrade();
+        let project = CarbonCredits::get_proj_by_id(1).unwrap();
+        let reports = CarbonCredits::get_annual_reports(1);
+        let passport = CarbonCredits::get_passport_by_assetid(1).unwrap();
+
+        assert_eq!(Releases::V2, CarbonCredits::storage_version());
+        assert_eq!(2, project.annual_reports_count);
+        assert_eq!(2, project.live_annual_reports_count);
+        assert_eq!(owner, project.owner);
+        assert_eq!(REGISTERED, project.state);
+        assert!(project.is_required_signer((owner, ROLES[1].1)));
+        assert!(!project.is_investor_sign_required());
+        assert_eq!(Standard::GOLD_STANDARD.default_definition().as_ref(), project.get_standard_definition());
+        assert_eq!(2, reports.len());
+        assert_eq!([9; 16], reports[0].file_id);
+        assert!(reports[0].is_carbon_credits_released());
+        assert_eq!(REPORT_AUDITOR_SIGN_PENDING, reports[1].state);
+        assert_eq!(TEST_CARBON_CREDITS_COUNT, reports[1].carbon_credits_count());
+        assert_eq!(0, reports[1].rejections_count);
+        assert_eq!(MonitoringPeriod::default(), reports[0].monitoring_period);
+        assert_eq!(1, passport.get_annual_report_index());
+        assert_eq!(&MonitoringPeriod::default(), passport.get_monitoring_period());
+        assert_eq!(TEST_CARBON_CREDITS_COUNT, reports[0].carbon_credits_issued());
+        assert_eq!(0, reports[1].carbon_credits_issued());
+        assert_eq!(TEST_CARBON_CREDITS_COUNT, passport.get_carbon_credits_issued());
+        assert_eq!(0, passport.get_remaining_carbon_credits());
+        assert_eq!(vec![1], CarbonCredits::get_projects_by_owner(owner).iter().map(|project| project.id).collect::<Vec<_>>());
+        assert_eq!(vec![1], CarbonCredits::get_projects_by_state(REGISTERED));
+        assert_eq!(vec![1], CarbonCredits::get_projects_by_signer(owner));
+        assert_eq!(Some(1), CarbonCredits::annual_report_asset(1, 0));
+        assert_eq!(TEST_CARBON_CREDITS_COUNT, CarbonCredits::get_project_carbon_credits_totals(1).issued);
+        assert_eq!(TEST_CARBON_CREDITS_COUNT, CarbonCredits::get_vintage_carbon_credits_totals(UNKNOWN_VINTAGE).issued);
+        assert_eq!(TEST_CARBON_CREDITS_COUNT, CarbonCredits::get_carbon_credits_total().issued);
+    });
+}
+
+#[test]
+fn it_works_migration_skipped_for_new_chain() {
+    new_test_ext().execute_with(|| {
+        let (_, project_id, owner) = full_sign_annual_report_gold_standard();
+        let version = CarbonCredits::storage_version();
+        let _ = CarbonCredits::on_runtime_upgrade();
+        let _ = CarbonCredits::create_annual_report(
+            Origin::signed(owner), project_id, create_annual_report_file(owner), TEST_CARBON_CREDITS_COUNT,
+            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, get_test_monitoring_period(1)
+        );
+
+        let project = CarbonCredits::get_proj_by_id(project_id).unwrap();
+
+        assert_eq!(Releases::V2, version);
+        assert_eq!(2, project.annual_reports_count);
+        assert_eq!(2, CarbonCredits::get_annual_reports(project_id).len());
+    });
+}
+
+#[test]
+fn it_works_migrate_baseline_encoded_storage() {
+    new_test_ext().execute_with(|| {
+        let owner = ROLES[1].0;
+        let create_time: u64 = 1_600_000_000_000;
+        // Project and passport, encoded field by field in the layout of the released storage version 1
+        let report_bytes = (
+            [9_u8; 16],
+            REPORT_ISSUED,
+            (get_test_carbon_credits_name(), get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL),
+            Compact(create_time),
+            TEST_CARBON_CREDITS_COUNT,
+            true,
+            vec![(owner, ROLES[1].1)],
+        ).encode();
+        let mut project_bytes = (owner, 1_u32, 2_u8, REGISTERED, Some([6_u8; 16])).encode();
+        project_bytes.extend(Compact(1_u32).encode());
+        project_bytes.extend(report_bytes);
+        project_bytes.extend((vec![(owner, ROLES[1].1)], 0_u8).encode());
+        let passport_bytes = (1_u32, 1_u32, 1_u64).encode();
+        unhashed::put_raw(&crate::ProjectById::<TestRuntime>::hashed_key_for(1), &project_bytes);
+        unhashed::put_raw(&crate::CarbonCreditPassportRegistry::<TestRuntime>::hashed_key_for(1), &passport_bytes);
+        crate::StorageVersion::put(Releases::V1);
+
+        let _ = CarbonCredits::on_runtime_upgrade();
+        let project = CarbonCredits::get_proj_by_id(1).unwrap();
+        let report = CarbonCredits::get_annual_report(1, 0).unwrap();
+        let passport = CarbonCredits::get_passport_by_assetid(1).unwrap();
+
+        assert_eq!(owner, project.owner);
+        assert_eq!(ProjectStatus::ISSUANCE, project.status);
+        assert_eq!(REGISTERED, project.state);
+        assert_eq!(Some([6; 16]), project.file_id);
+        assert_eq!(&Standard::GOLD_STANDARD, project.get_standard());
+        assert!(project.is_required_signer((owner, ROLES[1].1)));
+        assert_eq!(1, project.annual_reports_count);
+        assert_eq!([9; 16], report.file_id);
+        assert_eq!(REPORT_ISSUED, report.state);
+        assert_eq!(get_test_carbon_credits_name(), report.carbon_credits_meta.name);
+        assert_eq!(get_test_carbon_credits_symbol(), report.carbon_credits_meta.symbol);
+        assert_eq!(TEST_CARBON_CREDITS_COUNT, report.carbon_credits_count());
+        assert!(report.is_carbon_credits_released());
+        assert!(report.is_required_signer((owner, ROLES[1].1)));
+        assert_eq!(1, passport.get_project_id());
+        assert_eq!(1, passport.get_annual_report_index());
+        assert_eq!(TEST_CARBON_CREDITS_COUNT, passport.get_carbon_credits_issued());
+    });
+}
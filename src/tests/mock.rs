@@ -1,6 +1,7 @@
 #![allow(clippy::from_over_into)]
 
 use frame_support::sp_runtime::{
+    ModuleId,
     testing::Header,
     traits::{BlakeTwo256, IdentityLookup},
 };
@@ -23,7 +24,7 @@ frame_support::construct_runtime!(
 	{
 		System: frame_system::{ Module, Call, Config, Storage, Event<T> },
         Balances: pallet_balances::{Module, Call, Storage, Config<T>, Event<T>},
-		CarbonCredits: pallet_carbon_credits::{ Module, Call, Storage, Event<T> },
+		CarbonCredits: pallet_carbon_credits::{ Module, Call, Storage, Config, Event<T> },
 		EvercityAccounts: pallet_evercity_accounts::{ Module, Call, Storage, Event<T> },
 		Timestamp: pallet_timestamp::{ Module, Call, Storage, Inherent},
         Assets: pallet_assets::{ Module, Call, Storage, Event<T> },
@@ -59,8 +60,14 @@ impl frame_system::Config for TestRuntime {
 	type SS58Prefix = ();
 }
 
+parameter_types! {
+    pub const CarbonCreditsModuleId: ModuleId = ModuleId(*b"ev/carbn");
+}
+
 impl pallet_carbon_credits::Config for TestRuntime {
 	type Event = Event;
+	type ModuleId = CarbonCreditsModuleId;
+	type WeightInfo = ();
 }
 
 impl pallet_evercity_accounts::Config for TestRuntime {
@@ -136,6 +143,9 @@ pub static ROLES: [(u64, RoleMask); 6] = [
     (6_u64, CC_REGISTRY_ROLE_MASK),
 ];
 
+// 2022-01-01, milliseconds since the unix epoch
+pub const TEST_GENESIS_TIMESTAMP: u64 = 1_640_995_200_000;
+
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> frame_support::sp_io::TestExternalities {
     let mut t = frame_system::GenesisConfig::default()
@@ -144,7 +154,10 @@ pub fn new_test_ext() -> frame_support::sp_io::TestExternalities {
 
     pallet_balances::GenesisConfig::<TestRuntime> {
         // Provide some initial balances
-        balances: ROLES.iter().map(|x| (x.0, 10000000)).collect(),
+        // Buffer pool account must exist to hold carbon credits
+        balances: ROLES.iter().map(|x| (x.0, 10000000))
+            .chain(sp_std::iter::once((CarbonCredits::buffer_pool_account_id(), 1)))
+            .collect(),
     }
     .assimilate_storage(&mut t)
     .unwrap();
@@ -165,7 +178,14 @@ pub fn new_test_ext() -> frame_support::sp_io::TestExternalities {
     }
     .assimilate_storage(&mut t)
     .unwrap();
-    t.into()
+
+    pallet_carbon_credits::GenesisConfig::default()
+        .assimilate_storage::<TestRuntime>(&mut t)
+        .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| Timestamp::set_timestamp(TEST_GENESIS_TIMESTAMP));
+    ext
 }
 
 // Build genesis storage for event testing
@@ -176,7 +196,10 @@ pub fn new_test_ext_with_event() -> frame_support::sp_io::TestExternalities {
 
     pallet_balances::GenesisConfig::<TestRuntime> {
         // Provide some initial balances
-        balances: ROLES.iter().map(|x| (x.0, 10000000)).collect(),
+        // Buffer pool account must exist to hold carbon credits
+        balances: ROLES.iter().map(|x| (x.0, 10000000))
+            .chain(sp_std::iter::once((CarbonCredits::buffer_pool_account_id(), 1)))
+            .collect(),
     }
     .assimilate_storage(&mut t)
     .unwrap();
@@ -198,8 +221,15 @@ pub fn new_test_ext_with_event() -> frame_support::sp_io::TestExternalities {
     .assimilate_storage(&mut t)
     .unwrap();
 
+    pallet_carbon_credits::GenesisConfig::default()
+        .assimilate_storage::<TestRuntime>(&mut t)
+        .unwrap();
+
 	let mut ext = sp_io::TestExternalities::new(t);
-	ext.execute_with(|| System::set_block_number(1));
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		Timestamp::set_timestamp(TEST_GENESIS_TIMESTAMP);
+	});
 	ext
 }
 
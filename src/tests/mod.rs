@@ -1,5 +1,7 @@
 pub mod project_tests;
 pub mod annual_report_tests;
 pub mod carbon_credits_tests;
+pub mod standard_tests;
+pub mod migration_tests;
 pub mod helpers;
 pub mod mock;
\ No newline at end of file
@@ -5,9 +5,11 @@ use frame_support::{assert_ok, assert_noop, dispatch::{
 use crate::standard::Standard;
 use pallet_evercity_accounts::accounts::*;
 use crate::project::*;
+use crate::project_metadata::ProjectMetadata;
 use crate::tests::helpers::*;
 use crate::Error;
 use sp_std::vec;
+use pallet_evercity_filesign::file::H256;
 
 type RuntimeError = Error<TestRuntime>;
 
@@ -16,7 +18,7 @@ fn it_works_get_unexisting_project_gold_standard() {
     new_test_ext().execute_with(|| {
         let owner = ROLES[1].0;
         let standard = Standard::default();
-        let _ = CarbonCredits::create_project(Origin::signed(owner), standard, create_project_documentation_file(owner));
+        let _ = CarbonCredits::create_project(Origin::signed(owner), standard, create_project_documentation_file(owner), get_test_project_metadata(), TEST_CREDITING_PERIOD_START, TEST_CREDITING_PERIOD_END);
         let option = CarbonCredits::get_proj_by_id(2);
         assert!(option.is_none())
     });
@@ -27,7 +29,7 @@ fn it_works_for_create_new_project_gold_standard() {
     new_test_ext().execute_with(|| {
         let owner = ROLES[1].0;
         let standard = Standard::default();
-        let create_project_result = CarbonCredits::create_project(Origin::signed(owner), standard.clone(), create_project_documentation_file(owner));
+        let create_project_result = CarbonCredits::create_project(Origin::signed(owner), standard.clone(), create_project_documentation_file(owner), get_test_project_metadata(), TEST_CREDITING_PERIOD_START, TEST_CREDITING_PERIOD_END);
         let project = CarbonCredits::get_proj_by_id(1).unwrap();
 
         assert_eq!(owner, project.owner);
@@ -37,12 +39,27 @@ fn it_works_for_create_new_project_gold_standard() {
     });
 }
 
+#[test]
+fn it_works_get_projects_by_owner() {
+    new_test_ext().execute_with(|| {
+        let owner = ROLES[1].0;
+        let other_owner = ROLES[0].0;
+        let _ = CarbonCredits::create_project(Origin::signed(owner), Standard::default(), None, get_test_project_metadata(), TEST_CREDITING_PERIOD_START, TEST_CREDITING_PERIOD_END);
+        let _ = CarbonCredits::create_project(Origin::signed(owner), Standard::default(), None, get_test_project_metadata(), TEST_CREDITING_PERIOD_START, TEST_CREDITING_PERIOD_END);
+
+        let projects = CarbonCredits::get_projects_by_owner(owner);
+
+        assert_eq!(vec![1, 2], projects.iter().map(|project| project.id).collect::<Vec<_>>());
+        assert!(CarbonCredits::get_projects_by_owner(other_owner).is_empty());
+    });
+}
+
 #[test]
 fn it_works_for_create_new_project_file_not_specified_gold_standard() {
     new_test_ext().execute_with(|| {
         let owner = ROLES[1].0;
         let standard = Standard::default();
-        let create_project_result = CarbonCredits::create_project(Origin::signed(owner), standard.clone(), None);
+        let create_project_result = CarbonCredits::create_project(Origin::signed(owner), standard.clone(), None, get_test_project_metadata(), TEST_CREDITING_PERIOD_START, TEST_CREDITING_PERIOD_END);
         let project = CarbonCredits::get_proj_by_id(1).unwrap();
 
         assert_eq!(owner, project.owner);
@@ -58,7 +75,7 @@ fn it_fails_for_create_new_project_not_owner_role_gold_standard() {
     new_test_ext().execute_with(|| {
         let auditor = ROLES[3].0;
         let standard = Standard::default();
-        let create_project_result = CarbonCredits::create_project(Origin::signed(auditor), standard, create_project_documentation_file(auditor));
+        let create_project_result = CarbonCredits::create_project(Origin::signed(auditor), standard, create_project_documentation_file(auditor), get_test_project_metadata(), TEST_CREDITING_PERIOD_START, TEST_CREDITING_PERIOD_END);
         let project_opt = CarbonCredits::get_proj_by_id(1);
 
         assert!(project_opt.is_none());
@@ -76,7 +93,7 @@ fn it_fails_for_create_new_project_no_file_gold_standard() {
         let auditor = ROLES[3].0;
         let standard = Standard::default();
         let other_owner_file_id = create_project_documentation_file(auditor);
-        let create_project_result = CarbonCredits::create_project(Origin::signed(owner), standard, other_owner_file_id);
+        let create_project_result = CarbonCredits::create_project(Origin::signed(owner), standard, other_owner_file_id, get_test_project_metadata(), TEST_CREDITING_PERIOD_START, TEST_CREDITING_PERIOD_END);
         let project_opt = CarbonCredits::get_proj_by_id(1);
 
         assert_ne!(create_project_result, DispatchResult::Ok(()));
@@ -94,7 +111,7 @@ fn it_fails_for_create_new_project_other_owner_file_gold_standard() {
         let owner = ROLES[1].0;
         let standard = Standard::default();
         let not_existing_file_id = Some([1,2,3,4,5,6,7,8,9,1,1,1,1,1,1,1]);
-        let create_project_result = CarbonCredits::create_project(Origin::signed(owner), standard, not_existing_file_id);
+        let create_project_result = CarbonCredits::create_project(Origin::signed(owner), standard, not_existing_file_id, get_test_project_metadata(), TEST_CREDITING_PERIOD_START, TEST_CREDITING_PERIOD_END);
         let project_opt = CarbonCredits::get_proj_by_id(1);
 
         assert_ne!(create_project_result, DispatchResult::Ok(()));
@@ -112,7 +129,7 @@ fn it_works_for_change_file_id() {
         let owner = ROLES[1].0;
         let standard = Standard::default();
         let not_existing_file_id = None;
-        let create_project_result = CarbonCredits::create_project(Origin::signed(owner), standard, not_existing_file_id);
+        let create_project_result = CarbonCredits::create_project(Origin::signed(owner), standard, not_existing_file_id, get_test_project_metadata(), TEST_CREDITING_PERIOD_START, TEST_CREDITING_PERIOD_END);
         let project_before_change = CarbonCredits::get_proj_by_id(1);
         let file_id = create_project_documentation_file(owner);
         let change_id_result = CarbonCredits::change_project_file_id(Origin::signed(owner), 1, file_id.unwrap());
@@ -132,7 +149,7 @@ fn it_fails_for_change_file_id_not_file_owner() {
         let auditor = ROLES[2].0;
         let standard = Standard::default();
         let not_existing_file_id = None;
-        let _ = CarbonCredits::create_project(Origin::signed(owner), standard, not_existing_file_id);
+        let _ = CarbonCredits::create_project(Origin::signed(owner), standard, not_existing_file_id, get_test_project_metadata(), TEST_CREDITING_PERIOD_START, TEST_CREDITING_PERIOD_END);
         let project_before_change = CarbonCredits::get_proj_by_id(1);
         let file_id = create_project_documentation_file(auditor);
         let change_id_result = CarbonCredits::change_project_file_id(Origin::signed(owner), 1, file_id.unwrap());
@@ -151,7 +168,7 @@ fn it_fails_for_change_file_id_not_owner() {
         let new_owner_id = create_user_with_owner_role();
         let standard = Standard::default();
         let not_existing_file_id = None;
-        let _ = CarbonCredits::create_project(Origin::signed(owner), standard, not_existing_file_id);
+        let _ = CarbonCredits::create_project(Origin::signed(owner), standard, not_existing_file_id, get_test_project_metadata(), TEST_CREDITING_PERIOD_START, TEST_CREDITING_PERIOD_END);
         let project_before_change = CarbonCredits::get_proj_by_id(1);
         let file_id = create_project_documentation_file(new_owner_id);
         let change_id_result = CarbonCredits::change_project_file_id(Origin::signed(new_owner_id), 1, file_id.unwrap());
@@ -168,7 +185,7 @@ fn it_works_project_assign_signer() {
     new_test_ext().execute_with(|| {
         let owner = ROLES[1].0;
         let standard = Standard::default();
-        let _ = CarbonCredits::create_project(Origin::signed(owner), standard, create_project_documentation_file(owner));
+        let _ = CarbonCredits::create_project(Origin::signed(owner), standard, create_project_documentation_file(owner), get_test_project_metadata(), TEST_CREDITING_PERIOD_START, TEST_CREDITING_PERIOD_END);
         let project_id = 1;
 
         let assign_results = vec![
@@ -196,7 +213,7 @@ fn it_works_remove_signer() {
     new_test_ext().execute_with(|| {
         let owner = ROLES[1].0;
         let standard = Standard::default();
-        let _ = CarbonCredits::create_project(Origin::signed(owner), standard, create_project_documentation_file(owner));
+        let _ = CarbonCredits::create_project(Origin::signed(owner), standard, create_project_documentation_file(owner), get_test_project_metadata(), TEST_CREDITING_PERIOD_START, TEST_CREDITING_PERIOD_END);
         let project_id = 1;
 
         let _ = CarbonCredits::assign_project_signer(Origin::signed(owner), ROLES[1].0, ROLES[1].1, project_id);
@@ -224,7 +241,7 @@ fn it_fails_remove_unexisting_signer() {
     new_test_ext().execute_with(|| {
         let owner = ROLES[1].0;
         let standard = Standard::default();
-        let _ = CarbonCredits::create_project(Origin::signed(owner), standard, create_project_documentation_file(owner));
+        let _ = CarbonCredits::create_project(Origin::signed(owner), standard, create_project_documentation_file(owner), get_test_project_metadata(), TEST_CREDITING_PERIOD_START, TEST_CREDITING_PERIOD_END);
         let project_id = 1;
 
         let _ = CarbonCredits::assign_project_signer(Origin::signed(owner), ROLES[1].0, ROLES[1].1, project_id);
@@ -250,7 +267,7 @@ fn it_fails_remove_signed_signer() {
         let owner = ROLES[1].0;
         let auditor = ROLES[2].0;
         let standard = Standard::default();
-        let _ = CarbonCredits::create_project(Origin::signed(owner), standard, create_project_documentation_file(owner));
+        let _ = CarbonCredits::create_project(Origin::signed(owner), standard, create_project_documentation_file(owner), get_test_project_metadata(), TEST_CREDITING_PERIOD_START, TEST_CREDITING_PERIOD_END);
         let project_id = 1;
 
         let _ = CarbonCredits::assign_project_signer(Origin::signed(owner), ROLES[1].0, ROLES[1].1, project_id);
@@ -285,7 +302,7 @@ fn it_works_for_full_cycle_sign_project_gold_standard() {
         let standard = Standard::GOLD_STANDARD;
 
         let project_doc_id = create_project_documentation_file(owner);
-        let _ = CarbonCredits::create_project(Origin::signed(owner), standard, project_doc_id);
+        let _ = CarbonCredits::create_project(Origin::signed(owner), standard, project_doc_id, get_test_project_metadata(), TEST_CREDITING_PERIOD_START, TEST_CREDITING_PERIOD_END);
         crate::tests::helpers::assign_project_mock_users_required_signers_gold_standard(1);
 
         let tuple_vec = vec![
@@ -332,7 +349,135 @@ fn it_works_for_full_cycle_sign_project_gold_standard() {
 
         let project_after_registry_sign = CarbonCredits::get_proj_by_id(1).unwrap();    
         assert_eq!(*project_after_registry_sign.get_standard(), Standard::GOLD_STANDARD);
-        assert_eq!(0, project_after_registry_sign.annual_reports.len());
+        assert_eq!(0, project_after_registry_sign.annual_reports_count);
+    });
+}
+
+// Verra VCS flow test
+// Project Owner submits PDD => Validation/verification body validates PDD =>
+// => Verra reviews PDD => Registry Registers PDD
+#[test]
+fn it_works_for_full_cycle_sign_project_verra() {
+    new_test_ext().execute_with(|| {
+        let owner = ROLES[1].0;
+        let auditor = ROLES[2].0;
+        let standard_acc = ROLES[3].0;
+        let registry = ROLES[5].0;
+
+        let project_doc_id = create_project_documentation_file(owner);
+        let _ = CarbonCredits::create_project(Origin::signed(owner), Standard::VERRA_VCS, project_doc_id, get_test_project_metadata(), TEST_CREDITING_PERIOD_START, TEST_CREDITING_PERIOD_END);
+        crate::tests::helpers::assign_project_mock_users_required_signers_gold_standard(1);
+
+        let tuple_vec = vec![
+            (owner, AUDITOR_SIGN_PENDING, ProjectStatus::REGISTRATION),
+            (auditor, STANDARD_SIGN_PENDING, ProjectStatus::REGISTRATION),
+            (standard_acc, REGISTRY_SIGN_PENDING, ProjectStatus::REGISTRATION),
+            (registry, REGISTERED, ProjectStatus::ISSUANCE)
+        ];
+
+        tuple_vec.iter()
+            .for_each(|(acc, state, status)| {
+                let result = CarbonCredits::sign_project(Origin::signed(*acc), 1);
+                let project = CarbonCredits::get_proj_by_id(1).unwrap();
+
+                assert_ok!(result, ());
+                assert_eq!(*state, project.state);
+                assert_eq!(*status, project.status);
+                assert!(EvercityFilesign::address_has_signed_the_file(project_doc_id.unwrap(), acc));
+            });
+
+        let project_after_registry_sign = CarbonCredits::get_proj_by_id(1).unwrap();
+        assert_eq!(*project_after_registry_sign.get_standard(), Standard::VERRA_VCS);
+    });
+}
+
+// Investor approves PDD before registry registers it
+#[test]
+fn it_works_for_full_cycle_sign_project_with_investor_gold_standard() {
+    new_test_ext().execute_with(|| {
+        let owner = ROLES[1].0;
+        let auditor = ROLES[2].0;
+        let standard_acc = ROLES[3].0;
+        let investor = ROLES[4].0;
+        let registry = ROLES[5].0;
+
+        let project_doc_id = create_project_documentation_file(owner);
+        let _ = CarbonCredits::create_project(Origin::signed(owner), Standard::GOLD_STANDARD, project_doc_id, get_test_project_metadata(), TEST_CREDITING_PERIOD_START, TEST_CREDITING_PERIOD_END);
+        let set_investor_result = CarbonCredits::set_project_investor_sign_required(Origin::signed(owner), 1, true);
+        crate::tests::helpers::assign_project_mock_users_required_signers_with_investor(1);
+
+        let tuple_vec = vec![
+            (owner, AUDITOR_SIGN_PENDING),
+            (auditor, STANDARD_SIGN_PENDING),
+            (standard_acc, INVESTOR_SIGN_PENDING),
+            (investor, REGISTRY_SIGN_PENDING),
+            (registry, REGISTERED)
+        ];
+
+        assert_ok!(set_investor_result, ());
+        assert!(CarbonCredits::get_proj_by_id(1).unwrap().is_investor_sign_required());
+        tuple_vec.iter()
+            .for_each(|(acc, state)| {
+                let result = CarbonCredits::sign_project(Origin::signed(*acc), 1);
+                let project = CarbonCredits::get_proj_by_id(1).unwrap();
+
+                assert_ok!(result, ());
+                assert_eq!(*state, project.state);
+                assert!(EvercityFilesign::address_has_signed_the_file(project_doc_id.unwrap(), acc));
+            });
+    });
+}
+
+#[test]
+fn it_fails_sign_project_not_an_investor_gold_standard() {
+    new_test_ext().execute_with(|| {
+        let owner = ROLES[1].0;
+        let auditor = ROLES[2].0;
+        let standard_acc = ROLES[3].0;
+        let registry = ROLES[5].0;
+
+        let _ = CarbonCredits::create_project(Origin::signed(owner), Standard::GOLD_STANDARD, create_project_documentation_file(owner), get_test_project_metadata(), TEST_CREDITING_PERIOD_START, TEST_CREDITING_PERIOD_END);
+        let _ = CarbonCredits::set_project_investor_sign_required(Origin::signed(owner), 1, true);
+        crate::tests::helpers::assign_project_mock_users_required_signers_with_investor(1);
+        let _ = CarbonCredits::sign_project(Origin::signed(owner), 1);
+        let _ = CarbonCredits::sign_project(Origin::signed(auditor), 1);
+        let _ = CarbonCredits::sign_project(Origin::signed(standard_acc), 1);
+
+        let registry_sign_result = CarbonCredits::sign_project(Origin::signed(registry), 1);
+        let project = CarbonCredits::get_proj_by_id(1).unwrap();
+
+        assert_noop!(registry_sign_result, RuntimeError::AccountNotInvestor);
+        assert_eq!(INVESTOR_SIGN_PENDING, project.state);
+    });
+}
+
+#[test]
+fn it_fails_set_project_investor_sign_required_after_submit() {
+    new_test_ext().execute_with(|| {
+        let owner = ROLES[1].0;
+
+        let _ = CarbonCredits::create_project(Origin::signed(owner), Standard::GOLD_STANDARD, create_project_documentation_file(owner), get_test_project_metadata(), TEST_CREDITING_PERIOD_START, TEST_CREDITING_PERIOD_END);
+        crate::tests::helpers::assign_project_mock_users_required_signers_gold_standard(1);
+        let _ = CarbonCredits::sign_project(Origin::signed(owner), 1);
+
+        let set_investor_result = CarbonCredits::set_project_investor_sign_required(Origin::signed(owner), 1, true);
+        let project = CarbonCredits::get_proj_by_id(1).unwrap();
+
+        assert_noop!(set_investor_result, RuntimeError::InvalidState);
+        assert!(!project.is_investor_sign_required());
+    });
+}
+
+#[test]
+fn it_fails_set_project_investor_sign_required_not_owner() {
+    new_test_ext().execute_with(|| {
+        let owner = ROLES[1].0;
+        let new_owner_id = create_user_with_owner_role();
+
+        let _ = CarbonCredits::create_project(Origin::signed(owner), Standard::GOLD_STANDARD, create_project_documentation_file(owner), get_test_project_metadata(), TEST_CREDITING_PERIOD_START, TEST_CREDITING_PERIOD_END);
+        let set_investor_result = CarbonCredits::set_project_investor_sign_required(Origin::signed(new_owner_id), 1, true);
+
+        assert_noop!(set_investor_result, RuntimeError::AccountNotOwner);
     });
 }
 
@@ -342,7 +487,7 @@ fn it_fails_sign_project_not_an_owner_role_gold_standard() {
         let owner = ROLES[1].0;
         let standard = Standard::GOLD_STANDARD;
 
-        let _ = CarbonCredits::create_project(Origin::signed(owner), standard, create_project_documentation_file(owner));
+        let _ = CarbonCredits::create_project(Origin::signed(owner), standard, create_project_documentation_file(owner), get_test_project_metadata(), TEST_CREDITING_PERIOD_START, TEST_CREDITING_PERIOD_END);
         crate::tests::helpers::assign_project_mock_users_required_signers_gold_standard(1);
         assign_project_mock_users_required_signers_gold_standard(1);
 
@@ -370,7 +515,7 @@ fn it_fails_sign_project_not_owner_signer_gold_standard() {
         let owner = ROLES[1].0;
         let standard = Standard::GOLD_STANDARD;
 
-        let _ = CarbonCredits::create_project(Origin::signed(owner), standard, create_project_documentation_file(owner));
+        let _ = CarbonCredits::create_project(Origin::signed(owner), standard, create_project_documentation_file(owner), get_test_project_metadata(), TEST_CREDITING_PERIOD_START, TEST_CREDITING_PERIOD_END);
         let owner_sign_result = CarbonCredits::sign_project(Origin::signed(owner), 1);
 
         assert_noop!(
@@ -386,7 +531,7 @@ fn it_fails_sign_project_no_file_id() {
         let owner = ROLES[1].0;
         let standard = Standard::GOLD_STANDARD;
 
-        let _ = CarbonCredits::create_project(Origin::signed(owner), standard, None);
+        let _ = CarbonCredits::create_project(Origin::signed(owner), standard, None, get_test_project_metadata(), TEST_CREDITING_PERIOD_START, TEST_CREDITING_PERIOD_END);
         let owner_sign_result = CarbonCredits::sign_project(Origin::signed(owner), 1);
 
         assert_noop!(
@@ -403,7 +548,7 @@ fn it_fails_sign_project_not_auditor_signer_gold_standard() {
         let auditor = ROLES[2].0;
         let standard = Standard::GOLD_STANDARD;
 
-        let _ = CarbonCredits::create_project(Origin::signed(owner), standard, create_project_documentation_file(owner));
+        let _ = CarbonCredits::create_project(Origin::signed(owner), standard, create_project_documentation_file(owner), get_test_project_metadata(), TEST_CREDITING_PERIOD_START, TEST_CREDITING_PERIOD_END);
 
         let _ = CarbonCredits::assign_last_annual_report_signer(Origin::signed(owner), owner, ROLES[1].1, 1);
         let _owner_sign_result = CarbonCredits::sign_project(Origin::signed(owner), 1);
@@ -424,7 +569,7 @@ fn it_fails_sign_project_not_standard_signer_gold_standard() {
         let standard_acc = ROLES[3].0;
         let standard = Standard::GOLD_STANDARD;
 
-        let _ = CarbonCredits::create_project(Origin::signed(owner), standard, create_project_documentation_file(owner));
+        let _ = CarbonCredits::create_project(Origin::signed(owner), standard, create_project_documentation_file(owner), get_test_project_metadata(), TEST_CREDITING_PERIOD_START, TEST_CREDITING_PERIOD_END);
 
         let _ = CarbonCredits::assign_last_annual_report_signer(Origin::signed(owner), owner, ROLES[1].1, 1);
         let _ = CarbonCredits::assign_last_annual_report_signer(Origin::signed(owner), auditor, ROLES[2].1, 1);
@@ -449,7 +594,7 @@ fn it_fails_sign_project_not_registry_signer_gold_standard() {
         let registry = ROLES[5].0;
         let standard = Standard::GOLD_STANDARD;
 
-        let _ = CarbonCredits::create_project(Origin::signed(owner), standard, create_project_documentation_file(owner));
+        let _ = CarbonCredits::create_project(Origin::signed(owner), standard, create_project_documentation_file(owner), get_test_project_metadata(), TEST_CREDITING_PERIOD_START, TEST_CREDITING_PERIOD_END);
 
         let _ = CarbonCredits::assign_last_annual_report_signer(Origin::signed(owner), owner, ROLES[1].1, 1);
         let _ = CarbonCredits::assign_last_annual_report_signer(Origin::signed(owner), auditor, ROLES[2].1, 1);
@@ -477,7 +622,7 @@ fn it_fails_sign_project_not_an_owner_of_project_gold_standard() {
         let new_owner_id = create_user_with_owner_role();
         let is_owner = EvercityAccounts::account_is_cc_project_owner(&new_owner_id);
         
-        let _ = CarbonCredits::create_project(Origin::signed(owner), standard, create_project_documentation_file(owner));
+        let _ = CarbonCredits::create_project(Origin::signed(owner), standard, create_project_documentation_file(owner), get_test_project_metadata(), TEST_CREDITING_PERIOD_START, TEST_CREDITING_PERIOD_END);
         let _result = CarbonCredits::assign_project_signer(Origin::signed(owner), new_owner_id, CC_PROJECT_OWNER_ROLE_MASK, 1);
         let owner_sign_result = CarbonCredits::sign_project(Origin::signed(new_owner_id), 1);
 
@@ -496,7 +641,7 @@ fn it_fails_sign_project_not_an_auditor_gold_standard() {
         let standard = Standard::GOLD_STANDARD;
         let proj_file_id = create_project_documentation_file(owner);
 
-        let _ = CarbonCredits::create_project(Origin::signed(owner), standard, proj_file_id);
+        let _ = CarbonCredits::create_project(Origin::signed(owner), standard, proj_file_id, get_test_project_metadata(), TEST_CREDITING_PERIOD_START, TEST_CREDITING_PERIOD_END);
         crate::tests::helpers::assign_project_mock_users_required_signers_gold_standard(1);
         let _ = CarbonCredits::sign_project(Origin::signed(owner), 1);
 
@@ -526,7 +671,7 @@ fn it_fails_sign_project_not_a_standard_acc_gold_standard() {
         let standard = Standard::GOLD_STANDARD;
         let proj_file_id = create_project_documentation_file(owner);
 
-        let _ = CarbonCredits::create_project(Origin::signed(owner), standard, proj_file_id);
+        let _ = CarbonCredits::create_project(Origin::signed(owner), standard, proj_file_id, get_test_project_metadata(), TEST_CREDITING_PERIOD_START, TEST_CREDITING_PERIOD_END);
         crate::tests::helpers::assign_project_mock_users_required_signers_gold_standard(1);
         let _ = CarbonCredits::sign_project(Origin::signed(owner), 1);
         let _ = CarbonCredits::sign_project(Origin::signed(auditor), 1);
@@ -559,7 +704,7 @@ fn it_fails_sign_project_not_a_registry_gold_standard() {
         let standard = Standard::GOLD_STANDARD;
         let proj_file_id = create_project_documentation_file(owner);
 
-        let _ = CarbonCredits::create_project(Origin::signed(owner), standard, proj_file_id);
+        let _ = CarbonCredits::create_project(Origin::signed(owner), standard, proj_file_id, get_test_project_metadata(), TEST_CREDITING_PERIOD_START, TEST_CREDITING_PERIOD_END);
         crate::tests::helpers::assign_project_mock_users_required_signers_gold_standard(1);
         let _ = CarbonCredits::sign_project(Origin::signed(owner), 1);
         let _ = CarbonCredits::sign_project(Origin::signed(auditor), 1);
@@ -597,7 +742,7 @@ fn it_fails_sign_project_already_registered_project_gold_standard() {
         let all_roles = ROLES.iter().map(|x| x.1).reduce(|x, y| x + y).unwrap();
         let _ = EvercityAccounts::account_add_with_role_and_data(Origin::signed(0), some_new_acc, all_roles);
 
-        let _ = CarbonCredits::create_project(Origin::signed(owner), standard, create_project_documentation_file(owner));
+        let _ = CarbonCredits::create_project(Origin::signed(owner), standard, create_project_documentation_file(owner), get_test_project_metadata(), TEST_CREDITING_PERIOD_START, TEST_CREDITING_PERIOD_END);
         crate::tests::helpers::assign_project_mock_users_required_signers_gold_standard(1);
         let _ = CarbonCredits::sign_project(Origin::signed(owner), 1);
         let _ = CarbonCredits::sign_project(Origin::signed(auditor), 1);
@@ -623,7 +768,7 @@ fn it_works_for_create_new_project_deposit_event_gold_standard() {
     new_test_ext_with_event().execute_with(|| {
         let owner = ROLES[1].0;
         let standard = Standard::default();
-        let _ = CarbonCredits::create_project(Origin::signed(owner), standard, create_project_documentation_file(owner));
+        let _ = CarbonCredits::create_project(Origin::signed(owner), standard, create_project_documentation_file(owner), get_test_project_metadata(), TEST_CREDITING_PERIOD_START, TEST_CREDITING_PERIOD_END);
         let last_event = last_event().unwrap();
         crate::tests::helpers::assign_project_mock_users_required_signers_gold_standard(1);
 
@@ -643,7 +788,7 @@ fn it_works_sign_project_deposit_events_gold_standard() {
 
         let standard = Standard::GOLD_STANDARD;
 
-        let _ = CarbonCredits::create_project(Origin::signed(owner), standard, create_project_documentation_file(owner));
+        let _ = CarbonCredits::create_project(Origin::signed(owner), standard, create_project_documentation_file(owner), get_test_project_metadata(), TEST_CREDITING_PERIOD_START, TEST_CREDITING_PERIOD_END);
         crate::tests::helpers::assign_project_mock_users_required_signers_gold_standard(1);
 
         let tuple_vec = vec![
@@ -662,4 +807,562 @@ fn it_works_sign_project_deposit_events_gold_standard() {
             assert_eq!(*check_event, last_event);
         });
     });
-}
\ No newline at end of file
+}
+
+#[test]
+fn it_works_sign_project_deposit_events_verra() {
+    new_test_ext_with_event().execute_with(|| {
+        let owner = ROLES[1].0;
+        let auditor = ROLES[2].0;
+        let standard_acc = ROLES[3].0;
+        let registry = ROLES[5].0;
+
+        let _ = CarbonCredits::create_project(Origin::signed(owner), Standard::VERRA_VCS, create_project_documentation_file(owner), get_test_project_metadata(), TEST_CREDITING_PERIOD_START, TEST_CREDITING_PERIOD_END);
+        crate::tests::helpers::assign_project_mock_users_required_signers_gold_standard(1);
+
+        let tuple_vec = vec![
+            (owner, Event::pallet_carbon_credits(crate::RawEvent::ProjectSubmited(owner, 1))),
+            (auditor, Event::pallet_carbon_credits(crate::RawEvent::ProjectSignedByAduitor(auditor, 1))),
+            (standard_acc, Event::pallet_carbon_credits(crate::RawEvent::ProjectSignedByStandard(standard_acc, 1))),
+            (registry, Event::pallet_carbon_credits(crate::RawEvent::ProjectSignedByRegistry(registry, 1)))
+        ];
+
+        tuple_vec.iter()
+        .for_each(|(acc, check_event)| {
+            let _ = CarbonCredits::sign_project(Origin::signed(*acc), 1);
+            let last_event = last_event().unwrap();
+
+            assert_eq!(*check_event, last_event);
+        });
+    });
+}
+
+#[test]
+fn it_works_sign_project_with_investor_deposit_event() {
+    new_test_ext_with_event().execute_with(|| {
+        let owner = ROLES[1].0;
+        let auditor = ROLES[2].0;
+        let standard_acc = ROLES[3].0;
+        let investor = ROLES[4].0;
+
+        let _ = CarbonCredits::create_project(Origin::signed(owner), Standard::GOLD_STANDARD, create_project_documentation_file(owner), get_test_project_metadata(), TEST_CREDITING_PERIOD_START, TEST_CREDITING_PERIOD_END);
+        let _ = CarbonCredits::set_project_investor_sign_required(Origin::signed(owner), 1, true);
+        crate::tests::helpers::assign_project_mock_users_required_signers_with_investor(1);
+        let _ = CarbonCredits::sign_project(Origin::signed(owner), 1);
+        let _ = CarbonCredits::sign_project(Origin::signed(auditor), 1);
+        let _ = CarbonCredits::sign_project(Origin::signed(standard_acc), 1);
+        let _ = CarbonCredits::sign_project(Origin::signed(investor), 1);
+
+        let check_event = Event::pallet_carbon_credits(crate::RawEvent::ProjectSignedByInvestor(investor, 1));
+        assert_eq!(check_event, last_event().unwrap());
+    });
+}
+
+// Index tests:
+#[test]
+fn it_works_get_projects_by_state() {
+    new_test_ext().execute_with(|| {
+        let owner = ROLES[1].0;
+        let auditor = ROLES[2].0;
+
+        let _ = CarbonCredits::create_project(Origin::signed(owner), Standard::GOLD_STANDARD, create_project_documentation_file(owner), get_test_project_metadata(), TEST_CREDITING_PERIOD_START, TEST_CREDITING_PERIOD_END);
+        assert_eq!(vec![1], CarbonCredits::get_projects_by_state(PROJECT_OWNER_SIGN_PENDING));
+
+        crate::tests::helpers::assign_project_mock_users_required_signers_gold_standard(1);
+        let _ = CarbonCredits::sign_project(Origin::signed(owner), 1);
+        assert!(CarbonCredits::get_projects_by_state(PROJECT_OWNER_SIGN_PENDING).is_empty());
+        assert_eq!(vec![1], CarbonCredits::get_projects_by_state(AUDITOR_SIGN_PENDING));
+
+        let _ = CarbonCredits::reject_project(Origin::signed(auditor), 1, H256::from([0x42; 32]));
+        assert!(CarbonCredits::get_projects_by_state(AUDITOR_SIGN_PENDING).is_empty());
+        assert_eq!(vec![1], CarbonCredits::get_projects_by_state(PROJECT_OWNER_SIGN_PENDING));
+    });
+}
+
+#[test]
+fn it_works_get_registered_projects_by_state() {
+    new_test_ext().execute_with(|| {
+        let (_, project_id, _) = get_registerd_project_and_owner_gold_standard();
+
+        assert_eq!(vec![project_id], CarbonCredits::get_projects_by_state(REGISTERED));
+        [PROJECT_OWNER_SIGN_PENDING, AUDITOR_SIGN_PENDING, STANDARD_SIGN_PENDING, REGISTRY_SIGN_PENDING].iter()
+            .for_each(|state| assert!(CarbonCredits::get_projects_by_state(*state).is_empty()));
+    });
+}
+
+#[test]
+fn it_works_get_projects_pending_signature() {
+    new_test_ext().execute_with(|| {
+        let owner = ROLES[1].0;
+        let auditor = ROLES[2].0;
+        let standard_acc = ROLES[3].0;
+
+        let _ = CarbonCredits::create_project(Origin::signed(owner), Standard::GOLD_STANDARD, create_project_documentation_file(owner), get_test_project_metadata(), TEST_CREDITING_PERIOD_START, TEST_CREDITING_PERIOD_END);
+        crate::tests::helpers::assign_project_mock_users_required_signers_gold_standard(1);
+        assert_eq!(vec![1], CarbonCredits::get_projects_pending_signature(owner));
+        assert!(CarbonCredits::get_projects_pending_signature(auditor).is_empty());
+
+        let _ = CarbonCredits::sign_project(Origin::signed(owner), 1);
+        assert!(CarbonCredits::get_projects_pending_signature(owner).is_empty());
+        assert_eq!(vec![1], CarbonCredits::get_projects_pending_signature(auditor));
+
+        let _ = CarbonCredits::sign_project(Origin::signed(auditor), 1);
+        assert!(CarbonCredits::get_projects_pending_signature(auditor).is_empty());
+        assert_eq!(vec![1], CarbonCredits::get_projects_pending_signature(standard_acc));
+    });
+}
+
+#[test]
+fn it_works_remove_project_signer_from_index() {
+    new_test_ext().execute_with(|| {
+        let owner = ROLES[1].0;
+        let auditor = ROLES[2].0;
+
+        let _ = CarbonCredits::create_project(Origin::signed(owner), Standard::GOLD_STANDARD, create_project_documentation_file(owner), get_test_project_metadata(), TEST_CREDITING_PERIOD_START, TEST_CREDITING_PERIOD_END);
+        let _ = CarbonCredits::assign_project_signer(Origin::signed(owner), auditor, CC_AUDITOR_ROLE_MASK, 1);
+        assert_eq!(vec![1], CarbonCredits::get_projects_by_signer(auditor));
+
+        assert_ok!(CarbonCredits::remove_project_signer(Origin::signed(owner), auditor, CC_AUDITOR_ROLE_MASK, 1));
+        assert!(CarbonCredits::get_projects_by_signer(auditor).is_empty());
+    });
+}
+
+#[test]
+fn it_works_keep_project_in_signer_index_after_annual_report_deleted() {
+    new_test_ext().execute_with(|| {
+        let auditor = ROLES[2].0;
+        let (_, project_id, owner) = get_registerd_project_and_owner_gold_standard();
+        let _ = CarbonCredits::create_annual_report(
+            Origin::signed(owner), project_id, create_annual_report_file(owner), TEST_CARBON_CREDITS_COUNT,
+            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, get_test_monitoring_period(0)
+        );
+        assign_annual_report_mock_users_required_signers_gold_standard(project_id);
+        assert_eq!(2, CarbonCredits::projects_by_signer(auditor, project_id));
+
+        assert_ok!(CarbonCredits::delete_last_annual_report(Origin::signed(owner), project_id));
+        assert_eq!(1, CarbonCredits::projects_by_signer(auditor, project_id));
+        assert_eq!(vec![project_id], CarbonCredits::get_projects_by_signer(auditor));
+    });
+}
+
+// Rejection tests:
+#[test]
+fn it_works_reject_project_by_auditor() {
+    new_test_ext_with_event().execute_with(|| {
+        let owner = ROLES[1].0;
+        let auditor = ROLES[2].0;
+        let reason_hash = H256::from([0x42; 32]);
+
+        let _ = CarbonCredits::create_project(Origin::signed(owner), Standard::GOLD_STANDARD, create_project_documentation_file(owner), get_test_project_metadata(), TEST_CREDITING_PERIOD_START, TEST_CREDITING_PERIOD_END);
+        crate::tests::helpers::assign_project_mock_users_required_signers_gold_standard(1);
+        let _ = CarbonCredits::sign_project(Origin::signed(owner), 1);
+
+        let reject_result = CarbonCredits::reject_project(Origin::signed(auditor), 1, reason_hash);
+        let project = CarbonCredits::get_proj_by_id(1).unwrap();
+        let rejections = CarbonCredits::get_project_rejections(1);
+        let check_event = Event::pallet_carbon_credits(crate::RawEvent::ProjectRejected(auditor, 1, CC_AUDITOR_ROLE_MASK, reason_hash));
+
+        assert_ok!(reject_result, ());
+        assert_eq!(PROJECT_OWNER_SIGN_PENDING, project.state);
+        assert_eq!(ProjectStatus::PREPARING, project.status);
+        assert_eq!(1, project.rejections_count);
+        assert_eq!(1, rejections.len());
+        assert_eq!(auditor, rejections[0].rejected_by);
+        assert_eq!(CC_AUDITOR_ROLE_MASK, rejections[0].role);
+        assert_eq!(reason_hash, rejections[0].reason_hash);
+        assert_eq!(check_event, last_event().unwrap());
+    });
+}
+
+#[test]
+fn it_works_resubmit_project_after_rejection() {
+    new_test_ext().execute_with(|| {
+        let owner = ROLES[1].0;
+        let auditor = ROLES[2].0;
+        let standard_acc = ROLES[3].0;
+        let registry = ROLES[5].0;
+
+        let _ = CarbonCredits::create_project(Origin::signed(owner), Standard::GOLD_STANDARD, create_project_documentation_file(owner), get_test_project_metadata(), TEST_CREDITING_PERIOD_START, TEST_CREDITING_PERIOD_END);
+        crate::tests::helpers::assign_project_mock_users_required_signers_gold_standard(1);
+        let _ = CarbonCredits::sign_project(Origin::signed(owner), 1);
+        let _ = CarbonCredits::sign_project(Origin::signed(auditor), 1);
+        let _ = CarbonCredits::reject_project(Origin::signed(standard_acc), 1, H256::from([0x42; 32]));
+
+        // Owner uploads corrected PDD
+        let new_file_id = [1,2,3,4,5,6,7,8,9,1,1,1,1,1,1,1];
+        let _ = EvercityFilesign::create_new_file(Origin::signed(owner), 
+            "my_corrected_project_documentation".to_owned().as_bytes().to_vec(),
+            H256::from([0x77; 32]),
+            Some(new_file_id)
+        );
+        let change_id_result = CarbonCredits::change_project_file_id(Origin::signed(owner), 1, new_file_id);
+        crate::tests::helpers::assign_project_mock_users_required_signers_gold_standard(1);
+
+        let sign_results = vec![
+            CarbonCredits::sign_project(Origin::signed(owner), 1),
+            CarbonCredits::sign_project(Origin::signed(auditor), 1),
+            CarbonCredits::sign_project(Origin::signed(standard_acc), 1),
+            CarbonCredits::sign_project(Origin::signed(registry), 1),
+        ];
+        let project = CarbonCredits::get_proj_by_id(1).unwrap();
+
+        assert_ok!(change_id_result, ());
+        sign_results.iter().for_each(|result| {
+            assert_ok!(*result, ());
+        });
+        assert_eq!(REGISTERED, project.state);
+        assert_eq!(1, CarbonCredits::get_project_rejections(1).len());
+        assert!(EvercityFilesign::address_has_signed_the_file(new_file_id, &registry));
+    });
+}
+
+#[test]
+fn it_fails_reject_project_not_pending_signer_role() {
+    new_test_ext().execute_with(|| {
+        let owner = ROLES[1].0;
+        let standard_acc = ROLES[3].0;
+
+        let _ = CarbonCredits::create_project(Origin::signed(owner), Standard::GOLD_STANDARD, create_project_documentation_file(owner), get_test_project_metadata(), TEST_CREDITING_PERIOD_START, TEST_CREDITING_PERIOD_END);
+        crate::tests::helpers::assign_project_mock_users_required_signers_gold_standard(1);
+        let _ = CarbonCredits::sign_project(Origin::signed(owner), 1);
+
+        let reject_result = CarbonCredits::reject_project(Origin::signed(standard_acc), 1, H256::from([0x42; 32]));
+        let project = CarbonCredits::get_proj_by_id(1).unwrap();
+
+        assert_noop!(reject_result, RuntimeError::AccountNotAuditor);
+        assert_eq!(AUDITOR_SIGN_PENDING, project.state);
+    });
+}
+
+#[test]
+fn it_fails_reject_project_not_assigned_signer() {
+    new_test_ext().execute_with(|| {
+        let owner = ROLES[1].0;
+        let auditor = ROLES[2].0;
+
+        let _ = CarbonCredits::create_project(Origin::signed(owner), Standard::GOLD_STANDARD, create_project_documentation_file(owner), get_test_project_metadata(), TEST_CREDITING_PERIOD_START, TEST_CREDITING_PERIOD_END);
+        let _ = CarbonCredits::assign_project_signer(Origin::signed(owner), owner, CC_PROJECT_OWNER_ROLE_MASK, 1);
+        let _ = CarbonCredits::sign_project(Origin::signed(owner), 1);
+
+        let reject_result = CarbonCredits::reject_project(Origin::signed(auditor), 1, H256::from([0x42; 32]));
+        assert_noop!(reject_result, RuntimeError::IncorrectProjectSigner);
+    });
+}
+
+#[test]
+fn it_fails_reject_project_not_submitted_or_registered() {
+    new_test_ext().execute_with(|| {
+        let (_, project_id, owner) = get_registerd_project_and_owner_gold_standard();
+        let registry = ROLES[5].0;
+        let _ = CarbonCredits::create_project(Origin::signed(owner), Standard::GOLD_STANDARD, create_project_documentation_file(owner), get_test_project_metadata(), TEST_CREDITING_PERIOD_START, TEST_CREDITING_PERIOD_END);
+
+        let reject_registered_result = CarbonCredits::reject_project(Origin::signed(registry), project_id, H256::from([0x42; 32]));
+        let reject_not_submitted_result = CarbonCredits::reject_project(Origin::signed(owner), 2, H256::from([0x42; 32]));
+
+        assert_noop!(reject_registered_result, RuntimeError::InvalidState);
+        assert_noop!(reject_not_submitted_result, RuntimeError::InvalidState);
+    });
+}
+#[test]
+fn it_works_create_project_with_metadata() {
+    new_test_ext().execute_with(|| {
+        let owner = ROLES[1].0;
+        let _ = CarbonCredits::create_project(Origin::signed(owner), Standard::GOLD_STANDARD, create_project_documentation_file(owner), get_test_project_metadata(), TEST_CREDITING_PERIOD_START, TEST_CREDITING_PERIOD_END);
+
+        assert_eq!(Some(get_test_project_metadata()), CarbonCredits::get_project_metadata(1));
+    });
+}
+
+#[test]
+fn it_fails_create_project_invalid_metadata() {
+    new_test_ext().execute_with(|| {
+        let owner = ROLES[1].0;
+        let valid = get_test_project_metadata();
+        let invalid_metadata = vec![
+            ProjectMetadata { country_code: *b"br", ..valid.clone() },
+            ProjectMetadata { latitude: 90_000_001, ..valid.clone() },
+            ProjectMetadata { longitude: -180_000_001, ..valid.clone() },
+            ProjectMetadata { methodology: Vec::new(), ..valid.clone() },
+            ProjectMetadata { sector: vec![0x41; 65], ..valid.clone() },
+            ProjectMetadata { crediting_period_years: 0, ..valid },
+        ];
+
+        invalid_metadata.into_iter().for_each(|metadata| {
+            let result = CarbonCredits::create_project(Origin::signed(owner), Standard::GOLD_STANDARD, None, metadata, TEST_CREDITING_PERIOD_START, TEST_CREDITING_PERIOD_END);
+            assert_noop!(result, RuntimeError::InvalidProjectMetadata);
+        });
+        assert!(CarbonCredits::get_proj_by_id(1).is_none());
+    });
+}
+
+#[test]
+fn it_works_set_project_metadata() {
+    new_test_ext_with_event().execute_with(|| {
+        let owner = ROLES[1].0;
+        let metadata = ProjectMetadata { estimated_annual_reductions: 70_000, ..get_test_project_metadata() };
+        let _ = CarbonCredits::create_project(Origin::signed(owner), Standard::GOLD_STANDARD, create_project_documentation_file(owner), get_test_project_metadata(), TEST_CREDITING_PERIOD_START, TEST_CREDITING_PERIOD_END);
+        let result = CarbonCredits::set_project_metadata(Origin::signed(owner), 1, metadata.clone());
+        let check_event = Event::pallet_carbon_credits(crate::RawEvent::ProjectMetadataChanged(owner, 1));
+
+        assert_ok!(result, ());
+        assert_eq!(Some(metadata), CarbonCredits::get_project_metadata(1));
+        assert_eq!(check_event, last_event().unwrap());
+    });
+}
+
+#[test]
+fn it_fails_set_project_metadata_after_submit() {
+    new_test_ext().execute_with(|| {
+        let owner = ROLES[1].0;
+        let metadata = ProjectMetadata { estimated_annual_reductions: 70_000, ..get_test_project_metadata() };
+        let _ = CarbonCredits::create_project(Origin::signed(owner), Standard::GOLD_STANDARD, create_project_documentation_file(owner), get_test_project_metadata(), TEST_CREDITING_PERIOD_START, TEST_CREDITING_PERIOD_END);
+        crate::tests::helpers::assign_project_mock_users_required_signers_gold_standard(1);
+        let _ = CarbonCredits::sign_project(Origin::signed(owner), 1);
+
+        let result = CarbonCredits::set_project_metadata(Origin::signed(owner), 1, metadata);
+
+        assert_noop!(result, RuntimeError::InvalidState);
+        assert_eq!(Some(get_test_project_metadata()), CarbonCredits::get_project_metadata(1));
+    });
+}
+
+#[test]
+fn it_fails_set_project_metadata_not_owner() {
+    new_test_ext().execute_with(|| {
+        let owner = ROLES[1].0;
+        let new_owner_id = create_user_with_owner_role();
+        let _ = CarbonCredits::create_project(Origin::signed(owner), Standard::GOLD_STANDARD, create_project_documentation_file(owner), get_test_project_metadata(), TEST_CREDITING_PERIOD_START, TEST_CREDITING_PERIOD_END);
+
+        let result = CarbonCredits::set_project_metadata(Origin::signed(new_owner_id), 1, get_test_project_metadata());
+
+        assert_noop!(result, RuntimeError::AccountNotOwner);
+    });
+}
+
+#[test]
+fn it_works_create_project_with_crediting_period() {
+    new_test_ext().execute_with(|| {
+        let owner = ROLES[1].0;
+        let _ = CarbonCredits::create_project(Origin::signed(owner), Standard::GOLD_STANDARD, None, get_test_project_metadata(), TEST_CREDITING_PERIOD_START, TEST_CREDITING_PERIOD_END);
+        let crediting_period = CarbonCredits::get_crediting_period(1).unwrap();
+
+        assert_eq!(TEST_CREDITING_PERIOD_START, crediting_period.start);
+        assert_eq!(TEST_CREDITING_PERIOD_END, crediting_period.end);
+        assert_eq!(0, crediting_period.renewals_count);
+    });
+}
+
+#[test]
+fn it_fails_create_project_invalid_crediting_period() {
+    new_test_ext().execute_with(|| {
+        let owner = ROLES[1].0;
+        let result = CarbonCredits::create_project(Origin::signed(owner), Standard::GOLD_STANDARD, None, get_test_project_metadata(), TEST_CREDITING_PERIOD_END, TEST_CREDITING_PERIOD_START);
+
+        assert_noop!(result, RuntimeError::InvalidCreditingPeriod);
+        assert!(CarbonCredits::get_proj_by_id(1).is_none());
+    });
+}
+
+#[test]
+fn it_works_set_project_crediting_period() {
+    new_test_ext().execute_with(|| {
+        let owner = ROLES[1].0;
+        let new_end = TEST_CREDITING_PERIOD_END + 1000;
+        let _ = CarbonCredits::create_project(Origin::signed(owner), Standard::GOLD_STANDARD, None, get_test_project_metadata(), TEST_CREDITING_PERIOD_START, TEST_CREDITING_PERIOD_END);
+        let result = CarbonCredits::set_project_crediting_period(Origin::signed(owner), 1, TEST_CREDITING_PERIOD_START, new_end);
+
+        assert_ok!(result, ());
+        assert_eq!(new_end, CarbonCredits::get_crediting_period(1).unwrap().end);
+    });
+}
+
+#[test]
+fn it_fails_set_project_crediting_period_after_submit() {
+    new_test_ext().execute_with(|| {
+        let owner = ROLES[1].0;
+        let _ = CarbonCredits::create_project(Origin::signed(owner), Standard::GOLD_STANDARD, create_project_documentation_file(owner), get_test_project_metadata(), TEST_CREDITING_PERIOD_START, TEST_CREDITING_PERIOD_END);
+        crate::tests::helpers::assign_project_mock_users_required_signers_gold_standard(1);
+        let _ = CarbonCredits::sign_project(Origin::signed(owner), 1);
+
+        let result = CarbonCredits::set_project_crediting_period(Origin::signed(owner), 1, TEST_CREDITING_PERIOD_START, TEST_CREDITING_PERIOD_END + 1000);
+
+        assert_noop!(result, RuntimeError::InvalidState);
+        assert_eq!(TEST_CREDITING_PERIOD_END, CarbonCredits::get_crediting_period(1).unwrap().end);
+    });
+}
+
+#[test]
+fn it_works_renew_crediting_period() {
+    new_test_ext().execute_with(|| {
+        let (_, project_id, owner) = get_registerd_project_and_owner_gold_standard();
+        let auditor = ROLES[2].0;
+        let standard_acc = ROLES[3].0;
+        let registry = ROLES[5].0;
+        let new_end = TEST_CREDITING_PERIOD_END + 7 * TEST_MONITORING_PERIOD_DURATION;
+
+        let renew_result = CarbonCredits::renew_crediting_period(Origin::signed(owner), project_id, new_end);
+        let project = CarbonCredits::get_proj_by_id(project_id).unwrap();
+        let crediting_period = CarbonCredits::get_crediting_period(project_id).unwrap();
+
+        assert_ok!(renew_result, ());
+        assert_eq!(REGISTERED, project.state);
+        assert_eq!(ProjectStatus::ISSUANCE, project.status);
+        assert_eq!(TEST_CREDITING_PERIOD_END, crediting_period.end);
+        assert_eq!(Some(new_end), crediting_period.pending_renewal_end);
+        assert_eq!(vec![(owner, CC_PROJECT_OWNER_ROLE_MASK)], CarbonCredits::crediting_period_renewal_signatures(project_id));
+
+        let tuple_vec = vec![
+            (auditor, CC_AUDITOR_ROLE_MASK),
+            (standard_acc, CC_STANDARD_ROLE_MASK),
+            (registry, CC_REGISTRY_ROLE_MASK),
+        ];
+        tuple_vec.iter().for_each(|(acc, role)| {
+            let pending_renewal_end = CarbonCredits::get_crediting_period(project_id).unwrap().pending_renewal_end;
+            let result = CarbonCredits::sign_crediting_period_renewal(Origin::signed(*acc), project_id);
+
+            assert_eq!(Some(new_end), pending_renewal_end);
+            assert_ok!(result, ());
+            assert_eq!(REGISTERED, CarbonCredits::get_proj_by_id(project_id).unwrap().state);
+            if *role != CC_REGISTRY_ROLE_MASK {
+                assert_eq!(Some(&(*acc, *role)), CarbonCredits::crediting_period_renewal_signatures(project_id).last());
+            }
+        });
+
+        let crediting_period = CarbonCredits::get_crediting_period(project_id).unwrap();
+        assert_eq!(new_end, crediting_period.end);
+        assert_eq!(1, crediting_period.renewals_count);
+        assert_eq!(None, crediting_period.pending_renewal_end);
+        assert!(crediting_period.renewal_signing_order.is_empty());
+        assert!(CarbonCredits::crediting_period_renewal_signatures(project_id).is_empty());
+    });
+}
+
+#[test]
+fn it_fails_sign_crediting_period_renewal_not_pending_role() {
+    new_test_ext().execute_with(|| {
+        let (_, project_id, owner) = get_registerd_project_and_owner_gold_standard();
+        let registry = ROLES[5].0;
+        let _ = CarbonCredits::renew_crediting_period(Origin::signed(owner), project_id, TEST_CREDITING_PERIOD_END + 1000);
+
+        let result = CarbonCredits::sign_crediting_period_renewal(Origin::signed(registry), project_id);
+
+        assert_noop!(result, RuntimeError::AccountNotAuditor);
+    });
+}
+
+#[test]
+fn it_fails_sign_crediting_period_renewal_not_pending() {
+    new_test_ext().execute_with(|| {
+        let (_, project_id, _) = get_registerd_project_and_owner_gold_standard();
+        let auditor = ROLES[2].0;
+
+        let result = CarbonCredits::sign_crediting_period_renewal(Origin::signed(auditor), project_id);
+
+        assert_noop!(result, RuntimeError::CreditingPeriodRenewalNotPending);
+    });
+}
+
+#[test]
+fn it_fails_renew_crediting_period_renewal_pending() {
+    new_test_ext().execute_with(|| {
+        let (_, project_id, owner) = get_registerd_project_and_owner_gold_standard();
+        let _ = CarbonCredits::renew_crediting_period(Origin::signed(owner), project_id, TEST_CREDITING_PERIOD_END + 1000);
+
+        let result = CarbonCredits::renew_crediting_period(Origin::signed(owner), project_id, TEST_CREDITING_PERIOD_END + 2000);
+
+        assert_noop!(result, RuntimeError::CreditingPeriodRenewalPending);
+    });
+}
+
+#[test]
+fn it_works_reject_crediting_period_renewal() {
+    new_test_ext_with_event().execute_with(|| {
+        let (_, project_id, owner) = get_registerd_project_and_owner_gold_standard();
+        let auditor = ROLES[2].0;
+        let standard_acc = ROLES[3].0;
+        let reason_hash = H256::from([0x42; 32]);
+        let _ = CarbonCredits::renew_crediting_period(Origin::signed(owner), project_id, TEST_CREDITING_PERIOD_END + 1000);
+        let _ = CarbonCredits::sign_crediting_period_renewal(Origin::signed(auditor), project_id);
+
+        let reject_result = CarbonCredits::reject_crediting_period_renewal(Origin::signed(standard_acc), project_id, reason_hash);
+        let project = CarbonCredits::get_proj_by_id(project_id).unwrap();
+        let crediting_period = CarbonCredits::get_crediting_period(project_id).unwrap();
+        let check_event = Event::pallet_carbon_credits(crate::RawEvent::ProjectCreditingPeriodRenewalRejected(standard_acc, project_id, CC_STANDARD_ROLE_MASK, reason_hash));
+
+        assert_ok!(reject_result, ());
+        assert_eq!(REGISTERED, project.state);
+        assert_eq!(ProjectStatus::ISSUANCE, project.status);
+        assert_eq!(0, project.rejections_count);
+        assert_eq!(TEST_CREDITING_PERIOD_END, crediting_period.end);
+        assert_eq!(0, crediting_period.renewals_count);
+        assert_eq!(None, crediting_period.pending_renewal_end);
+        assert!(CarbonCredits::crediting_period_renewal_signatures(project_id).is_empty());
+        assert_eq!(vec![project_id], CarbonCredits::get_projects_by_state(REGISTERED));
+        assert_eq!(check_event, last_event().unwrap());
+
+        // Owner can submit renewal again
+        assert_ok!(CarbonCredits::renew_crediting_period(Origin::signed(owner), project_id, TEST_CREDITING_PERIOD_END + 2000));
+    });
+}
+
+#[test]
+fn it_works_create_annual_report_and_release_during_crediting_period_renewal() {
+    new_test_ext().execute_with(|| {
+        let (_, project_id, owner) = get_registerd_project_and_owner_gold_standard();
+        let auditor = ROLES[2].0;
+        let standard_acc = ROLES[3].0;
+        let registry = ROLES[5].0;
+        let _ = CarbonCredits::renew_crediting_period(Origin::signed(owner), project_id, TEST_CREDITING_PERIOD_END + 1000);
+
+        let create_result = CarbonCredits::create_annual_report(
+            Origin::signed(owner), project_id, create_annual_report_file(owner), TEST_CARBON_CREDITS_COUNT,
+            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, get_test_monitoring_period(0)
+        );
+        assign_annual_report_mock_users_required_signers_gold_standard(project_id);
+        vec![owner, auditor, standard_acc, registry].iter()
+            .for_each(|acc| assert_ok!(CarbonCredits::sign_last_annual_report(Origin::signed(*acc), project_id)));
+        let release_result = CarbonCredits::release_carbon_credits(Origin::signed(owner), project_id, 1, owner, 1);
+
+        assert_ok!(create_result);
+        assert_ok!(release_result, ());
+        assert!(CarbonCredits::get_last_annual_report(project_id).unwrap().is_carbon_credits_released());
+        assert!(CarbonCredits::get_crediting_period(project_id).unwrap().is_renewal_pending());
+    });
+}
+
+#[test]
+fn it_fails_renew_crediting_period_not_registered() {
+    new_test_ext().execute_with(|| {
+        let owner = ROLES[1].0;
+        let _ = CarbonCredits::create_project(Origin::signed(owner), Standard::GOLD_STANDARD, create_project_documentation_file(owner), get_test_project_metadata(), TEST_CREDITING_PERIOD_START, TEST_CREDITING_PERIOD_END);
+
+        let result = CarbonCredits::renew_crediting_period(Origin::signed(owner), 1, TEST_CREDITING_PERIOD_END + 1000);
+
+        assert_noop!(result, RuntimeError::ProjectNotRegistered);
+    });
+}
+
+#[test]
+fn it_fails_renew_crediting_period_not_extending() {
+    new_test_ext().execute_with(|| {
+        let (_, project_id, owner) = get_registerd_project_and_owner_gold_standard();
+
+        let result = CarbonCredits::renew_crediting_period(Origin::signed(owner), project_id, TEST_CREDITING_PERIOD_END);
+
+        assert_noop!(result, RuntimeError::InvalidCreditingPeriod);
+    });
+}
+
+#[test]
+fn it_fails_renew_fixed_crediting_period() {
+    new_test_ext().execute_with(|| {
+        let definition = Standard::GOLD_STANDARD.default_definition().unwrap().with_max_crediting_period_renewals(0);
+        let _ = CarbonCredits::set_standard_definition(Origin::root(), Standard::GOLD_STANDARD, definition);
+        let (_, project_id, owner) = get_registerd_project_and_owner_gold_standard();
+
+        let result = CarbonCredits::renew_crediting_period(Origin::signed(owner), project_id, TEST_CREDITING_PERIOD_END + 1000);
+
+        assert_noop!(result, RuntimeError::CreditingPeriodRenewalsExceeded);
+        assert_eq!(REGISTERED, CarbonCredits::get_proj_by_id(project_id).unwrap().state);
+    });
+}
@@ -0,0 +1,377 @@
+use crate::Error;
+use crate::tests::mock::*;
+use frame_support::{assert_ok, assert_noop, dispatch::DispatchError};
+use crate::standard::{Standard, StandardStruct};
+use crate::project::*;
+use crate::annual_report::*;
+use pallet_evercity_accounts::accounts::*;
+use crate::tests::helpers::*;
+use sp_std::vec;
+
+type RuntimeError = Error<TestRuntime>;
+
+fn get_test_custom_standard_definition() -> StandardStruct {
+    StandardStruct::new(
+        vec![CC_PROJECT_OWNER_ROLE_MASK, CC_AUDITOR_ROLE_MASK, CC_REGISTRY_ROLE_MASK],
+        vec![CC_PROJECT_OWNER_ROLE_MASK, CC_REGISTRY_ROLE_MASK],
+    )
+}
+
+#[test]
+fn it_works_set_custom_standard_definition() {
+    new_test_ext_with_event().execute_with(|| {
+        let standard = Standard::CUSTOM(1);
+        let result = CarbonCredits::set_standard_definition(Origin::root(), standard.clone(), get_test_custom_standard_definition());
+        let check_event = Event::pallet_carbon_credits(crate::RawEvent::StandardDefinitionSet(standard.clone()));
+
+        assert_ok!(result, ());
+        assert_eq!(Some(get_test_custom_standard_definition()), CarbonCredits::get_standard_definition(&standard));
+        assert_eq!(check_event, last_event().unwrap());
+    });
+}
+
+#[test]
+fn it_fails_set_standard_definition_not_root() {
+    new_test_ext().execute_with(|| {
+        let standard = Standard::CUSTOM(1);
+        let result = CarbonCredits::set_standard_definition(Origin::signed(ROLES[0].0), standard.clone(), get_test_custom_standard_definition());
+
+        assert_noop!(result, DispatchError::BadOrigin);
+        assert!(CarbonCredits::get_standard_definition(&standard).is_none());
+    });
+}
+
+#[test]
+fn it_fails_set_standard_definition_invalid_signing_order() {
+    new_test_ext().execute_with(|| {
+        let standard = Standard::CUSTOM(1);
+        let definitions = vec![
+            // owner doesnt start signing
+            StandardStruct::new(vec![CC_AUDITOR_ROLE_MASK, CC_PROJECT_OWNER_ROLE_MASK], vec![CC_PROJECT_OWNER_ROLE_MASK, CC_REGISTRY_ROLE_MASK]),
+            // only owner signs
+            StandardStruct::new(vec![CC_PROJECT_OWNER_ROLE_MASK, CC_REGISTRY_ROLE_MASK], vec![CC_PROJECT_OWNER_ROLE_MASK]),
+            // role signs twice
+            StandardStruct::new(vec![CC_PROJECT_OWNER_ROLE_MASK, CC_AUDITOR_ROLE_MASK, CC_AUDITOR_ROLE_MASK], vec![CC_PROJECT_OWNER_ROLE_MASK, CC_REGISTRY_ROLE_MASK]),
+            // role cant sign
+            StandardStruct::new(vec![CC_PROJECT_OWNER_ROLE_MASK, MASTER_ROLE_MASK], vec![CC_PROJECT_OWNER_ROLE_MASK, CC_REGISTRY_ROLE_MASK]),
+        ];
+
+        definitions.into_iter().for_each(|definition| {
+            let result = CarbonCredits::set_standard_definition(Origin::root(), standard.clone(), definition);
+            assert_noop!(result, RuntimeError::InvalidStandardDefinition);
+        });
+    });
+}
+
+#[test]
+fn it_fails_set_standard_definition_invalid_buffer_percent() {
+    new_test_ext().execute_with(|| {
+        let standard = Standard::CUSTOM(1);
+        let result = CarbonCredits::set_standard_definition(Origin::root(), standard.clone(), get_test_custom_standard_definition().with_buffer_percent(100));
+
+        assert_noop!(result, RuntimeError::InvalidStandardDefinition);
+        assert_ok!(CarbonCredits::set_standard_definition(Origin::root(), standard, get_test_custom_standard_definition().with_buffer_percent(20)));
+    });
+}
+
+#[test]
+fn it_fails_set_standard_definition_invalid_issuer_role() {
+    new_test_ext().execute_with(|| {
+        let standard = Standard::CUSTOM(1);
+        let result = CarbonCredits::set_standard_definition(Origin::root(), standard.clone(), get_test_custom_standard_definition().with_issuer_role(CC_AUDITOR_ROLE_MASK));
+
+        assert_noop!(result, RuntimeError::InvalidStandardDefinition);
+        assert_ok!(CarbonCredits::set_standard_definition(Origin::root(), standard, get_test_custom_standard_definition().with_issuer_role(CC_REGISTRY_ROLE_MASK)));
+    });
+}
+
+#[test]
+fn it_works_remove_standard_definition() {
+    new_test_ext().execute_with(|| {
+        let standard = Standard::CUSTOM(1);
+        let _ = CarbonCredits::set_standard_definition(Origin::root(), standard.clone(), get_test_custom_standard_definition());
+        let result = CarbonCredits::remove_standard_definition(Origin::root(), standard.clone());
+
+        assert_ok!(result, ());
+        assert!(CarbonCredits::get_standard_definition(&standard).is_none());
+    });
+}
+
+#[test]
+fn it_fails_remove_unexisting_standard_definition() {
+    new_test_ext().execute_with(|| {
+        let result = CarbonCredits::remove_standard_definition(Origin::root(), Standard::CUSTOM(1));
+        assert_noop!(result, RuntimeError::StandardNotExist);
+    });
+}
+
+#[test]
+fn it_fails_remove_custom_standard_definition_with_projects() {
+    new_test_ext().execute_with(|| {
+        let owner = ROLES[1].0;
+        let standard = Standard::CUSTOM(1);
+        let _ = CarbonCredits::set_standard_definition(Origin::root(), standard.clone(), get_test_custom_standard_definition());
+        let _ = CarbonCredits::create_project(Origin::signed(owner), standard.clone(), create_project_documentation_file(owner), get_test_project_metadata(), TEST_CREDITING_PERIOD_START, TEST_CREDITING_PERIOD_END);
+
+        let result = CarbonCredits::remove_standard_definition(Origin::root(), standard.clone());
+
+        assert_noop!(result, RuntimeError::StandardHasProjects);
+        assert_eq!(1, CarbonCredits::projects_count_by_standard(&standard));
+        assert_eq!(Some(get_test_custom_standard_definition()), CarbonCredits::get_standard_definition(&standard));
+    });
+}
+
+#[test]
+fn it_fails_create_project_unexisting_custom_standard() {
+    new_test_ext().execute_with(|| {
+        let owner = ROLES[1].0;
+        let result = CarbonCredits::create_project(Origin::signed(owner), Standard::CUSTOM(1), create_project_documentation_file(owner), get_test_project_metadata(), TEST_CREDITING_PERIOD_START, TEST_CREDITING_PERIOD_END);
+
+        assert_noop!(result, RuntimeError::StandardNotExist);
+        assert!(CarbonCredits::get_proj_by_id(1).is_none());
+    });
+}
+
+#[test]
+fn it_works_full_cycle_custom_standard() {
+    new_test_ext().execute_with(|| {
+        let owner = ROLES[1].0;
+        let auditor = ROLES[2].0;
+        let registry = ROLES[5].0;
+        let standard = Standard::CUSTOM(1);
+        let _ = CarbonCredits::set_standard_definition(Origin::root(), standard.clone(), get_test_custom_standard_definition());
+
+        let (_, project_id, _) = get_project_and_owner_and_custom_signers(standard, assign_project_mock_users_required_signers_gold_standard);
+        let project = CarbonCredits::get_proj_by_id(project_id).unwrap();
+        assert_eq!(REGISTERED, project.state);
+        assert_eq!(ProjectStatus::ISSUANCE, project.status);
+        assert!(EvercityFilesign::address_has_signed_the_file(project.file_id.unwrap(), &auditor));
+        assert!(EvercityFilesign::address_has_signed_the_file(project.file_id.unwrap(), &registry));
+
+        let _ = CarbonCredits::create_annual_report(
+            Origin::signed(owner), project_id, create_annual_report_file(owner), TEST_CARBON_CREDITS_COUNT,
+            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, get_test_monitoring_period(0)
+        );
+        assign_annual_report_mock_users_required_signers_gold_standard(project_id);
+
+        let auditor_sign_result = CarbonCredits::sign_last_annual_report(Origin::signed(auditor), project_id);
+        assert_noop!(auditor_sign_result, RuntimeError::AccountNotOwner);
+
+        let tuple_vec = vec![
+            (owner, REPORT_REGISTRY_SIGN_PENDING),
+            (registry, REPORT_ISSUED),
+        ];
+        tuple_vec.iter().for_each(|(acc, state)| {
+            let result = CarbonCredits::sign_last_annual_report(Origin::signed(*acc), project_id);
+            let project = CarbonCredits::get_proj_by_id(project_id).unwrap();
+
+            assert_ok!(result, ());
+            assert_eq!(*state, CarbonCredits::get_last_annual_report(project.id).unwrap().state);
+        });
+    });
+}
+
+#[test]
+fn it_works_override_built_in_standard_definition() {
+    new_test_ext().execute_with(|| {
+        let standard = Standard::GOLD_STANDARD;
+        let _ = CarbonCredits::set_standard_definition(Origin::root(), standard.clone(), get_test_custom_standard_definition());
+        assert_eq!(Some(get_test_custom_standard_definition()), CarbonCredits::get_standard_definition(&standard));
+
+        let _ = CarbonCredits::remove_standard_definition(Origin::root(), standard.clone());
+        assert_eq!(standard.default_definition(), CarbonCredits::get_standard_definition(&standard));
+    });
+}
+
+#[test]
+fn it_works_custom_standard_with_investor_stage() {
+    new_test_ext().execute_with(|| {
+        let owner = ROLES[1].0;
+        let investor = ROLES[4].0;
+        let registry = ROLES[5].0;
+        let standard = Standard::CUSTOM(1);
+        let definition = StandardStruct::new(
+            vec![CC_PROJECT_OWNER_ROLE_MASK, CC_INVESTOR_ROLE_MASK, CC_REGISTRY_ROLE_MASK],
+            vec![CC_PROJECT_OWNER_ROLE_MASK, CC_REGISTRY_ROLE_MASK],
+        );
+        let set_result = CarbonCredits::set_standard_definition(Origin::root(), standard.clone(), definition);
+
+        let _ = CarbonCredits::create_project(Origin::signed(owner), standard, create_project_documentation_file(owner), get_test_project_metadata(), TEST_CREDITING_PERIOD_START, TEST_CREDITING_PERIOD_END);
+        assign_project_mock_users_required_signers_with_investor(1);
+
+        let tuple_vec = vec![
+            (owner, INVESTOR_SIGN_PENDING),
+            (investor, REGISTRY_SIGN_PENDING),
+            (registry, REGISTERED),
+        ];
+
+        assert_ok!(set_result, ());
+        tuple_vec.iter().for_each(|(acc, state)| {
+            let result = CarbonCredits::sign_project(Origin::signed(*acc), 1);
+            let project = CarbonCredits::get_proj_by_id(1).unwrap();
+
+            assert_ok!(result, ());
+            assert_eq!(*state, project.state);
+        });
+    });
+}
+
+#[test]
+fn it_works_with_investor_stage_standard_definition() {
+    new_test_ext().execute_with(|| {
+        let definition = Standard::VERRA_VCS.default_definition().unwrap().with_investor_stage();
+
+        assert_eq!(
+            vec![CC_PROJECT_OWNER_ROLE_MASK, CC_AUDITOR_ROLE_MASK, CC_STANDARD_ROLE_MASK, CC_INVESTOR_ROLE_MASK, CC_REGISTRY_ROLE_MASK],
+            definition.project_signing_order
+        );
+        assert_eq!(
+            vec![CC_PROJECT_OWNER_ROLE_MASK, CC_AUDITOR_ROLE_MASK, CC_INVESTOR_ROLE_MASK, CC_REGISTRY_ROLE_MASK],
+            definition.report_signing_order
+        );
+        assert!(definition.is_valid());
+    });
+}
+#[test]
+fn it_works_sign_project_after_standard_definition_changed() {
+    new_test_ext().execute_with(|| {
+        let owner = ROLES[1].0;
+        let auditor = ROLES[2].0;
+        let standard_acc = ROLES[3].0;
+        let registry = ROLES[5].0;
+        let _ = CarbonCredits::create_project(Origin::signed(owner), Standard::GOLD_STANDARD, create_project_documentation_file(owner), get_test_project_metadata(), TEST_CREDITING_PERIOD_START, TEST_CREDITING_PERIOD_END);
+        assign_project_mock_users_required_signers_gold_standard(1);
+        let _ = CarbonCredits::sign_project(Origin::signed(owner), 1);
+        let _ = CarbonCredits::sign_project(Origin::signed(auditor), 1);
+
+        // Standard role is removed from the signing order, while its signature is pending
+        let set_result = CarbonCredits::set_standard_definition(Origin::root(), Standard::GOLD_STANDARD, get_test_custom_standard_definition());
+
+        let tuple_vec = vec![
+            (standard_acc, REGISTRY_SIGN_PENDING),
+            (registry, REGISTERED),
+        ];
+
+        assert_ok!(set_result, ());
+        tuple_vec.iter().for_each(|(acc, state)| {
+            let result = CarbonCredits::sign_project(Origin::signed(*acc), 1);
+            let project = CarbonCredits::get_proj_by_id(1).unwrap();
+
+            assert_ok!(result, ());
+            assert_eq!(*state, project.state);
+        });
+        // Registered project keeps the definition, fixed on submission
+        assert_eq!(Standard::GOLD_STANDARD.default_definition().as_ref(), CarbonCredits::get_proj_by_id(1).unwrap().get_standard_definition());
+    });
+}
+
+#[test]
+fn it_works_resubmit_rejected_project_with_new_standard_definition() {
+    new_test_ext().execute_with(|| {
+        let owner = ROLES[1].0;
+        let auditor = ROLES[2].0;
+        let standard_acc = ROLES[3].0;
+        let _ = CarbonCredits::create_project(Origin::signed(owner), Standard::GOLD_STANDARD, create_project_documentation_file(owner), get_test_project_metadata(), TEST_CREDITING_PERIOD_START, TEST_CREDITING_PERIOD_END);
+        assign_project_mock_users_required_signers_gold_standard(1);
+        let _ = CarbonCredits::sign_project(Origin::signed(owner), 1);
+        let _ = CarbonCredits::sign_project(Origin::signed(auditor), 1);
+        let _ = CarbonCredits::set_standard_definition(Origin::root(), Standard::GOLD_STANDARD, get_test_custom_standard_definition());
+
+        let reject_result = CarbonCredits::reject_project(Origin::signed(standard_acc), 1, Default::default());
+        assert!(CarbonCredits::get_proj_by_id(1).unwrap().get_standard_definition().is_none());
+
+        // Resubmitted project follows the new definition
+        let _ = CarbonCredits::sign_project(Origin::signed(owner), 1);
+        let _ = CarbonCredits::sign_project(Origin::signed(auditor), 1);
+        let project = CarbonCredits::get_proj_by_id(1).unwrap();
+
+        assert_ok!(reject_result, ());
+        assert_eq!(REGISTRY_SIGN_PENDING, project.state);
+        assert_eq!(get_test_custom_standard_definition().project_signing_order, project.get_standard_definition().unwrap().project_signing_order);
+    });
+}
+
+#[test]
+fn it_works_sign_annual_report_after_standard_definition_changed() {
+    new_test_ext().execute_with(|| {
+        let auditor = ROLES[2].0;
+        let standard_acc = ROLES[3].0;
+        let registry = ROLES[5].0;
+        let (_, project_id, owner) = get_registerd_project_and_owner_gold_standard();
+        let _ = CarbonCredits::create_annual_report(
+            Origin::signed(owner), project_id, create_annual_report_file(owner), TEST_CARBON_CREDITS_COUNT,
+            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, get_test_monitoring_period(0)
+        );
+        assign_annual_report_mock_users_required_signers_gold_standard(project_id);
+        let _ = CarbonCredits::sign_last_annual_report(Origin::signed(owner), project_id);
+        let _ = CarbonCredits::sign_last_annual_report(Origin::signed(auditor), project_id);
+
+        // Standard role is removed from the signing order, while its signature is pending
+        let set_result = CarbonCredits::set_standard_definition(Origin::root(), Standard::GOLD_STANDARD, get_test_custom_standard_definition());
+        assert_eq!(vec![(project_id, 0)], CarbonCredits::get_annual_reports_pending_signature(standard_acc));
+
+        let tuple_vec = vec![
+            (standard_acc, REPORT_REGISTRY_SIGN_PENDING),
+            (registry, REPORT_ISSUED),
+        ];
+
+        assert_ok!(set_result, ());
+        tuple_vec.iter().for_each(|(acc, state)| {
+            let result = CarbonCredits::sign_last_annual_report(Origin::signed(*acc), project_id);
+
+            assert_ok!(result, ());
+            assert_eq!(*state, CarbonCredits::get_last_annual_report(project_id).unwrap().state);
+        });
+    });
+}
+
+#[test]
+fn it_works_create_annual_report_after_standard_definition_changed() {
+    new_test_ext().execute_with(|| {
+        let auditor = ROLES[2].0;
+        let standard_acc = ROLES[3].0;
+        let registry = ROLES[5].0;
+        let (_, project_id, owner) = get_registerd_project_and_owner_gold_standard();
+
+        // Registered project follows the definition, fixed on submission, new definition applies to new projects
+        let set_result = CarbonCredits::set_standard_definition(Origin::root(), Standard::GOLD_STANDARD, get_test_custom_standard_definition());
+        let _ = CarbonCredits::create_annual_report(
+            Origin::signed(owner), project_id, create_annual_report_file(owner), TEST_CARBON_CREDITS_COUNT,
+            get_test_carbon_credits_name() , get_test_carbon_credits_symbol(), TEST_CARBON_CREDITS_DECIMAL, get_test_monitoring_period(0)
+        );
+        assign_annual_report_mock_users_required_signers_gold_standard(project_id);
+
+        let tuple_vec = vec![
+            (owner, REPORT_AUDITOR_SIGN_PENDING),
+            (auditor, REPORT_STANDARD_SIGN_PENDING),
+            (standard_acc, REPORT_REGISTRY_SIGN_PENDING),
+            (registry, REPORT_ISSUED),
+        ];
+
+        assert_ok!(set_result, ());
+        tuple_vec.iter().for_each(|(acc, state)| {
+            let result = CarbonCredits::sign_last_annual_report(Origin::signed(*acc), project_id);
+
+            assert_ok!(result, ());
+            assert_eq!(*state, CarbonCredits::get_last_annual_report(project_id).unwrap().state);
+        });
+    });
+}
+
+#[test]
+fn it_works_verra_annual_report_issued_without_standard_signature() {
+    new_test_ext_with_event().execute_with(|| {
+        let standard_acc = ROLES[3].0;
+        let (_, project_id, _) = full_sign_annual_report_verra();
+        let project = CarbonCredits::get_proj_by_id(project_id).unwrap();
+        let report = CarbonCredits::get_last_annual_report(project_id).unwrap();
+        let check_event = Event::pallet_carbon_credits(crate::RawEvent::AnnualReportSignedByStandard(standard_acc, project_id));
+
+        // Verra reviews the project registration, but not monitoring reports
+        assert_eq!(REGISTERED, project.state);
+        assert!(EvercityFilesign::address_has_signed_the_file(project.file_id.unwrap(), &standard_acc));
+        assert_eq!(REPORT_ISSUED, report.state);
+        assert!(!EvercityFilesign::address_has_signed_the_file(report.file_id, &standard_acc));
+        assert!(!System::events().into_iter().any(|record| record.event == check_event));
+    });
+}
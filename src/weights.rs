@@ -0,0 +1,60 @@
+//! Weights for pallet_evercity_carbon_credits
+//!
+//! Benchmarks are defined in benchmarking.rs. Values below are not benchmark output yet: database reads and writes
+//! are counted from the code, per transfer: passport, holder freeze, sender balance before and after the transfer,
+//! 3 reads and 2 writes of the assets pallet, serial ranges of the sender and the receiver. Execution time is estimated.
+//! They must be replaced by the output of the benchmark on the reference hardware, also after changes of the benchmarked extrinsics:
+//!
+//! ./target/release/node-template benchmark
+//!     --chain=dev
+//!     --steps=50
+//!     --repeat=20
+//!     --pallet=pallet_evercity_carbon_credits
+//!     --extrinsic=*
+//!     --execution=wasm
+//!     --wasm-execution=compiled
+//!     --heap-pages=4096
+//!     --output=./src/weights.rs
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use frame_support::sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_evercity_carbon_credits
+pub trait WeightInfo {
+    fn transfer_carbon_credits() -> Weight;
+    fn batch_transfer_carbon_credits(b: u32, ) -> Weight;
+}
+
+/// Weights for pallet_evercity_carbon_credits using the Substrate node and recommended hardware
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    fn transfer_carbon_credits() -> Weight {
+        (71_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(10 as Weight))
+            .saturating_add(T::DbWeight::get().writes(6 as Weight))
+    }
+    fn batch_transfer_carbon_credits(b: u32, ) -> Weight {
+        (5_000_000 as Weight)
+            .saturating_add((69_000_000 as Weight).saturating_mul(b as Weight))
+            .saturating_add(T::DbWeight::get().reads((10 as Weight).saturating_mul(b as Weight)))
+            .saturating_add(T::DbWeight::get().writes((6 as Weight).saturating_mul(b as Weight)))
+    }
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+    fn transfer_carbon_credits() -> Weight {
+        (71_000_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(10 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(6 as Weight))
+    }
+    fn batch_transfer_carbon_credits(b: u32, ) -> Weight {
+        (5_000_000 as Weight)
+            .saturating_add((69_000_000 as Weight).saturating_mul(b as Weight))
+            .saturating_add(RocksDbWeight::get().reads((10 as Weight).saturating_mul(b as Weight)))
+            .saturating_add(RocksDbWeight::get().writes((6 as Weight).saturating_mul(b as Weight)))
+    }
+}